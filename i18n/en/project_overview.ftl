@@ -6,10 +6,419 @@ page-id = Page { $num }
 git-description = Git commit {$hash} on {$date}
 settings = settings
 settings-path-placeholder = enter root-path
+settings-root-pick = choose folder…
+settings-root-label-placeholder = name of the root, e.g. Work, shown instead of its path
+settings-discovery-depth = look for projects
+settings-discovery-depth-root = directly in the root
+settings-discovery-depth-levels = up to {$levels} levels deep, not looking inside projects
+settings-ignore-patterns = skip directories named like, * matching any characters
+settings-ignore-pattern-placeholder = e.g. node_modules, target or .* for hidden folders
+settings-ignore-patterns-reset = restore the built-in list
 save = save
 
 settings-program-command-placeholder = enter program command
 settings-program-name-placeholder = enter program name
+settings-program-icon-placeholder = icon name or image path shown instead of the name, optional
+settings-program-env = environment variables set for the program
+settings-program-start = folder the program starts in
+settings-program-start-default = as set for all programs
+settings-program-start-in-project = the project folder
+settings-program-start-outside-project = the app's folder
 add = add
+program-missing = cannot be found, check that "{$command}" is installed
+program-in-terminal = {$command}, in a terminal
+program-default = launch projects with this program by default, unless they were last opened with another one
+project-default-program = launch by default with
+project-default-program-global = the last used or default program
+project-dock-pins = pinned to the dock with
+settings-program-run-in-terminal = run in a terminal, for programs like nvim
+settings-program-mnemonic = key launching the selected project with this program
+settings-program-mnemonic-none = no key
 
-search-input = enter search
+search-input = enter search, kind:rust for a project type or #tag for tagged projects, add > code to launch with code
+search-results = {$count ->
+    [0] no matches
+    [one] 1 match
+   *[other] {$count} matches
+}
+search-input-person = enter search, kind:rust for a project type, #tag for tagged projects or @name for a person's projects
+
+project-details = project details
+project-details-missing = project no longer exists
+project-details-readme-badges = badges
+readme-badge-build = build
+readme-badge-coverage = coverage
+readme-badge-version = version
+readme-badge-other = badge
+project-details-release = latest release {$version}
+project-details-release-days = latest release {$version}, {$days ->
+    [0] today
+    [one] 1 day ago
+   *[other] {$days} days ago
+}
+project-details-unreleased-commits = {$count ->
+    [0] no unreleased commits
+    [one] 1 unreleased commit
+   *[other] {$count} unreleased commits
+}
+unreleased-only = only projects with unreleased changes
+show-inactive = also show inactive projects
+project-details-alternate-paths = also reachable via
+
+snapshot-export = export snapshot
+snapshot-open = open snapshot
+snapshot-close = close snapshot
+snapshot-browsing = browsing a snapshot of {$root} taken {$created}, projects cannot be opened
+root-read-only = the project root is on a read-only filesystem
+root-not-mounted = the project root is on media that is not mounted ({$path})
+mount = mount
+mount-failed = failed to mount: {$error}
+project-read-only = read-only
+badge-locked = locked
+project-locked = locked, changes are disabled
+project-locked-reason = locked, changes are disabled: {$reason}
+
+delete = delete
+cancel = cancel
+unsaved-settings = unsaved settings
+unsaved-settings-body = some settings were edited but not saved yet, save what is complete or discard the edits?
+unsaved-settings-discard = discard
+programs-diverged = programs changed elsewhere
+programs-diverged-body = another window changed the programs while this one had changes of its own, combine both or keep one side?
+programs-diverged-mine = this window: {$programs}
+programs-diverged-theirs = the other window: {$programs}
+programs-merge = combine
+programs-use-theirs = use the other window's
+programs-keep-mine = keep this window's
+close = close
+delete-program-title = delete {$name}?
+delete-program-body = the following projects have settings referring to this program, they will be removed as well
+
+launch-overrides = launch overrides
+launch-override-working-directory = working directory: {$path}
+launch-override-path = %path%: {$path}
+launch-override-editing = editing {$program}
+launch-override-working-directory-placeholder = working directory (relative to the project)
+launch-override-path-placeholder = %path% target (relative to the project)
+
+content-search = search in projects
+content-search-input = enter text to search for, press enter to start
+content-search-running = searching…
+content-search-hits = {$project} ({$hits} hits)
+
+project-details-modified-files = modified files
+project-details-last-commit-files = changed in the last commit
+project-details-no-files = none
+
+file = File
+new-project = new project
+new-project-name-placeholder = enter project name
+new-project-empty = create empty project
+new-project-templates = create from template
+settings-templates-path-placeholder = enter templates-path
+
+hook-running = running post-create hook ({$step}/{$total}): {$command}
+hook-failed = post-create hook failed: {$error}
+
+import-project = import project…
+import-project-body = move or copy {$path} into the project root?
+import-project-move = move
+import-project-copy = copy
+import-project-progress = importing project, {$done} of {$total} files copied
+import-project-failed = failed to import project: {$error}
+
+sessions = Sessions
+sessions-hint = add projects to a session from their details
+session-name-placeholder = enter session name
+session-entry = {$project} with {$program}
+project-sessions-empty = create a session and a program to add this project to a session
+remove = remove
+session-autostart = launch on login
+
+palette-project = run…
+palette-title = commands
+palette-project-title = run for {$project}
+palette-placeholder = type to filter
+palette-show-details = show details
+open-with = open with…
+open-folder = open folder
+copy-path = copy path
+project-pin = pin
+project-unpin = unpin
+project-tags-edit = edit tags…
+project-rename = rename
+project-rename-title = rename {$project}
+project-rename-body = shown instead of the folder name, which stays unchanged, leave empty to show the folder name again
+dock-pin = pin {$program} to the dock
+settings-dock-pins = pinned to the dock
+settings-shortcuts = keyboard shortcuts
+settings-shortcuts-hint = click a shortcut and press the new keys, escape to keep the old ones
+settings-shortcut-project = {$action} (selected project)
+settings-shortcut-recording = press keys…
+settings-shortcut-none = none
+settings-dock-pins-empty = pin a project with a program from its details or its commands
+bulk-launch-tag = launch all tagged "{$tag}"…
+bulk-launch-matching = launch all matching "{$search}"…
+bulk-launch-body = {$count ->
+    [one] opens 1 project with the chosen program
+   *[other] opens {$count} projects with the chosen program
+}
+bulk-launch-body-limited = opens the first {$limit} of {$count} projects with the chosen program
+saved-search-add = save the search "{$search}" for bulk launches
+settings-saved-searches = saved searches
+settings-saved-searches-empty = save a search from the command palette to bulk launch what it matches
+bulk-launch-confirm = launch all
+palette-launch = launch with {$program}
+launch-prompt = launch with {$program}
+launch-prompt-confirm = launch
+launch-skipped = launch skipped
+launch-unanswered-prompts = {$program} for {$project} asks for {$prompts}, launch it from the window once to answer
+palette-run = run {$command}
+palette-launch-session = launch session {$session}
+palette-open-project = open {$project}
+refresh = refresh
+list-view = list view
+grid-view = grid view
+sort = sort
+sort-by-modified = last modified
+sort-by-name = name
+sort-by-created = newest
+sort-by-launched = recently launched
+last-launched = last opened with {$program}, {$days ->
+    [0] today
+    [one] yesterday
+   *[other] {$days} days ago
+}
+sort-manually = manually, drag to reorder
+focus-search = search
+
+projects-shown = showing {$shown} of {$total} projects
+projects-show-more = show {$count} more
+
+settings-usage-weight = how much launching projects often and recently ranks them higher in the search
+settings-usage-weight-off = not at all
+settings-max-project-age = hide projects without changes for
+settings-max-project-age-off = never hide projects
+settings-archive-after = suggest hiding projects without changes or launches for
+settings-archive-after-off = never suggest hiding projects
+settings-max-project-age-months = {$months ->
+    [12] a year
+    [24] two years
+   *[other] {$months} months
+}
+settings-list-click-action = clicking a project in list view
+settings-grid-click-action = clicking a project in grid view
+click-action-open-details = opens its details
+click-action-launch-default = launches it with its default program
+click-action-select = selects it
+settings-program-desktop-entry = pick an installed application or enter a command containing %path%, %name%, %parent% or %git_branch%
+settings-program-placeholders = %path% the project, %name% its folder name, %parent% the folder containing it, %git_branch% its checked out branch, %root% the project root, %prompt:Label% a value asked for at launch
+settings-program-unknown-placeholders = not known and passed on as written: {$placeholders}
+installed-apps-open = add from installed apps…
+installed-apps = installed apps
+installed-apps-placeholder = type to filter
+installed-apps-added = added
+programs-export = copy programs
+programs-import = paste programs
+programs-exported = copied {$count} programs to the clipboard
+programs-imported = added {$count} programs, skipped {$skipped} already present and {$invalid} invalid or repeated
+programs-import-failed = the clipboard holds no shared programs
+settings-program-flatpak = or run a Flatpak application
+settings-track-launched = keep launched programs tied to the app instead of detaching them
+settings-start-in-project = start programs inside the project folder
+settings-after-launch = after launching a project
+after-launch-stay-focused = keep this window focused
+after-launch-yield-focus = focus the launched program
+after-launch-minimize = minimize this window
+settings-terminal = terminal for programs and tasks that run in one
+settings-terminal-placeholder = e.g. cosmic-term, detected from $TERMINAL if empty
+settings-env-profiles = environment profiles
+settings-env-profiles-description = environment variables set when launching the projects using a profile, e.g. staging or production credentials
+settings-env-profile-name-placeholder = enter profile name, e.g. staging
+settings-env-var-placeholder = add a variable, KEY=value
+project-env-profile = launch with the environment profile
+project-env-profile-none = none
+settings-hide-header-controls = hide the view toggle and sort menu in the header
+settings-reduce-motion = reduce motion
+settings-color-blind-palette = color blind friendly badge colors
+settings-show-project-icons = show project logos
+settings-security-audit = audit dependencies for vulnerabilities (cargo audit, npm audit)
+settings-ignore-power-state = run background work on battery and metered connections
+settings-job-concurrency = background jobs running at once
+settings-background-paused = background work is paused on battery or a metered connection
+badge-vulnerabilities = {$count ->
+    [one] 1 vulnerability
+   *[other] {$count} vulnerabilities
+}
+project-details-audit-failed = dependency audit failed: {$error}
+
+settings-lint-checks = lint checks
+settings-check-marker-placeholder = only in projects containing, e.g. Cargo.toml
+settings-check-command-placeholder = command, e.g. cargo fmt --check
+badge-lint-failing = would fail CI
+
+settings-health-checks = health checks
+settings-health-check-interval = run health checks
+settings-health-check-manual = manually only
+settings-health-check-every-minutes = every {$minutes} minutes
+settings-health-check-every-hours = {$hours ->
+    [one] every hour
+   *[other] every {$hours} hours
+}
+badge-healthy = healthy
+badge-unhealthy = unhealthy
+project-details-run-health-checks = run health checks
+
+check-log = check log
+check-log-running = running…
+check-log-passed = passed
+check-log-failed = failed
+check-log-empty = not run yet
+
+jobs = tasks
+jobs-empty = no background tasks
+job-audit = dependency audit
+job-queued = queued
+job-running = running
+job-cancelling = stopping
+job-failed = failed: {$error}
+
+config-validate = check config for problems
+config-report = config report
+config-report-checking = checking…
+config-report-valid = no problems found
+config-report-issues = {$count ->
+    [one] found 1 problem
+   *[other] found {$count} problems
+}
+config-issue-remove = remove
+config-issues-removed = removed
+config-issue-missing-project-root = project root {$path} does not exist
+config-issue-missing-templates-path = templates folder {$path} does not exist
+config-issue-duplicate-program = program {$name} is configured more than once, removing keeps the first
+config-issue-invalid-program = program {$name} cannot run "{$command}"
+config-issue-dangling-override = launch override of {$program} for {$project} points to a missing project or program
+config-issue-dangling-session-entry = session {$session} opens {$program} in {$project}, which no longer exists
+config-issue-duplicate-session = session {$name} exists more than once, removing keeps the first
+config-issue-dangling-default-program = default program {$program} of {$project} points to a missing project or program
+config-issue-missing-default-program = default program {$program} no longer exists
+config-issue-dangling-env-profile = environment profile {$profile} of {$project} points to a missing project or profile
+config-issue-dangling-dock-pin = dock pin of {$program} for {$project} points to a missing project or program
+config-issue-duplicate-mnemonic = program {$program} uses the key {$mnemonic} of an earlier program, removing clears it
+config-issue-dangling-annotation = pin, tags and name of {$project} belong to a missing project
+
+settings-automations = automations
+settings-automations-description = scripts run on app events, with details in PROJECT_OVERVIEW_* environment variables
+settings-automation-command-placeholder = command, e.g. notify-send "$PROJECT_OVERVIEW_PROJECT_NAME"
+automation-project-launched = project launched
+automation-project-discovered = new project discovered
+automation-scan-finished = scan finished
+
+settings-rules = rules
+settings-rules-description = applied to matching projects while scanning. in globs * matches any characters and ? a single one, regular expressions match anywhere unless anchored with ^ and $
+settings-rule-pattern-placeholder = pattern, e.g. */clients/* or ^(api|web)-
+settings-rule-pattern-invalid = invalid pattern: {$error}
+settings-rule-value-placeholder = tag or display name
+rule-target-name = name matches
+rule-target-path = path matches
+rule-syntax-glob = the glob
+rule-syntax-regex = the regular expression
+rule-action-tag = add the tag
+rule-action-kind = set the kind
+rule-action-display-name = show with the name
+rule-assigns-tag = adds the tag {$tag}
+rule-assigns-kind = sets the kind to {$kind}
+rule-assigns-display-name = shows as {$name}
+
+project-tags = tags
+project-tags-title = tags of {$project}
+project-tags-shared = shared with the team: {$tags}
+project-tags-placeholder = add a personal tag
+
+settings-show-ownership = show project owners and last committers
+job-ownership = ownership
+job-release = release detection
+job-git-status = git status
+git-status-clean = on {$branch}
+git-status-dirty = on {$branch}, uncommitted changes
+git-detached = detached HEAD
+badge-owner = owned by {$name}
+badge-last-committer = last commit by {$name}
+project-details-owner = owner
+project-details-last-committer = last commit by
+
+project-hide = hide
+project-trash = move to trash
+trash = hidden and trashed
+trash-failed = could not move the project: {$error}
+trash-hidden = hidden projects
+trash-hidden-empty = no hidden projects
+trash-unhide = show
+trash-trashed = trashed projects
+trash-trashed-empty = no projects were moved to the trash
+trash-restore = restore
+trash-gone = no longer in the trash
+trash-forget = forget
+
+settings-show-footer = show project statistics in a footer
+job-stats = project statistics
+statistics = statistics
+statistics-weekly-digest = show a weekly digest notification
+statistics-this-week = last 7 days
+statistics-last-week = the 7 days before
+statistics-kinds = projects by kind
+statistics-kinds-size = disk usage by kind
+statistics-kind-other = other
+archive-suggestions-title = cleanup suggestions
+archive-suggestions = {$count ->
+    [one] 1 project had
+   *[other] {$count} projects had
+} no changes or launches for {$months} months
+archive-suggestions-review = review
+archive-suggestions-hide = hide all
+archive-suggestions-keep = keep
+archive-suggestions-keep-all = keep all
+archive-suggestions-hide-title = hide {$count ->
+    [one] 1 project
+   *[other] {$count} projects
+}?
+archive-suggestions-hide-body = every project without changes or launches for {$months} months is hidden. they stay on disk and can be shown again from the settings.
+statistics-sizes-disabled = sizes are collected while the statistics footer is shown
+statistics-largest-projects = largest projects
+statistics-largest-artifacts = largest build artifacts
+clean-artifacts = clean
+clean-artifacts-title = clean {$project}?
+clean-artifacts-body = these build and dependency folders are deleted, freeing {$size}. they are recreated by the next build.
+clean-failed = cleaning stopped: {$error}
+clean-nothing = nothing to clean, build folders tracked by git are kept
+weekly-digest = your week in projects
+digest-summary = {$touched ->
+    [one] 1 project touched
+   *[other] {$touched} projects touched
+}, {$launches ->
+    [one] 1 launch
+   *[other] {$launches} launches
+}, {$new ->
+    [one] 1 new project
+   *[other] {$new} new projects
+}, {$cleaned} of artifacts cleaned
+footer-projects = {$count ->
+    [one] 1 project
+   *[other] {$count} projects
+}
+footer-dirty = {$count ->
+    [one] 1 repository with changes
+   *[other] {$count} repositories with changes
+}
+footer-artifacts = {$size} in build artifacts
+footer-last-scan = scanned at {$time}
+footer-collecting = collecting {$done} of {$total}
+
+launch-failed = failed to launch {$program}: {$error}
+scan-failed = scanning the project root stopped: {$error}
+
+scan-diff = since the last scan: {$added} added, {$removed} removed, {$renamed} renamed, {$possibly_renamed} possibly renamed
+scan-diff-added = added {$path}
+scan-diff-removed = removed {$path}
+scan-diff-renamed = {$old} renamed to {$new}
+scan-diff-possibly-renamed = {$old} possibly renamed to {$new}, its settings stayed behind
@@ -0,0 +1,129 @@
+use crate::app::Message;
+use crate::domain::forge::Forge;
+use crate::fl;
+use cosmic::iced::{Alignment, Background, Border, Color};
+use cosmic::{cosmic_theme, theme, widget, Element};
+
+/// A status indicator shown next to a project.
+///
+/// Every badge has its own icon, so it can be told apart without relying on its color.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Badge {
+    ReadOnly,
+    Locked,
+    Forge(Forge),
+    Vulnerabilities(usize),
+    LintFailing,
+    Health(bool),
+    Tag(String),
+    Owner(String),
+    LastCommitter(String),
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Tone {
+    Neutral,
+    Good,
+    Warning,
+    Bad,
+}
+
+impl Badge {
+    fn icon(&self) -> &'static str {
+        match self {
+            Badge::ReadOnly => "changes-prevent-symbolic",
+            Badge::Locked => "system-lock-screen-symbolic",
+            Badge::Forge(Forge::SelfHosted(_)) => "network-server-symbolic",
+            Badge::Forge(_) => "folder-remote-symbolic",
+            Badge::Vulnerabilities(_) => "security-low-symbolic",
+            Badge::LintFailing => "dialog-error-symbolic",
+            Badge::Health(true) => "emblem-ok-symbolic",
+            Badge::Health(false) => "dialog-warning-symbolic",
+            Badge::Tag(_) => "bookmark-new-symbolic",
+            Badge::Owner(_) => "avatar-default-symbolic",
+            Badge::LastCommitter(_) => "document-edit-symbolic",
+        }
+    }
+
+    fn tone(&self) -> Tone {
+        match self {
+            Badge::ReadOnly => Tone::Neutral,
+            Badge::Locked => Tone::Warning,
+            Badge::Forge(_) => Tone::Neutral,
+            Badge::Vulnerabilities(_) => Tone::Bad,
+            Badge::LintFailing => Tone::Warning,
+            Badge::Health(true) => Tone::Good,
+            Badge::Health(false) => Tone::Bad,
+            Badge::Tag(_) => Tone::Neutral,
+            Badge::Owner(_) => Tone::Neutral,
+            Badge::LastCommitter(_) => Tone::Neutral,
+        }
+    }
+
+    fn label(&self) -> String {
+        match self {
+            Badge::ReadOnly => fl!("project-read-only"),
+            Badge::Locked => fl!("badge-locked"),
+            Badge::Forge(forge) => forge.name().to_string(),
+            Badge::Vulnerabilities(count) => fl!("badge-vulnerabilities", count = count),
+            Badge::LintFailing => fl!("badge-lint-failing"),
+            Badge::Health(true) => fl!("badge-healthy"),
+            Badge::Health(false) => fl!("badge-unhealthy"),
+            Badge::Tag(tag) => tag.clone(),
+            Badge::Owner(name) => fl!("badge-owner", name = name.as_str()),
+            Badge::LastCommitter(name) => fl!("badge-last-committer", name = name.as_str()),
+        }
+    }
+
+    pub fn view<'a>(&self, color_blind_palette: bool) -> Element<'a, Message> {
+        view(self.icon(), self.label(), self.tone(), color_blind_palette)
+    }
+}
+
+impl Tone {
+    fn color(self, color_blind_palette: bool) -> Color {
+        // The color blind palette uses the Okabe-Ito colors.
+        match (self, color_blind_palette) {
+            (Tone::Neutral, _) => Color::from_rgb8(0x8e, 0x8e, 0x8e),
+            (Tone::Good, false) => Color::from_rgb8(0x3a, 0xa8, 0x4a),
+            (Tone::Good, true) => Color::from_rgb8(0x00, 0x72, 0xb2),
+            (Tone::Warning, false) => Color::from_rgb8(0xe5, 0xa5, 0x0a),
+            (Tone::Warning, true) => Color::from_rgb8(0xe6, 0x9f, 0x00),
+            (Tone::Bad, false) => Color::from_rgb8(0xe0, 0x1b, 0x24),
+            (Tone::Bad, true) => Color::from_rgb8(0xd5, 0x5e, 0x00),
+        }
+    }
+}
+
+/// Renders a badge from its parts, for badges carrying dynamic text.
+pub fn view<'a>(
+    icon: &'static str,
+    label: String,
+    tone: Tone,
+    color_blind_palette: bool,
+) -> Element<'a, Message> {
+    let cosmic_theme::Spacing { space_xxxs, .. } = theme::active().cosmic().spacing;
+
+    let mut background = tone.color(color_blind_palette);
+    background.a = 0.3;
+
+    let content = widget::row()
+        .push(widget::icon::from_name(icon).size(12).icon())
+        .push(widget::text::caption(label))
+        .align_y(Alignment::Center)
+        .spacing(space_xxxs);
+
+    widget::container(content)
+        .padding([0, space_xxxs])
+        .class(cosmic::theme::Container::custom(move |_| {
+            widget::container::Style {
+                background: Some(Background::Color(background)),
+                border: Border {
+                    radius: 4.0.into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        }))
+        .into()
+}
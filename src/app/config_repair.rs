@@ -0,0 +1,255 @@
+use crate::config::Config;
+use crate::domain::mount;
+use crate::fl;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// A problem in the saved config, reported for the user to remove the offending entry.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConfigIssue {
+    MissingProjectRoot(PathBuf),
+    MissingTemplatesPath(PathBuf),
+    DuplicateProgram(String),
+    InvalidProgram {
+        name: String,
+        command: String,
+    },
+    DanglingOverride {
+        project: PathBuf,
+        program: String,
+    },
+    DanglingSessionEntry {
+        session: String,
+        project: PathBuf,
+        program: String,
+    },
+    DuplicateSession(String),
+    /// A default program, of a single project or of all of them with `project` unset, that is missing.
+    DanglingDefaultProgram {
+        project: Option<PathBuf>,
+        program: String,
+    },
+    DanglingEnvProfile {
+        project: PathBuf,
+        profile: String,
+    },
+    DanglingDockPin {
+        project: PathBuf,
+        program: String,
+    },
+    /// A program whose key is already taken by an earlier one.
+    DuplicateMnemonic {
+        program: String,
+        mnemonic: char,
+    },
+    DanglingAnnotation(PathBuf),
+}
+
+impl ConfigIssue {
+    pub fn label(&self) -> String {
+        match self {
+            ConfigIssue::MissingProjectRoot(path) => fl!(
+                "config-issue-missing-project-root",
+                path = path.display().to_string()
+            ),
+            ConfigIssue::MissingTemplatesPath(path) => fl!(
+                "config-issue-missing-templates-path",
+                path = path.display().to_string()
+            ),
+            ConfigIssue::DuplicateProgram(name) => {
+                fl!("config-issue-duplicate-program", name = name.as_str())
+            }
+            ConfigIssue::InvalidProgram { name, command } => fl!(
+                "config-issue-invalid-program",
+                name = name.as_str(),
+                command = command.as_str()
+            ),
+            ConfigIssue::DanglingOverride { project, program } => fl!(
+                "config-issue-dangling-override",
+                project = project.display().to_string(),
+                program = program.as_str()
+            ),
+            ConfigIssue::DanglingSessionEntry {
+                session,
+                project,
+                program,
+            } => fl!(
+                "config-issue-dangling-session-entry",
+                session = session.as_str(),
+                project = project.display().to_string(),
+                program = program.as_str()
+            ),
+            ConfigIssue::DuplicateSession(name) => {
+                fl!("config-issue-duplicate-session", name = name.as_str())
+            }
+            ConfigIssue::DanglingDefaultProgram {
+                project: Some(project),
+                program,
+            } => fl!(
+                "config-issue-dangling-default-program",
+                project = project.display().to_string(),
+                program = program.as_str()
+            ),
+            ConfigIssue::DanglingDefaultProgram {
+                project: None,
+                program,
+            } => fl!(
+                "config-issue-missing-default-program",
+                program = program.as_str()
+            ),
+            ConfigIssue::DanglingEnvProfile { project, profile } => fl!(
+                "config-issue-dangling-env-profile",
+                project = project.display().to_string(),
+                profile = profile.as_str()
+            ),
+            ConfigIssue::DanglingDockPin { project, program } => fl!(
+                "config-issue-dangling-dock-pin",
+                project = project.display().to_string(),
+                program = program.as_str()
+            ),
+            ConfigIssue::DuplicateMnemonic { program, mnemonic } => fl!(
+                "config-issue-duplicate-mnemonic",
+                program = program.as_str(),
+                mnemonic = mnemonic.to_string()
+            ),
+            ConfigIssue::DanglingAnnotation(project) => fl!(
+                "config-issue-dangling-annotation",
+                project = project.display().to_string()
+            ),
+        }
+    }
+}
+
+/// Looks for entries referring to missing paths or programs, reading the filesystem and running
+/// `flatpak info` for Flatpak programs, so it is run off the UI thread.
+///
+/// Project paths are only checked while the root is there, as they would all look missing while
+/// its media is unmounted.
+pub fn validate(config: &Config) -> Vec<ConfigIssue> {
+    let mut issues = vec![];
+
+    let root_available = match config.project_root_path() {
+        Some(path) if !path.is_dir() => {
+            // unmounted media is expected to come back, a missing folder on a fixed disk is not
+            if mount::removable_root(path).is_none() {
+                issues.push(ConfigIssue::MissingProjectRoot(path.clone()));
+            }
+            false
+        }
+        _ => true,
+    };
+    let missing = |project: &Path| root_available && !project.is_dir();
+
+    if let Some(path) = config.templates_path() {
+        if !path.is_dir() {
+            issues.push(ConfigIssue::MissingTemplatesPath(path.clone()));
+        }
+    }
+
+    let mut program_names = HashSet::new();
+    let mut mnemonics = HashSet::new();
+
+    for program in config.programs() {
+        if !program_names.insert(program.name()) {
+            issues.push(ConfigIssue::DuplicateProgram(program.name().to_string()));
+            continue;
+        }
+
+        if !program.is_installed() {
+            issues.push(ConfigIssue::InvalidProgram {
+                name: program.name().to_string(),
+                command: program.command().to_string(),
+            });
+        }
+
+        if let Some(mnemonic) = program.mnemonic() {
+            if !mnemonics.insert(mnemonic) {
+                issues.push(ConfigIssue::DuplicateMnemonic {
+                    program: program.name().to_string(),
+                    mnemonic,
+                });
+            }
+        }
+    }
+
+    if let Some(program) = config
+        .default_program()
+        .filter(|program| !program_names.contains(program))
+    {
+        issues.push(ConfigIssue::DanglingDefaultProgram {
+            project: None,
+            program: program.to_string(),
+        });
+    }
+
+    for default in config.default_programs() {
+        if missing(default.project()) || !program_names.contains(default.program()) {
+            issues.push(ConfigIssue::DanglingDefaultProgram {
+                project: Some(default.project().to_path_buf()),
+                program: default.program().to_string(),
+            });
+        }
+    }
+
+    for launch_override in config.launch_overrides() {
+        if missing(launch_override.project()) || !program_names.contains(launch_override.program())
+        {
+            issues.push(ConfigIssue::DanglingOverride {
+                project: launch_override.project().to_path_buf(),
+                program: launch_override.program().to_string(),
+            });
+        }
+    }
+
+    for chosen in config.project_env_profiles() {
+        let known = config
+            .env_profiles()
+            .iter()
+            .any(|profile| profile.name() == chosen.profile());
+
+        if missing(chosen.project()) || !known {
+            issues.push(ConfigIssue::DanglingEnvProfile {
+                project: chosen.project().to_path_buf(),
+                profile: chosen.profile().to_string(),
+            });
+        }
+    }
+
+    for pin in config.dock_pins() {
+        if missing(pin.project()) || !program_names.contains(pin.program()) {
+            issues.push(ConfigIssue::DanglingDockPin {
+                project: pin.project().to_path_buf(),
+                program: pin.program().to_string(),
+            });
+        }
+    }
+
+    for annotation in config.annotations() {
+        if missing(annotation.project()) {
+            issues.push(ConfigIssue::DanglingAnnotation(
+                annotation.project().to_path_buf(),
+            ));
+        }
+    }
+
+    let mut session_names = HashSet::new();
+
+    for session in config.sessions() {
+        if !session_names.insert(session.name()) {
+            issues.push(ConfigIssue::DuplicateSession(session.name().to_string()));
+            continue;
+        }
+
+        for entry in session.entries() {
+            if missing(entry.project()) || !program_names.contains(entry.program()) {
+                issues.push(ConfigIssue::DanglingSessionEntry {
+                    session: session.name().to_string(),
+                    project: entry.project().to_path_buf(),
+                    program: entry.program().to_string(),
+                });
+            }
+        }
+    }
+
+    issues
+}
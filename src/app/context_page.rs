@@ -1,16 +1,51 @@
-use crate::app::{AppModel, Message, APP_ICON, REPOSITORY};
+use crate::app::job::JobState;
+use crate::app::shortcut::Shortcut;
+use crate::app::view_mode::{AfterLaunch, ClickAction, ViewMode};
+use crate::app::{
+    digest_summary, format_size, program_icon, AppModel, Message, APP_ICON, ARCHIVE_AFTER_MONTHS,
+    DISCOVERY_DEPTHS, HEALTH_CHECK_INTERVALS, JOB_CONCURRENCY_LIMITS, MAX_PROJECT_AGES,
+    MNEMONIC_KEYS, REPOSITORY, START_IN_PROJECT_OPTIONS, USAGE_WEIGHTS,
+};
+use crate::cli::Page;
+use crate::domain::automation::AutomationEvent;
+use crate::domain::check::{self, CheckKind};
+use crate::domain::command;
+use crate::domain::digest;
+use crate::domain::dock::DockPin;
+use crate::domain::env_profile;
+use crate::domain::launch::LaunchMode;
 use crate::domain::program::Program;
+use crate::domain::project::{Project, ProjectKind};
+use crate::domain::readme::ReadmeBadgeKind;
+use crate::domain::rule::{Rule, RuleAction, RuleSyntax, RuleTarget};
+use crate::domain::stats::ProjectStats;
 use crate::fl;
 use cosmic::app::context_drawer;
-use cosmic::iced::Alignment;
+use cosmic::iced::{Alignment, Background, Border, Color, Length};
 use cosmic::{cosmic_theme, theme, widget, Element};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
 pub enum ContextPage {
     #[default]
     About,
     Settings,
+    ProjectDetails,
+    NewProject,
+    Sessions,
+    CheckLog,
+    Jobs,
+    Trash,
+    Statistics,
+}
+
+impl From<Page> for ContextPage {
+    fn from(page: Page) -> Self {
+        match page {
+            Page::Settings | Page::Programs => ContextPage::Settings,
+            Page::Statistics => ContextPage::Statistics,
+        }
+    }
 }
 
 impl ContextPage {
@@ -24,6 +59,35 @@ impl ContextPage {
                 context_drawer::context_drawer(Self::settings(app), Message::CloseContextDrawer)
                     .title(fl!("settings"))
             }
+            ContextPage::ProjectDetails => context_drawer::context_drawer(
+                Self::project_details(app),
+                Message::CloseContextDrawer,
+            )
+            .title(fl!("project-details")),
+            ContextPage::Sessions => {
+                context_drawer::context_drawer(Self::sessions(app), Message::CloseContextDrawer)
+                    .title(fl!("sessions"))
+            }
+            ContextPage::CheckLog => {
+                context_drawer::context_drawer(Self::check_log(app), Message::CloseContextDrawer)
+                    .title(fl!("check-log"))
+            }
+            ContextPage::Jobs => {
+                context_drawer::context_drawer(Self::jobs(app), Message::CloseContextDrawer)
+                    .title(fl!("jobs"))
+            }
+            ContextPage::Trash => {
+                context_drawer::context_drawer(Self::trash(app), Message::CloseContextDrawer)
+                    .title(fl!("trash"))
+            }
+            ContextPage::Statistics => {
+                context_drawer::context_drawer(Self::statistics(app), Message::CloseContextDrawer)
+                    .title(fl!("statistics"))
+            }
+            ContextPage::NewProject => {
+                context_drawer::context_drawer(Self::new_project(app), Message::CloseContextDrawer)
+                    .title(fl!("new-project"))
+            }
         }
     }
     fn about(_app: &AppModel) -> Element<Message> {
@@ -59,15 +123,164 @@ impl ContextPage {
             .into()
     }
 
+    fn click_action(app: &AppModel, view_mode: ViewMode) -> Element<Message> {
+        widget::dropdown(
+            &app.click_action_labels,
+            ClickAction::ALL
+                .iter()
+                .position(|&action| action == app.config.click_action(view_mode)),
+            move |index| Message::ClickActionSelected(view_mode, index),
+        )
+        .into()
+    }
+
     fn settings(app: &AppModel) -> Element<Message> {
         let theme = theme::active();
         let cosmic_theme::Spacing { space_xs, .. } = theme.cosmic().spacing;
 
         widget::column()
             .push(Self::root_path(app, &theme))
+            .push(widget::text::text(fl!("settings-discovery-depth")))
+            .push(widget::dropdown(
+                &app.discovery_depth_labels,
+                DISCOVERY_DEPTHS
+                    .iter()
+                    .position(|&depth| depth == app.config.discovery_depth()),
+                Message::DiscoveryDepthSelected,
+            ))
+            .push(Self::ignore_patterns(app, &theme))
+            .push(Self::templates_path(app, &theme))
             .push(widget::divider::horizontal::default())
             .push(Self::program_input(app, &theme))
             .push(Self::programs(app, &theme))
+            .push(
+                widget::toggler(app.config.launch_mode() == LaunchMode::Tracked)
+                    .label(fl!("settings-track-launched"))
+                    .on_toggle(Message::TrackLaunchedToggled),
+            )
+            .push(
+                widget::toggler(app.config.start_in_project())
+                    .label(fl!("settings-start-in-project"))
+                    .on_toggle(Message::StartInProjectToggled),
+            )
+            .push(widget::text::text(fl!("settings-after-launch")))
+            .push(widget::dropdown(
+                &app.after_launch_labels,
+                AfterLaunch::ALL
+                    .iter()
+                    .position(|&after_launch| after_launch == app.config.after_launch()),
+                Message::AfterLaunchSelected,
+            ))
+            .push(Self::terminal(app, &theme))
+            .push(widget::divider::horizontal::default())
+            .push(Self::env_profiles(app, &theme))
+            .push(widget::divider::horizontal::default())
+            .push(widget::text::text(fl!("settings-usage-weight")))
+            .push(widget::dropdown(
+                &app.usage_weight_labels,
+                USAGE_WEIGHTS
+                    .iter()
+                    .position(|&weight| weight == app.config.usage_weight()),
+                Message::UsageWeightSelected,
+            ))
+            .push(widget::text::text(fl!("settings-max-project-age")))
+            .push(widget::dropdown(
+                &app.max_project_age_labels,
+                MAX_PROJECT_AGES
+                    .iter()
+                    .position(|&months| months == app.config.max_project_age()),
+                Message::MaxProjectAgeSelected,
+            ))
+            .push(widget::text::text(fl!("settings-archive-after")))
+            .push(widget::dropdown(
+                &app.archive_after_labels,
+                ARCHIVE_AFTER_MONTHS
+                    .iter()
+                    .position(|&months| months == app.config.archive_after()),
+                Message::ArchiveAfterSelected,
+            ))
+            .push(widget::text::text(fl!("settings-list-click-action")))
+            .push(Self::click_action(app, ViewMode::List))
+            .push(widget::text::text(fl!("settings-grid-click-action")))
+            .push(Self::click_action(app, ViewMode::Grid))
+            .push(
+                widget::toggler(app.config.hide_header_controls())
+                    .label(fl!("settings-hide-header-controls"))
+                    .on_toggle(Message::HideHeaderControlsToggled),
+            )
+            .push(
+                widget::toggler(app.config.reduce_motion())
+                    .label(fl!("settings-reduce-motion"))
+                    .on_toggle(Message::ReduceMotionToggled),
+            )
+            .push(
+                widget::toggler(app.config.color_blind_palette())
+                    .label(fl!("settings-color-blind-palette"))
+                    .on_toggle(Message::ColorBlindPaletteToggled),
+            )
+            .push(
+                widget::toggler(app.config.show_project_icons())
+                    .label(fl!("settings-show-project-icons"))
+                    .on_toggle(Message::ShowProjectIconsToggled),
+            )
+            .push(
+                widget::toggler(app.config.show_footer())
+                    .label(fl!("settings-show-footer"))
+                    .on_toggle(Message::ShowFooterToggled),
+            )
+            .push(
+                widget::toggler(app.config.show_ownership())
+                    .label(fl!("settings-show-ownership"))
+                    .on_toggle(Message::ShowOwnershipToggled),
+            )
+            .push(
+                widget::toggler(app.config.security_audit())
+                    .label(fl!("settings-security-audit"))
+                    .on_toggle(Message::SecurityAuditToggled),
+            )
+            .push(
+                widget::toggler(app.config.ignore_power_state())
+                    .label(fl!("settings-ignore-power-state"))
+                    .on_toggle(Message::IgnorePowerStateToggled),
+            )
+            .push(widget::text::text(fl!("settings-job-concurrency")))
+            .push(widget::dropdown(
+                &app.job_concurrency_labels,
+                JOB_CONCURRENCY_LIMITS
+                    .iter()
+                    .position(|&limit| limit == app.config.job_concurrency()),
+                Message::JobConcurrencySelected,
+            ))
+            .push_maybe(
+                app.background_paused()
+                    .then(|| widget::text::caption(fl!("settings-background-paused"))),
+            )
+            .push(widget::divider::horizontal::default())
+            .push(Self::checks(app, &theme, CheckKind::Lint))
+            .push(widget::divider::horizontal::default())
+            .push(Self::checks(app, &theme, CheckKind::Health))
+            .push(widget::text::text(fl!("settings-health-check-interval")))
+            .push(widget::dropdown(
+                &app.health_check_interval_labels,
+                HEALTH_CHECK_INTERVALS
+                    .iter()
+                    .position(|&minutes| minutes == app.config.health_check_interval()),
+                Message::HealthCheckIntervalSelected,
+            ))
+            .push(widget::divider::horizontal::default())
+            .push(Self::automations(app, &theme))
+            .push(widget::divider::horizontal::default())
+            .push(Self::rules(app, &theme))
+            .push(widget::divider::horizontal::default())
+            .push(Self::dock_pins(app, &theme))
+            .push(widget::divider::horizontal::default())
+            .push(Self::saved_searches(app, &theme))
+            .push(widget::divider::horizontal::default())
+            .push(Self::shortcuts(app, &theme))
+            .push(widget::divider::horizontal::default())
+            .push(
+                widget::button::standard(fl!("config-validate")).on_press(Message::ValidateConfig),
+            )
             .spacing(space_xs)
             .into()
     }
@@ -80,12 +293,97 @@ impl ContextPage {
         let input = widget::text_input(fl!("settings-path-placeholder"), &app.root_path_input)
             .on_input(Message::RootPathInputChanged);
 
+        let label_input = widget::text_input(
+            fl!("settings-root-label-placeholder"),
+            &app.root_label_input,
+        )
+        .on_input(Message::RootLabelInputChanged);
+
         let mut save = widget::button::text(fl!("save"));
 
         if path_buf.exists() {
             save = save.on_press(Message::RootPathSave(path_buf));
         }
 
+        let pick =
+            widget::button::standard(fl!("settings-root-pick")).on_press(Message::RootPathPick);
+
+        widget::column()
+            .push(
+                widget::row()
+                    .push(input)
+                    .push(pick)
+                    .align_y(Alignment::Center)
+                    .spacing(space_xxs),
+            )
+            .push(label_input)
+            .push(save)
+            .spacing(space_xxs)
+            .into()
+    }
+
+    fn ignore_patterns<'a>(app: &'a AppModel, theme: &cosmic::Theme) -> Element<'a, Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme.cosmic().spacing;
+
+        let input = widget::text_input(
+            fl!("settings-ignore-pattern-placeholder"),
+            &app.ignore_pattern_input,
+        )
+        .on_input(Message::IgnorePatternInputChanged)
+        .on_submit(Message::IgnorePatternAdd);
+
+        let mut column = widget::column()
+            .push(widget::text::text(fl!("settings-ignore-patterns")))
+            .push(
+                widget::row()
+                    .push(input)
+                    .push(widget::button::text(fl!("add")).on_press(Message::IgnorePatternAdd))
+                    .align_y(Alignment::Center)
+                    .spacing(space_xxs),
+            )
+            .spacing(space_xxs);
+
+        for (index, pattern) in app.config.ignore_patterns().into_iter().enumerate() {
+            let delete_button =
+                widget::button::icon(widget::icon::from_name("edit-delete-symbolic"))
+                    .on_press(Message::IgnorePatternDelete(index));
+
+            column = column.push(
+                widget::row()
+                    .push(widget::text::text(pattern).width(Length::Fill))
+                    .push(delete_button)
+                    .align_y(Alignment::Center)
+                    .spacing(space_xxs),
+            );
+        }
+
+        if !app.config.has_default_ignore_patterns() {
+            column = column.push(
+                widget::button::text(fl!("settings-ignore-patterns-reset"))
+                    .on_press(Message::IgnorePatternsReset),
+            );
+        }
+
+        column.into()
+    }
+
+    fn templates_path<'a>(app: &'a AppModel, theme: &cosmic::Theme) -> Element<'a, Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme.cosmic().spacing;
+
+        let path_buf = PathBuf::from(&app.templates_path_input);
+
+        let input = widget::text_input(
+            fl!("settings-templates-path-placeholder"),
+            &app.templates_path_input,
+        )
+        .on_input(Message::TemplatesPathInputChanged);
+
+        let mut save = widget::button::text(fl!("save"));
+
+        if path_buf.is_dir() {
+            save = save.on_press(Message::TemplatesPathSave(path_buf));
+        }
+
         widget::column()
             .push(input)
             .push(save)
@@ -93,6 +391,95 @@ impl ContextPage {
             .into()
     }
 
+    fn terminal<'a>(app: &'a AppModel, theme: &cosmic::Theme) -> Element<'a, Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme.cosmic().spacing;
+
+        let input = widget::text_input(fl!("settings-terminal-placeholder"), &app.terminal_input)
+            .on_input(Message::TerminalInputChanged)
+            .on_submit(Message::TerminalSave);
+
+        widget::column()
+            .push(widget::text::text(fl!("settings-terminal")))
+            .push(input)
+            .push(widget::button::text(fl!("save")).on_press(Message::TerminalSave))
+            .spacing(space_xxs)
+            .into()
+    }
+
+    fn env_profiles<'a>(app: &'a AppModel, theme: &cosmic::Theme) -> Element<'a, Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme.cosmic().spacing;
+
+        let input = widget::text_input(
+            fl!("settings-env-profile-name-placeholder"),
+            &app.env_profile_name_input,
+        )
+        .on_input(Message::EnvProfileNameInputChanged)
+        .on_submit(Message::EnvProfileAdd);
+
+        let add = widget::button::text(fl!("add")).on_press_maybe(
+            app.is_valid_env_profile_name()
+                .then_some(Message::EnvProfileAdd),
+        );
+
+        let mut column = widget::column()
+            .push(widget::text::heading(fl!("settings-env-profiles")))
+            .push(widget::text::caption(fl!(
+                "settings-env-profiles-description"
+            )))
+            .push(input)
+            .push(add)
+            .spacing(space_xxs);
+
+        for profile in app.config.env_profiles() {
+            let name = profile.name().to_string();
+
+            let delete_button =
+                widget::button::icon(widget::icon::from_name("edit-delete-symbolic"))
+                    .on_press(Message::EnvProfileDelete(name.clone()));
+
+            column = column.push(widget::divider::horizontal::light()).push(
+                widget::row()
+                    .push(widget::text::text(profile.name()).width(Length::Fill))
+                    .push(delete_button)
+                    .align_y(Alignment::Center)
+                    .spacing(space_xxs),
+            );
+
+            for (key, _) in profile.vars() {
+                let remove_button =
+                    widget::button::icon(widget::icon::from_name("list-remove-symbolic"))
+                        .on_press(Message::EnvVarRemove(name.clone(), key.clone()));
+
+                // values often are credentials, so only the names are shown
+                column = column.push(
+                    widget::row()
+                        .push(widget::text::caption(key).width(Length::Fill))
+                        .push(remove_button)
+                        .align_y(Alignment::Center)
+                        .spacing(space_xxs),
+                );
+            }
+
+            let var_input = app.env_var_inputs.get(&name).map_or("", String::as_str);
+            let valid = env_profile::parse_var(var_input).is_some();
+
+            column = column.push(
+                widget::text_input(fl!("settings-env-var-placeholder"), var_input)
+                    .on_input({
+                        let name = name.clone();
+                        move |input| Message::EnvVarInputChanged(name.clone(), input)
+                    })
+                    .on_submit(Message::EnvVarAdd(name.clone())),
+            );
+            column = column.push(
+                widget::button::text(fl!("add"))
+                    .on_press_maybe(valid.then(|| Message::EnvVarAdd(name))),
+            );
+        }
+
+        column.into()
+    }
+
     fn program_input<'a>(app: &'a AppModel, theme: &cosmic::Theme) -> Element<'a, Message> {
         let cosmic_theme::Spacing { space_xxs, .. } = theme.cosmic().spacing;
 
@@ -100,6 +487,7 @@ impl ContextPage {
             fl!("settings-program-command-placeholder"),
             &app.program_command_input,
         )
+        .id(app.program_input_id.clone())
         .on_input(Message::ProgramCommandInputChanged);
 
         let name_input = widget::text_input(
@@ -107,16 +495,131 @@ impl ContextPage {
             &app.program_name_input,
         )
         .on_input(Message::ProgramNameInputChanged);
-        let mut add = widget::button::text(fl!("add"));
 
-        if app.is_valid_program() {
-            add = add.on_press(Message::ProgramSave);
+        let icon_input = widget::text_input(
+            fl!("settings-program-icon-placeholder"),
+            &app.program_icon_input,
+        )
+        .on_input(Message::ProgramIconInputChanged);
+        let add = match app.program_edit {
+            Some(_) => widget::button::text(fl!("save")),
+            None => widget::button::text(fl!("add")),
+        }
+        .on_press_maybe(app.is_valid_program().then_some(Message::ProgramSave));
+
+        let mut env = widget::column().spacing(space_xxs);
+
+        for (key, _) in &app.program_env {
+            env = env.push(
+                widget::row()
+                    .push(widget::text::caption(key).width(Length::Fill))
+                    .push(
+                        widget::button::icon(widget::icon::from_name("list-remove-symbolic"))
+                            .on_press(Message::ProgramEnvRemove(key.clone())),
+                    )
+                    .align_y(Alignment::Center)
+                    .spacing(space_xxs),
+            );
         }
 
+        let env_valid = env_profile::parse_var(&app.program_env_input).is_some();
+
+        env = env.push(
+            widget::row()
+                .push(
+                    widget::text_input(fl!("settings-env-var-placeholder"), &app.program_env_input)
+                        .on_input(Message::ProgramEnvInputChanged)
+                        .on_submit(Message::ProgramEnvAdd),
+                )
+                .push(
+                    widget::button::text(fl!("add"))
+                        .on_press_maybe(env_valid.then_some(Message::ProgramEnvAdd)),
+                )
+                .align_y(Alignment::Center)
+                .spacing(space_xxs),
+        );
+
+        let cancel = app
+            .program_edit
+            .is_some()
+            .then(|| widget::button::text(fl!("cancel")).on_press(Message::ProgramEditCancel));
+
+        let desktop_entry = widget::dropdown(
+            &app.desktop_entry_labels,
+            app.program_desktop_entry,
+            Message::ProgramDesktopEntrySelected,
+        );
+
+        // desktop entries and Flatpak apps bring their own window
+        let run_in_terminal =
+            (app.program_desktop_entry.is_none() && app.program_flatpak.is_none()).then(|| {
+                widget::toggler(app.program_run_in_terminal)
+                    .label(fl!("settings-program-run-in-terminal"))
+                    .on_toggle(Message::ProgramRunInTerminalToggled)
+            });
+
+        let flatpak_app = (!app.flatpak_apps.is_empty()).then(|| {
+            widget::dropdown(
+                &app.flatpak_app_labels,
+                app.program_flatpak,
+                Message::ProgramFlatpakSelected,
+            )
+        });
+
+        let unknown_placeholders = app
+            .program_desktop_entry
+            .is_none()
+            .then(|| command::unknown_placeholders(&app.program_command_input))
+            .filter(|unknown| !unknown.is_empty())
+            .map(|unknown| {
+                unknown
+                    .iter()
+                    .map(|name| format!("%{name}%"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            });
+
+        let installed_apps = widget::button::text(fl!("installed-apps-open")).on_press_maybe(
+            (!app.desktop_entries.is_empty()).then_some(Message::OpenInstalledApps),
+        );
+
         widget::column()
+            .push(widget::text::caption(fl!("settings-program-desktop-entry")))
+            .push(desktop_entry)
+            .push(installed_apps)
+            .push_maybe(
+                flatpak_app
+                    .is_some()
+                    .then(|| widget::text::caption(fl!("settings-program-flatpak"))),
+            )
+            .push_maybe(flatpak_app)
             .push(command_input)
+            .push(widget::text::caption(fl!("settings-program-placeholders")))
+            .push_maybe(unknown_placeholders.map(|placeholders| {
+                widget::text::caption(fl!(
+                    "settings-program-unknown-placeholders",
+                    placeholders = placeholders
+                ))
+            }))
+            .push_maybe(run_in_terminal)
             .push(name_input)
-            .push(add)
+            .push(icon_input)
+            .push(widget::text::caption(fl!("settings-program-start")))
+            .push(widget::dropdown(
+                &app.start_in_project_labels,
+                START_IN_PROJECT_OPTIONS
+                    .iter()
+                    .position(|&option| option == app.program_start_in_project),
+                Message::ProgramStartInProjectSelected,
+            ))
+            .push(widget::text::caption(fl!("settings-program-env")))
+            .push(env)
+            .push(
+                widget::row()
+                    .push(add)
+                    .push_maybe(cancel)
+                    .spacing(space_xxs),
+            )
             .spacing(space_xxs)
             .into()
     }
@@ -124,35 +627,1579 @@ impl ContextPage {
     fn programs<'a>(app: &'a AppModel, theme: &cosmic::Theme) -> Element<'a, Message> {
         let cosmic_theme::Spacing { space_xxs, .. } = theme.cosmic().spacing;
 
-        let mut column = widget::column().spacing(space_xxs);
+        let share = widget::row()
+            .push(
+                widget::button::text(fl!("programs-export"))
+                    .on_press_maybe((!app.programs.is_empty()).then_some(Message::ProgramsExport)),
+            )
+            .push(widget::button::text(fl!("programs-import")).on_press(Message::ProgramsImport))
+            .spacing(space_xxs);
 
-        for program in &app.programs {
+        let mut column = widget::column().push(share).spacing(space_xxs);
+
+        for (index, program) in app.programs.iter().enumerate() {
             column = column.push(widget::divider::horizontal::light());
-            column = column.push(Self::program(app, theme, program));
+            column = column.push(Self::program(app, theme, index, program));
+        }
+
+        column.into()
+    }
+
+    fn checks<'a>(
+        app: &'a AppModel,
+        theme: &cosmic::Theme,
+        kind: CheckKind,
+    ) -> Element<'a, Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme.cosmic().spacing;
+
+        let title = match kind {
+            CheckKind::Lint => fl!("settings-lint-checks"),
+            CheckKind::Health => fl!("settings-health-checks"),
+        };
+
+        let marker_input = widget::text_input(
+            fl!("settings-check-marker-placeholder"),
+            app.check_marker_inputs
+                .get(&kind)
+                .map(String::as_str)
+                .unwrap_or_default(),
+        )
+        .on_input(move |marker| Message::CheckMarkerInputChanged(kind, marker));
+
+        let command_input = widget::text_input(
+            fl!("settings-check-command-placeholder"),
+            app.check_command_inputs
+                .get(&kind)
+                .map(String::as_str)
+                .unwrap_or_default(),
+        )
+        .on_input(move |command| Message::CheckCommandInputChanged(kind, command));
+
+        let mut column = widget::column()
+            .push(widget::text::heading(title))
+            .push(marker_input)
+            .push(command_input)
+            .push(widget::button::text(fl!("add")).on_press(Message::CheckAdd(kind)))
+            .spacing(space_xxs);
+
+        for (index, check) in app.config.checks(kind).iter().enumerate() {
+            let delete_button =
+                widget::button::icon(widget::icon::from_name("edit-delete-symbolic"))
+                    .on_press(Message::CheckDelete(kind, index));
+
+            column = column.push(widget::divider::horizontal::light()).push(
+                widget::row()
+                    .push(
+                        widget::column()
+                            .push(widget::text::text(check.command()))
+                            .push(widget::text::caption(check.marker()))
+                            .width(Length::Fill),
+                    )
+                    .push(delete_button)
+                    .align_y(Alignment::Center)
+                    .spacing(space_xxs),
+            );
+        }
+
+        column.into()
+    }
+
+    fn automations<'a>(app: &'a AppModel, theme: &cosmic::Theme) -> Element<'a, Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme.cosmic().spacing;
+
+        let command_input = widget::text_input(
+            fl!("settings-automation-command-placeholder"),
+            &app.automation_command_input,
+        )
+        .on_input(Message::AutomationCommandInputChanged);
+
+        let mut column = widget::column()
+            .push(widget::text::heading(fl!("settings-automations")))
+            .push(widget::text::caption(fl!(
+                "settings-automations-description"
+            )))
+            .push(widget::dropdown(
+                &app.automation_event_labels,
+                Some(app.automation_event),
+                Message::AutomationEventSelected,
+            ))
+            .push(command_input)
+            .push(widget::button::text(fl!("add")).on_press(Message::AutomationAdd))
+            .spacing(space_xxs);
+
+        for (index, automation) in app.config.automations().iter().enumerate() {
+            let event = AutomationEvent::ALL
+                .iter()
+                .position(|event| *event == automation.event())
+                .and_then(|index| app.automation_event_labels.get(index))
+                .map(String::as_str)
+                .unwrap_or_default();
+
+            let delete_button =
+                widget::button::icon(widget::icon::from_name("edit-delete-symbolic"))
+                    .on_press(Message::AutomationDelete(index));
+
+            column = column.push(widget::divider::horizontal::light()).push(
+                widget::row()
+                    .push(
+                        widget::column()
+                            .push(widget::text::text(automation.command()))
+                            .push(widget::text::caption(event))
+                            .width(Length::Fill),
+                    )
+                    .push(delete_button)
+                    .align_y(Alignment::Center)
+                    .spacing(space_xxs),
+            );
+        }
+
+        column.into()
+    }
+
+    fn rules<'a>(app: &'a AppModel, theme: &cosmic::Theme) -> Element<'a, Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme.cosmic().spacing;
+
+        let pattern_input = widget::text_input(
+            fl!("settings-rule-pattern-placeholder"),
+            &app.rule_pattern_input,
+        )
+        .on_input(Message::RulePatternInputChanged);
+
+        let value: Element<'a, Message> = match &app.rule_action {
+            RuleAction::Kind(kind) => widget::dropdown(
+                &app.rule_kind_labels,
+                ProjectKind::ALL.iter().position(|option| option == kind),
+                Message::RuleKindSelected,
+            )
+            .into(),
+            RuleAction::Tag(value) | RuleAction::DisplayName(value) => {
+                widget::text_input(fl!("settings-rule-value-placeholder"), value)
+                    .on_input(Message::RuleValueInputChanged)
+                    .on_submit(Message::RuleAdd)
+                    .into()
+            }
+        };
+
+        let pattern = app.rule_pattern_input.trim();
+        let pattern_error = Rule::pattern_error(RuleSyntax::ALL[app.rule_syntax], pattern)
+            .filter(|_| !pattern.is_empty());
+        let can_add =
+            !pattern.is_empty() && pattern_error.is_none() && app.rule_action.is_complete();
+
+        let mut column = widget::column()
+            .push(widget::text::heading(fl!("settings-rules")))
+            .push(widget::text::caption(fl!("settings-rules-description")))
+            .push(
+                widget::row()
+                    .push(widget::dropdown(
+                        &app.rule_target_labels,
+                        Some(app.rule_target),
+                        Message::RuleTargetSelected,
+                    ))
+                    .push(widget::dropdown(
+                        &app.rule_syntax_labels,
+                        Some(app.rule_syntax),
+                        Message::RuleSyntaxSelected,
+                    ))
+                    .push(pattern_input)
+                    .align_y(Alignment::Center)
+                    .spacing(space_xxs),
+            )
+            .push(
+                widget::row()
+                    .push(widget::dropdown(
+                        &app.rule_action_labels,
+                        RuleAction::all()
+                            .iter()
+                            .position(|action| action.same_kind(&app.rule_action)),
+                        Message::RuleActionSelected,
+                    ))
+                    .push(value)
+                    .align_y(Alignment::Center)
+                    .spacing(space_xxs),
+            )
+            .push_maybe(pattern_error.map(|err| {
+                widget::text::caption(fl!("settings-rule-pattern-invalid", error = err))
+            }))
+            .push(
+                widget::button::text(fl!("add"))
+                    .on_press_maybe(can_add.then_some(Message::RuleAdd)),
+            )
+            .spacing(space_xxs);
+
+        for (index, rule) in app.config.rules().iter().enumerate() {
+            let target = match rule.target() {
+                RuleTarget::Name => fl!("rule-target-name"),
+                RuleTarget::Path => fl!("rule-target-path"),
+            };
+            let syntax = match rule.syntax() {
+                RuleSyntax::Glob => fl!("rule-syntax-glob"),
+                RuleSyntax::Regex => fl!("rule-syntax-regex"),
+            };
+
+            let action = match rule.action() {
+                RuleAction::Tag(tag) => fl!("rule-assigns-tag", tag = tag.as_str()),
+                RuleAction::Kind(kind) => fl!("rule-assigns-kind", kind = kind.label()),
+                RuleAction::DisplayName(name) => {
+                    fl!("rule-assigns-display-name", name = name.as_str())
+                }
+            };
+
+            let delete_button =
+                widget::button::icon(widget::icon::from_name("edit-delete-symbolic"))
+                    .on_press(Message::RuleDelete(index));
+
+            column = column.push(widget::divider::horizontal::light()).push(
+                widget::row()
+                    .push(
+                        widget::column()
+                            .push(widget::text::text(format!(
+                                "{target} {syntax} {}",
+                                rule.pattern()
+                            )))
+                            .push(widget::text::caption(action))
+                            .width(Length::Fill),
+                    )
+                    .push(delete_button)
+                    .align_y(Alignment::Center)
+                    .spacing(space_xxs),
+            );
+        }
+
+        column.into()
+    }
+
+    fn shortcuts<'a>(app: &'a AppModel, theme: &cosmic::Theme) -> Element<'a, Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme.cosmic().spacing;
+
+        let mut column = widget::column()
+            .push(widget::text::heading(fl!("settings-shortcuts")))
+            .push(widget::text::caption(fl!("settings-shortcuts-hint")))
+            .spacing(space_xxs);
+
+        for shortcut in Shortcut::all() {
+            let key_bind = app.key_binds.get(shortcut);
+
+            let keys = if app.recording_shortcut == Some(shortcut) {
+                fl!("settings-shortcut-recording")
+            } else {
+                key_bind.map_or_else(|| fl!("settings-shortcut-none"), ToString::to_string)
+            };
+
+            let label = match shortcut {
+                Shortcut::Menu(_) => shortcut.label(),
+                Shortcut::Project(_) => fl!("settings-shortcut-project", action = shortcut.label()),
+            };
+
+            let mut row = widget::row()
+                .push(widget::text::text(label).width(Length::Fill))
+                .push(widget::button::text(keys).on_press(Message::ShortcutRecord(shortcut)))
+                .align_y(Alignment::Center)
+                .spacing(space_xxs);
+
+            if key_bind != shortcut.default_key_bind().as_ref() {
+                row = row.push(
+                    widget::button::icon(widget::icon::from_name("edit-undo-symbolic"))
+                        .on_press(Message::ShortcutReset(shortcut)),
+                );
+            }
+
+            if key_bind.is_some() {
+                row = row.push(
+                    widget::button::icon(widget::icon::from_name("edit-delete-symbolic"))
+                        .on_press(Message::ShortcutClear(shortcut)),
+                );
+            }
+
+            column = column.push(row);
+        }
+
+        column.into()
+    }
+
+    fn dock_pins<'a>(app: &'a AppModel, theme: &cosmic::Theme) -> Element<'a, Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme.cosmic().spacing;
+
+        let mut column = widget::column()
+            .push(widget::text::heading(fl!("settings-dock-pins")))
+            .spacing(space_xxs);
+
+        if app.config.dock_pins().is_empty() {
+            column = column.push(widget::text::caption(fl!("settings-dock-pins-empty")));
+        }
+
+        for pin in app.config.dock_pins() {
+            let remove_button =
+                widget::button::icon(widget::icon::from_name("edit-delete-symbolic"))
+                    .on_press(Message::DockPinRemove(pin.clone()));
+
+            column = column.push(widget::divider::horizontal::light()).push(
+                widget::row()
+                    .push(
+                        widget::text::text(fl!(
+                            "session-entry",
+                            project = pin.project().to_string_lossy().to_string(),
+                            program = pin.program()
+                        ))
+                        .width(Length::Fill),
+                    )
+                    .push(remove_button)
+                    .align_y(Alignment::Center)
+                    .spacing(space_xxs),
+            );
+        }
+
+        column.into()
+    }
+
+    fn saved_searches<'a>(app: &'a AppModel, theme: &cosmic::Theme) -> Element<'a, Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme.cosmic().spacing;
+
+        let mut column = widget::column()
+            .push(widget::text::heading(fl!("settings-saved-searches")))
+            .spacing(space_xxs);
+
+        if app.config.saved_searches().is_empty() {
+            column = column.push(widget::text::caption(fl!("settings-saved-searches-empty")));
+        }
+
+        for search in app.config.saved_searches() {
+            let delete_button =
+                widget::button::icon(widget::icon::from_name("edit-delete-symbolic"))
+                    .on_press(Message::SavedSearchDelete(search.clone()));
+
+            column = column.push(
+                widget::row()
+                    .push(widget::text::text(search.as_str()).width(Length::Fill))
+                    .push(delete_button)
+                    .align_y(Alignment::Center)
+                    .spacing(space_xxs),
+            );
         }
 
         column.into()
     }
 
     fn program<'a>(
-        _app: &'a AppModel,
+        app: &'a AppModel,
         theme: &cosmic::Theme,
+        index: usize,
         program: &'a Program,
     ) -> Element<'a, Message> {
         let cosmic_theme::Spacing { space_xxs, .. } = theme.cosmic().spacing;
 
-        let name = widget::text::text(program.name());
-        let command = widget::text::caption(program.command());
+        let mut name = widget::row()
+            .push_maybe(program_icon(program).map(|icon| widget::icon(icon).size(16)))
+            .push(widget::text::text(program.name()))
+            .align_y(Alignment::Center)
+            .spacing(space_xxs);
+
+        if app.is_program_broken(program.name()) {
+            name = name
+                .push(widget::icon::from_name("dialog-warning-symbolic").size(16))
+                .push(widget::text::caption(fl!(
+                    "program-missing",
+                    command = program.command()
+                )));
+        }
+
+        let command = if program.run_in_terminal() {
+            widget::text::caption(fl!("program-in-terminal", command = program.command()))
+        } else {
+            widget::text::caption(program.command())
+        };
 
         let column = widget::column().push(name).push(command);
 
+        let mnemonic = program
+            .mnemonic()
+            .and_then(|mnemonic| MNEMONIC_KEYS.find(mnemonic))
+            .map_or(0, |index| index + 1);
+
+        let program_name = program.name().to_string();
+        let mnemonic_dropdown =
+            widget::dropdown(&app.mnemonic_labels, Some(mnemonic), move |index| {
+                Message::ProgramMnemonicSelected(program_name.clone(), index)
+            });
+
+        // the order of the programs is the order of the launch buttons
+        let up_button = widget::button::icon(widget::icon::from_name("go-up-symbolic"))
+            .on_press_maybe((index > 0).then_some(Message::ProgramMoveUp(index)));
+
+        let down_button = widget::button::icon(widget::icon::from_name("go-down-symbolic"))
+            .on_press_maybe(
+                (index + 1 < app.programs.len()).then_some(Message::ProgramMoveDown(index)),
+            );
+
+        let edit_button = widget::button::icon(widget::icon::from_name("document-edit-symbolic"))
+            .on_press(Message::ProgramEdit(program.name().to_string()));
+
         let delete_button = widget::button::icon(widget::icon::from_name("edit-delete-symbolic"))
             .on_press(Message::ProgramDelete(program.name().to_string()));
 
+        let is_default = app.config.default_program() == Some(program.name());
+
+        let default_button = if is_default {
+            widget::button::icon(widget::icon::from_name("starred-symbolic"))
+        } else {
+            widget::button::icon(widget::icon::from_name("non-starred-symbolic"))
+                .on_press(Message::DefaultProgramSelected(program.name().to_string()))
+        };
+
         widget::row()
             .spacing(space_xxs)
             .push(column)
+            .push(widget::tooltip(
+                mnemonic_dropdown,
+                widget::text::text(fl!("settings-program-mnemonic")),
+                widget::tooltip::Position::Bottom,
+            ))
+            .push(widget::tooltip(
+                default_button,
+                widget::text::text(fl!("program-default")),
+                widget::tooltip::Position::Bottom,
+            ))
+            .push(up_button)
+            .push(down_button)
+            .push(edit_button)
             .push(delete_button)
             .into()
     }
+
+    fn project_details(app: &AppModel) -> Element<Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        let Some(project) = app.details_project() else {
+            return widget::text::text(fl!("project-details-missing")).into();
+        };
+
+        let mut column = widget::column()
+            .push(widget::text::title4(project.label()))
+            .push(widget::text::caption(app.project_location(project)))
+            .push_maybe(app.snapshot.is_none().then(|| {
+                widget::row()
+                    .push(
+                        widget::button::standard(fl!("open-with"))
+                            .on_press(Message::OpenWith(project.path().clone())),
+                    )
+                    .push(
+                        widget::button::standard(fl!("open-folder"))
+                            .on_press(Message::OpenFolder(project.path().clone())),
+                    )
+                    .spacing(space_xxs)
+            }))
+            .spacing(space_xxs);
+
+        if project.is_read_only() {
+            column = column.push(widget::text::caption(fl!("project-read-only")));
+        }
+
+        match project.lock_reason() {
+            Some("") => column = column.push(widget::text::caption(fl!("project-locked"))),
+            Some(reason) => {
+                column = column.push(widget::text::caption(fl!(
+                    "project-locked-reason",
+                    reason = reason
+                )))
+            }
+            None => {}
+        }
+
+        if let Some((_, Some(release))) = app.releases.get(project.path()) {
+            column = column.push(widget::text::caption(match release.released() {
+                Some(released) => fl!(
+                    "project-details-release-days",
+                    version = release.version(),
+                    days = digest::now().saturating_sub(released) / (24 * 60 * 60)
+                ),
+                None => fl!("project-details-release", version = release.version()),
+            }));
+
+            if let Some(commits) = release.unreleased_commits() {
+                column = column.push(widget::text::caption(fl!(
+                    "project-details-unreleased-commits",
+                    count = commits
+                )));
+            }
+        }
+
+        if !project.alternate_paths().is_empty() {
+            column = column
+                .push(widget::divider::horizontal::light())
+                .push(widget::text::heading(fl!(
+                    "project-details-alternate-paths"
+                )));
+
+            for path in project.alternate_paths() {
+                column = column.push(widget::text::caption(path.to_string_lossy()));
+            }
+        }
+
+        if let Some(ownership) = app.ownership.get(project.path()) {
+            let people = [
+                (ownership.owner(), fl!("project-details-owner")),
+                (
+                    ownership.last_committer(),
+                    fl!("project-details-last-committer"),
+                ),
+            ];
+
+            for (name, label) in people {
+                let Some(name) = name else {
+                    continue;
+                };
+
+                column = column.push(
+                    widget::row()
+                        .push(widget::text::caption(label))
+                        .push(
+                            widget::button::link(name.to_string())
+                                .on_press(Message::SearchTextInputChanged(format!("@{name}")))
+                                .padding(0),
+                        )
+                        .align_y(Alignment::Center)
+                        .spacing(space_xxs),
+                );
+            }
+        }
+
+        match app.audit(project) {
+            Some(Ok(vulnerabilities)) if !vulnerabilities.is_empty() => {
+                column =
+                    column
+                        .push(widget::divider::horizontal::light())
+                        .push(widget::text::heading(fl!(
+                            "badge-vulnerabilities",
+                            count = vulnerabilities.len()
+                        )));
+
+                for vulnerability in vulnerabilities {
+                    column = column.push(widget::text::caption(format!(
+                        "{} – {}",
+                        vulnerability.package(),
+                        vulnerability.title()
+                    )));
+                }
+            }
+            Some(Err(err)) => {
+                column =
+                    column
+                        .push(widget::divider::horizontal::light())
+                        .push(widget::text::caption(fl!(
+                            "project-details-audit-failed",
+                            error = err.as_str()
+                        )));
+            }
+            _ => {}
+        }
+
+        if let Some(results) = app.check_results(CheckKind::Lint, project) {
+            if !check::all_passed(results) {
+                column = column
+                    .push(widget::divider::horizontal::light())
+                    .push(widget::text::heading(fl!("badge-lint-failing")));
+
+                for result in results.iter().filter(|result| !result.success()) {
+                    column = column
+                        .push(widget::text::text(result.command()))
+                        .push(widget::text::caption(result.output()));
+                }
+            }
+        }
+
+        if !app.config.checks(CheckKind::Health).is_empty()
+            && app.is_allowed(&Message::RunChecks(CheckKind::Health, None))
+        {
+            let mut run = widget::button::text(fl!("project-details-run-health-checks"));
+
+            if !app.is_checking(CheckKind::Health, project.path()) {
+                run = run.on_press(Message::RunChecks(
+                    CheckKind::Health,
+                    Some(project.path().clone()),
+                ));
+            }
+
+            column = column.push(widget::divider::horizontal::light()).push(
+                widget::row()
+                    .push(run)
+                    .push(
+                        widget::button::text(fl!("check-log"))
+                            .on_press(Message::OpenContextDrawer(ContextPage::CheckLog)),
+                    )
+                    .spacing(space_xxs),
+            );
+        }
+
+        if !app.details_badges.is_empty() {
+            let badges = app
+                .details_badges
+                .iter()
+                .map(|badge| {
+                    let kind = match badge.kind() {
+                        ReadmeBadgeKind::Build => fl!("readme-badge-build"),
+                        ReadmeBadgeKind::Coverage => fl!("readme-badge-coverage"),
+                        ReadmeBadgeKind::Version => fl!("readme-badge-version"),
+                        ReadmeBadgeKind::Other => fl!("readme-badge-other"),
+                    };
+
+                    let label = if badge.label().is_empty() {
+                        kind
+                    } else {
+                        format!("{kind}: {}", badge.label())
+                    };
+
+                    widget::button::link(label)
+                        .on_press_maybe(
+                            badge
+                                .link()
+                                .map(|link| Message::LaunchUrl(link.to_string())),
+                        )
+                        .padding(0)
+                        .into()
+                })
+                .collect();
+
+            column = column
+                .push(widget::divider::horizontal::light())
+                .push(widget::text::heading(fl!("project-details-readme-badges")))
+                .push(
+                    widget::flex_row(badges)
+                        .column_spacing(space_xxs)
+                        .row_spacing(space_xxs),
+                );
+        }
+
+        let tags = app.annotation(project).tags().to_vec();
+
+        if !tags.is_empty() && app.is_allowed(&Message::BulkLaunchTag(String::new())) {
+            let bulk_launches = tags
+                .into_iter()
+                .map(|tag| {
+                    widget::button::text(fl!("bulk-launch-tag", tag = tag.as_str()))
+                        .on_press(Message::BulkLaunchTag(tag))
+                        .into()
+                })
+                .collect();
+
+            column = column.push(widget::divider::horizontal::light()).push(
+                widget::flex_row(bulk_launches)
+                    .column_spacing(space_xxs)
+                    .row_spacing(space_xxs),
+            );
+        }
+
+        if let Some(changes) = &app.details_changes {
+            column = column
+                .push(widget::divider::horizontal::light())
+                .push(Self::changed_files(
+                    fl!("project-details-modified-files"),
+                    project.path(),
+                    changes.modified(),
+                ))
+                .push(Self::changed_files(
+                    fl!("project-details-last-commit-files"),
+                    project.path(),
+                    changes.last_commit(),
+                ));
+        }
+
+        if app.kiosk || app.snapshot.is_some() {
+            return column.into();
+        }
+
+        let mut trash = widget::button::destructive(fl!("project-trash"));
+
+        if !project.is_protected() && !app.root_read_only {
+            trash = trash.on_press(Message::ProjectTrash(project.path().clone()));
+        }
+
+        column
+            .push(widget::divider::horizontal::light())
+            .push(
+                widget::row()
+                    .push(
+                        widget::button::standard(fl!("project-hide"))
+                            .on_press(Message::ProjectHide(project.path().clone())),
+                    )
+                    .push(trash)
+                    .spacing(space_xxs),
+            )
+            .push(widget::divider::horizontal::light())
+            .push(Self::tags(app, project))
+            .push(widget::divider::horizontal::light())
+            .push(Self::project_default_program(app, project.path()))
+            .push_maybe((!app.programs.is_empty()).then(widget::divider::horizontal::light))
+            .push_maybe(Self::project_dock_pins(app, project.path()))
+            .push_maybe(
+                (!app.config.env_profiles().is_empty()).then(widget::divider::horizontal::light),
+            )
+            .push_maybe(Self::project_env_profile(app, project.path()))
+            .push(widget::divider::horizontal::light())
+            .push(Self::launch_overrides(app, project.path()))
+            .push(widget::divider::horizontal::light())
+            .push(Self::project_sessions(app, project.path()))
+            .into()
+    }
+
+    fn project_default_program<'a>(app: &'a AppModel, project: &Path) -> Element<'a, Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        let chosen = app.config.project_default_program(project);
+        let select = |name: Option<&str>| {
+            Message::ProjectDefaultProgramSelected(project.to_path_buf(), name.map(str::to_string))
+        };
+
+        let global = if chosen.is_none() {
+            widget::button::suggested(fl!("project-default-program-global"))
+        } else {
+            widget::button::text(fl!("project-default-program-global")).on_press(select(None))
+        };
+
+        let mut buttons: Vec<Element<Message>> = vec![global.into()];
+
+        for program in &app.programs {
+            let button = if chosen == Some(program.name()) {
+                widget::button::suggested(program.name())
+            } else {
+                widget::button::text(program.name()).on_press(select(Some(program.name())))
+            };
+
+            buttons.push(button.into());
+        }
+
+        widget::column()
+            .push(widget::text::heading(fl!("project-default-program")))
+            .push(
+                widget::flex_row(buttons)
+                    .column_spacing(space_xxs)
+                    .row_spacing(space_xxs),
+            )
+            .spacing(space_xxs)
+            .into()
+    }
+
+    fn project_dock_pins<'a>(app: &'a AppModel, project: &Path) -> Option<Element<'a, Message>> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        if app.programs.is_empty() {
+            return None;
+        }
+
+        let buttons: Vec<Element<Message>> = app
+            .programs
+            .iter()
+            .map(|program| {
+                let pin = DockPin::new(project.to_path_buf(), program.name().to_string());
+
+                if app.config.dock_pins().contains(&pin) {
+                    widget::button::suggested(program.name())
+                        .on_press(Message::DockPinRemove(pin))
+                        .into()
+                } else {
+                    widget::button::text(program.name())
+                        .on_press(Message::DockPinAdd(pin))
+                        .into()
+                }
+            })
+            .collect();
+
+        Some(
+            widget::column()
+                .push(widget::text::heading(fl!("project-dock-pins")))
+                .push(
+                    widget::flex_row(buttons)
+                        .column_spacing(space_xxs)
+                        .row_spacing(space_xxs),
+                )
+                .spacing(space_xxs)
+                .into(),
+        )
+    }
+
+    fn project_env_profile<'a>(app: &'a AppModel, project: &Path) -> Option<Element<'a, Message>> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        if app.config.env_profiles().is_empty() {
+            return None;
+        }
+
+        let chosen = app
+            .config
+            .project_env_profile(project)
+            .map(|profile| profile.name());
+        let select = |name: Option<&str>| {
+            Message::ProjectEnvProfileSelected(project.to_path_buf(), name.map(str::to_string))
+        };
+
+        let none = if chosen.is_none() {
+            widget::button::suggested(fl!("project-env-profile-none"))
+        } else {
+            widget::button::text(fl!("project-env-profile-none")).on_press(select(None))
+        };
+
+        let mut buttons: Vec<Element<Message>> = vec![none.into()];
+
+        for profile in app.config.env_profiles() {
+            let button = if chosen == Some(profile.name()) {
+                widget::button::suggested(profile.name())
+            } else {
+                widget::button::text(profile.name()).on_press(select(Some(profile.name())))
+            };
+
+            buttons.push(button.into());
+        }
+
+        Some(
+            widget::column()
+                .push(widget::text::heading(fl!("project-env-profile")))
+                .push(
+                    widget::flex_row(buttons)
+                        .column_spacing(space_xxs)
+                        .row_spacing(space_xxs),
+                )
+                .spacing(space_xxs)
+                .into(),
+        )
+    }
+
+    fn check_log(app: &AppModel) -> Element<Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        let Some(project) = app.details_project() else {
+            return widget::text::text(fl!("project-details-missing")).into();
+        };
+
+        let mut column = widget::column()
+            .push(
+                widget::button::link(project.label().to_string())
+                    .on_press(Message::OpenContextDrawer(ContextPage::ProjectDetails))
+                    .padding(0),
+            )
+            .spacing(space_xxs);
+
+        for kind in [CheckKind::Lint, CheckKind::Health] {
+            let title = match kind {
+                CheckKind::Lint => fl!("settings-lint-checks"),
+                CheckKind::Health => fl!("settings-health-checks"),
+            };
+
+            column = column
+                .push(widget::divider::horizontal::light())
+                .push(widget::text::heading(title));
+
+            if app.is_checking(kind, project.path()) {
+                column = column.push(widget::text::caption(fl!("check-log-running")));
+            }
+
+            match app.check_results(kind, project) {
+                Some(results) if !results.is_empty() => {
+                    for result in results {
+                        let status = if result.success() {
+                            fl!("check-log-passed")
+                        } else {
+                            fl!("check-log-failed")
+                        };
+
+                        column = column
+                            .push(widget::text::text(format!(
+                                "{} – {}",
+                                result.command(),
+                                status
+                            )))
+                            .push(widget::text::monotext(result.output()));
+                    }
+                }
+                _ => {
+                    column = column.push(widget::text::caption(fl!("check-log-empty")));
+                }
+            }
+        }
+
+        column.into()
+    }
+
+    fn jobs(app: &AppModel) -> Element<Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        let jobs = app.jobs.jobs();
+
+        if jobs.is_empty() {
+            return widget::text::text(fl!("jobs-empty")).into();
+        }
+
+        let mut column = widget::column().spacing(space_xxs);
+
+        for job in jobs {
+            let project = job
+                .project()
+                .file_name()
+                .unwrap_or(job.project().as_os_str())
+                .to_string_lossy();
+
+            let (state, icon) = match job.state() {
+                JobState::Queued => (fl!("job-queued"), "process-stop-symbolic"),
+                JobState::Running => (fl!("job-running"), "process-stop-symbolic"),
+                JobState::Cancelling => (fl!("job-cancelling"), "process-stop-symbolic"),
+                JobState::Failed(err) => (
+                    fl!("job-failed", error = err.as_str()),
+                    "edit-clear-symbolic",
+                ),
+            };
+
+            column = column.push(widget::divider::horizontal::light()).push(
+                widget::row()
+                    .push(
+                        widget::column()
+                            .push(widget::text::text(format!(
+                                "{} – {}",
+                                job.kind().label(),
+                                project
+                            )))
+                            .push(widget::text::caption(state))
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        widget::button::icon(widget::icon::from_name(icon)).on_press_maybe(
+                            (*job.state() != JobState::Cancelling)
+                                .then(|| Message::JobCancel(job.id())),
+                        ),
+                    )
+                    .align_y(Alignment::Center)
+                    .spacing(space_xxs),
+            );
+        }
+
+        column.into()
+    }
+
+    pub fn tags<'a>(app: &'a AppModel, project: &'a Project) -> Element<'a, Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        let mut column = widget::column()
+            .push(widget::text::heading(fl!("project-tags")))
+            .spacing(space_xxs);
+
+        let shared = app.shared_annotation(project);
+
+        if !shared.tags().is_empty() {
+            column = column.push(widget::text::caption(fl!(
+                "project-tags-shared",
+                tags = shared.tags().join(", ")
+            )));
+        }
+
+        for tag in app.personal_annotation(project.path()).tags() {
+            column = column.push(
+                widget::row()
+                    .push(widget::text::text(tag.clone()).width(Length::Fill))
+                    .push(
+                        widget::button::text(fl!("remove"))
+                            .on_press(Message::TagRemove(project.path().clone(), tag.clone())),
+                    )
+                    .spacing(space_xxs),
+            );
+        }
+
+        column
+            .push(
+                widget::text_input(fl!("project-tags-placeholder"), &app.tag_input)
+                    .on_input(Message::TagInputChanged)
+                    .on_submit(Message::TagAdd(project.path().clone())),
+            )
+            .into()
+    }
+
+    fn statistics(app: &AppModel) -> Element<Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        let this_week = digest::now().saturating_sub(digest::WEEK);
+        let last_week = this_week.saturating_sub(digest::WEEK);
+
+        widget::column()
+            .push(
+                widget::toggler(app.config.weekly_digest())
+                    .label(fl!("statistics-weekly-digest"))
+                    .on_toggle(Message::WeeklyDigestToggled),
+            )
+            .push(widget::divider::horizontal::light())
+            .push(widget::text::heading(fl!("statistics-this-week")))
+            .push(widget::text::caption(digest_summary(
+                &app.digest(this_week),
+            )))
+            .push(widget::text::heading(fl!("statistics-last-week")))
+            .push(widget::text::caption(digest_summary(
+                &app.digest(last_week),
+            )))
+            .push(widget::divider::horizontal::light())
+            .push_maybe(Self::archive_suggestions(app))
+            .push(Self::kinds(app))
+            .push(widget::divider::horizontal::light())
+            .push(Self::largest(
+                app,
+                fl!("statistics-largest-projects"),
+                app.largest_projects(ProjectStats::size),
+            ))
+            .push(Self::largest(
+                app,
+                fl!("statistics-largest-artifacts"),
+                app.largest_projects(ProjectStats::artifact_size),
+            ))
+            .spacing(space_xxs)
+            .into()
+    }
+
+    /// Card of the projects suggested for hiding, `None` without any.
+    fn archive_suggestions(app: &AppModel) -> Option<Element<Message>> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        let suggestions = app.archive_suggestions();
+
+        if suggestions.is_empty() {
+            return None;
+        }
+
+        let mut column = widget::column()
+            .push(widget::text::heading(fl!("archive-suggestions-title")))
+            .push(widget::text::caption(fl!(
+                "archive-suggestions",
+                count = suggestions.len(),
+                months = app.config.archive_after()
+            )))
+            .spacing(space_xxs);
+
+        for project in suggestions {
+            column = column.push(
+                widget::row()
+                    .push(
+                        widget::button::text(project.label())
+                            .on_press(Message::OpenProjectDetails(project.path().clone()))
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        widget::button::text(fl!("project-hide"))
+                            .on_press(Message::ArchiveSuggestionAccept(project.path().clone())),
+                    )
+                    .push(
+                        widget::button::text(fl!("archive-suggestions-keep"))
+                            .on_press(Message::ArchiveSuggestionDismiss(project.path().clone())),
+                    )
+                    .align_y(Alignment::Center)
+                    .spacing(space_xxs),
+            );
+        }
+
+        column = column
+            .push(
+                widget::row()
+                    .push(
+                        widget::button::text(fl!("archive-suggestions-hide"))
+                            .on_press(Message::ArchiveSuggestionsAccept),
+                    )
+                    .push(
+                        widget::button::text(fl!("archive-suggestions-keep-all"))
+                            .on_press(Message::ArchiveSuggestionsDismiss),
+                    )
+                    .spacing(space_xxs),
+            )
+            .push(widget::divider::horizontal::light());
+
+        Some(column.into())
+    }
+
+    /// Table of projects with their size, each opening its details and offering to clean it.
+    fn largest<'a>(
+        app: &'a AppModel,
+        title: String,
+        projects: Vec<(&'a Project, u64)>,
+    ) -> Element<'a, Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        let mut column = widget::column()
+            .push(widget::text::heading(title))
+            .spacing(space_xxs);
+
+        if !app.config.show_footer() {
+            return column
+                .push(widget::text::caption(fl!("statistics-sizes-disabled")))
+                .into();
+        }
+
+        for (project, size) in projects {
+            let cleanable = !project.is_protected()
+                && !app.root_read_only
+                && app
+                    .project_stats
+                    .get(project.path())
+                    .is_some_and(|(_, stats)| stats.artifact_size() > 0);
+
+            column = column.push(
+                widget::row()
+                    .push(
+                        widget::button::text(project.label())
+                            .on_press(Message::OpenProjectDetails(project.path().clone()))
+                            .width(Length::Fill),
+                    )
+                    .push(widget::text::caption(format_size(size)))
+                    .push(widget::button::text(fl!("clean-artifacts")).on_press_maybe(
+                        cleanable.then(|| Message::ProjectClean(project.path().clone())),
+                    ))
+                    .align_y(Alignment::Center)
+                    .spacing(space_xxs),
+            );
+        }
+
+        column.into()
+    }
+
+    /// Bar charts of how many projects of each kind there are and how much disk they use.
+    fn kinds(app: &AppModel) -> Element<Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        let mut kinds = app.kind_stats();
+
+        let mut column = widget::column()
+            .push(widget::text::heading(fl!("statistics-kinds")))
+            .spacing(space_xxs);
+
+        let max_count = kinds.iter().map(|kind| kind.count()).max().unwrap_or(0);
+
+        for kind in &kinds {
+            column = column.push(Self::kind_bar(
+                kind.kind(),
+                kind.count() as u64,
+                max_count as u64,
+                kind.count().to_string(),
+            ));
+        }
+
+        column = column.push(widget::text::heading(fl!("statistics-kinds-size")));
+
+        if !app.config.show_footer() {
+            column = column.push(widget::text::caption(fl!("statistics-sizes-disabled")));
+        } else if app.project_stats.len() < app.projects.len() {
+            column = column.push(widget::text::caption(fl!(
+                "footer-collecting",
+                done = app.project_stats.len(),
+                total = app.projects.len()
+            )));
+        }
+
+        kinds.sort_by(|a, b| b.size().cmp(&a.size()));
+        let max_size = kinds.iter().map(|kind| kind.size()).max().unwrap_or(0);
+
+        for kind in &kinds {
+            column = column.push(Self::kind_bar(
+                kind.kind(),
+                kind.size(),
+                max_size,
+                format_size(kind.size()),
+            ));
+        }
+
+        column.into()
+    }
+
+    fn kind_bar<'a>(
+        kind: Option<ProjectKind>,
+        value: u64,
+        max: u64,
+        caption: String,
+    ) -> Element<'a, Message> {
+        let theme = theme::active();
+        let cosmic_theme::Spacing { space_xxs, .. } = theme.cosmic().spacing;
+        let accent = Color::from(theme.cosmic().accent_color());
+
+        let label = match kind {
+            Some(kind) => kind.label().to_string(),
+            None => fl!("statistics-kind-other"),
+        };
+
+        // portions of the row, so the longest bar fills it
+        let filled = (value * 100 / max.max(1)) as u16;
+
+        let bar = widget::row()
+            .push_maybe((filled > 0).then(|| {
+                widget::container(widget::horizontal_space())
+                    .width(Length::FillPortion(filled))
+                    .height(Length::Fixed(8.0))
+                    .class(cosmic::theme::Container::custom(move |_| {
+                        widget::container::Style {
+                            background: Some(Background::Color(accent)),
+                            border: Border {
+                                radius: 4.0.into(),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        }
+                    }))
+            }))
+            .push_maybe(
+                (filled < 100)
+                    .then(|| widget::horizontal_space().width(Length::FillPortion(100 - filled))),
+            )
+            .width(Length::Fill);
+
+        widget::row()
+            .push_maybe(kind.map(|kind| widget::icon::from_name(kind.icon_name()).size(16)))
+            .push(widget::text::text(label).width(Length::Fixed(80.0)))
+            .push(bar)
+            .push(widget::text::caption(caption))
+            .align_y(Alignment::Center)
+            .spacing(space_xxs)
+            .into()
+    }
+
+    fn trash(app: &AppModel) -> Element<Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        let mut column = widget::column().spacing(space_xxs);
+
+        if let Some(err) = &app.trash_error {
+            column = column.push(widget::text::caption(fl!(
+                "trash-failed",
+                error = err.as_str()
+            )));
+        }
+
+        column = column.push(widget::text::heading(fl!("trash-hidden")));
+
+        if app.config.hidden_projects().is_empty() {
+            column = column.push(widget::text::caption(fl!("trash-hidden-empty")));
+        }
+
+        for path in app.config.hidden_projects() {
+            column = column.push(
+                widget::row()
+                    .push(widget::text::text(path.to_string_lossy()).width(Length::Fill))
+                    .push(
+                        widget::button::text(fl!("trash-unhide"))
+                            .on_press(Message::ProjectUnhide(path.clone())),
+                    )
+                    .align_y(Alignment::Center)
+                    .spacing(space_xxs),
+            );
+        }
+
+        column = column
+            .push(widget::divider::horizontal::light())
+            .push(widget::text::heading(fl!("trash-trashed")));
+
+        if app.config.trashed_projects().is_empty() {
+            column = column.push(widget::text::caption(fl!("trash-trashed-empty")));
+        }
+
+        for trashed in app.config.trashed_projects() {
+            let original = trashed.original().to_path_buf();
+
+            let (status, action) = if trashed.exists() {
+                (
+                    None,
+                    widget::button::text(fl!("trash-restore"))
+                        .on_press(Message::ProjectRestore(original)),
+                )
+            } else {
+                (
+                    Some(widget::text::caption(fl!("trash-gone"))),
+                    widget::button::text(fl!("trash-forget"))
+                        .on_press(Message::TrashForget(original)),
+                )
+            };
+
+            column = column.push(
+                widget::row()
+                    .push(
+                        widget::column()
+                            .push(widget::text::text(trashed.original().to_string_lossy()))
+                            .push_maybe(status)
+                            .width(Length::Fill),
+                    )
+                    .push(action)
+                    .align_y(Alignment::Center)
+                    .spacing(space_xxs),
+            );
+        }
+
+        column.into()
+    }
+
+    fn project_sessions<'a>(app: &'a AppModel, project_path: &'a Path) -> Element<'a, Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        let mut column = widget::column()
+            .push(widget::text::heading(fl!("sessions")))
+            .spacing(space_xxs);
+
+        if app.config.sessions().is_empty() || app.programs.is_empty() {
+            return column
+                .push(widget::text::caption(fl!("project-sessions-empty")))
+                .into();
+        }
+
+        let program_index = app.session_program.unwrap_or_default();
+        let program = app
+            .programs
+            .get(program_index)
+            .map(|program| program.name());
+
+        column = column.push(widget::dropdown(
+            &app.programs,
+            Some(program_index),
+            Message::SessionProgramSelected,
+        ));
+
+        for session in app.config.sessions() {
+            let mut row = widget::row()
+                .spacing(space_xxs)
+                .push(widget::text::text(session.name()).width(Length::Fill));
+
+            match program {
+                Some(program) if session.contains(project_path, program) => {
+                    row = row.push(widget::button::text(fl!("remove")).on_press(
+                        Message::SessionRemoveEntry {
+                            session: session.name().to_string(),
+                            project: project_path.to_path_buf(),
+                            program: program.to_string(),
+                        },
+                    ));
+                }
+                _ => {
+                    row = row.push(
+                        widget::button::text(fl!("add"))
+                            .on_press(Message::SessionAddProject(session.name().to_string())),
+                    );
+                }
+            }
+
+            column = column.push(row);
+        }
+
+        column.into()
+    }
+
+    fn sessions(app: &AppModel) -> Element<Message> {
+        let cosmic_theme::Spacing {
+            space_xs,
+            space_xxs,
+            ..
+        } = theme::active().cosmic().spacing;
+
+        let input = widget::text_input(fl!("session-name-placeholder"), &app.session_name_input)
+            .on_input(Message::SessionNameInputChanged);
+
+        let mut create = widget::button::text(fl!("add"));
+
+        if app.is_valid_session_name() {
+            create = create.on_press(Message::SessionCreate);
+        }
+
+        let mut column = widget::column()
+            .push(input)
+            .push(create)
+            .push(widget::text::caption(fl!("sessions-hint")))
+            .spacing(space_xs);
+
+        for session in app.config.sessions() {
+            let launch =
+                widget::button::icon(widget::icon::from_name("media-playback-start-symbolic"))
+                    .on_press(Message::SessionLaunch(session.name().to_string()));
+
+            let delete = widget::button::icon(widget::icon::from_name("edit-delete-symbolic"))
+                .on_press(Message::SessionDelete(session.name().to_string()));
+
+            let header = widget::row()
+                .spacing(space_xxs)
+                .align_y(Alignment::Center)
+                .push(widget::text::heading(session.name()).width(Length::Fill))
+                .push(launch)
+                .push(delete);
+
+            let autostart = widget::toggler(session.autostart())
+                .label(fl!("session-autostart"))
+                .on_toggle(|enabled| {
+                    Message::SessionAutostartToggled(session.name().to_string(), enabled)
+                });
+
+            let mut entries = widget::column().push(header).push(autostart);
+
+            for entry in session.entries() {
+                let name = entry
+                    .project()
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_default();
+
+                let remove = widget::button::icon(widget::icon::from_name("list-remove-symbolic"))
+                    .on_press(Message::SessionRemoveEntry {
+                        session: session.name().to_string(),
+                        project: entry.project().to_path_buf(),
+                        program: entry.program().to_string(),
+                    });
+
+                entries = entries.push(
+                    widget::row()
+                        .spacing(space_xxs)
+                        .align_y(Alignment::Center)
+                        .push(
+                            widget::text::caption(fl!(
+                                "session-entry",
+                                project = name,
+                                program = entry.program()
+                            ))
+                            .width(Length::Fill),
+                        )
+                        .push(remove),
+                );
+            }
+
+            column = column
+                .push(widget::divider::horizontal::light())
+                .push(entries);
+        }
+
+        column.into()
+    }
+
+    fn changed_files<'a>(
+        title: String,
+        project_path: &'a Path,
+        files: &'a [PathBuf],
+    ) -> Element<'a, Message> {
+        let mut column = widget::column().push(widget::text::heading(title));
+
+        if files.is_empty() {
+            column = column.push(widget::text::caption(fl!("project-details-no-files")));
+        }
+
+        for file in files {
+            let name = file.strip_prefix(project_path).unwrap_or(file);
+
+            column = column.push(
+                widget::button::link(name.display().to_string())
+                    .on_press(Message::OpenFile(file.clone()))
+                    .padding(0),
+            );
+        }
+
+        column.into()
+    }
+
+    fn launch_overrides<'a>(app: &'a AppModel, project_path: &'a Path) -> Element<'a, Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        let mut column = widget::column()
+            .push(widget::text::heading(fl!("launch-overrides")))
+            .spacing(space_xxs);
+
+        for program in &app.programs {
+            let launch_override = app.config.launch_override(project_path, program.name());
+
+            let mut description = widget::column().push(widget::text::text(program.name()));
+
+            if let Some(path) =
+                launch_override.and_then(|launch_override| launch_override.working_directory())
+            {
+                description = description.push(widget::text::caption(fl!(
+                    "launch-override-working-directory",
+                    path = path.display().to_string()
+                )));
+            }
+
+            if let Some(path) = launch_override.and_then(|launch_override| launch_override.path()) {
+                description = description.push(widget::text::caption(fl!(
+                    "launch-override-path",
+                    path = path.display().to_string()
+                )));
+            }
+
+            let mut row = widget::row()
+                .spacing(space_xxs)
+                .push(description.width(Length::Fill))
+                .push(
+                    widget::button::icon(widget::icon::from_name("edit-symbolic"))
+                        .on_press(Message::LaunchOverrideEdit(program.name().to_string())),
+                );
+
+            if launch_override.is_some() {
+                row = row.push(
+                    widget::button::icon(widget::icon::from_name("edit-delete-symbolic"))
+                        .on_press(Message::LaunchOverrideDelete(program.name().to_string())),
+                );
+            }
+
+            column = column.push(row);
+        }
+
+        if let Some(program_name) = &app.override_program {
+            let working_directory = widget::text_input(
+                fl!("launch-override-working-directory-placeholder"),
+                &app.override_working_directory_input,
+            )
+            .on_input(Message::LaunchOverrideWorkingDirectoryInputChanged);
+
+            let path = widget::text_input(
+                fl!("launch-override-path-placeholder"),
+                &app.override_path_input,
+            )
+            .on_input(Message::LaunchOverridePathInputChanged);
+
+            column = column
+                .push(widget::text::caption(fl!(
+                    "launch-override-editing",
+                    program = program_name.as_str()
+                )))
+                .push(working_directory)
+                .push(path)
+                .push(widget::button::text(fl!("save")).on_press(Message::LaunchOverrideSave));
+        }
+
+        column.into()
+    }
+
+    fn new_project(app: &AppModel) -> Element<Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        let input = widget::text_input(
+            fl!("new-project-name-placeholder"),
+            &app.new_project_name_input,
+        )
+        .on_input(Message::NewProjectNameInputChanged);
+
+        let can_create = app.new_project_target().is_some();
+
+        let mut column = widget::column().push(input).spacing(space_xxs);
+
+        if app.root_read_only {
+            column = column.push(widget::text::caption(fl!("root-read-only")));
+        }
+
+        let mut empty = widget::button::text(fl!("new-project-empty"));
+
+        if can_create {
+            empty = empty.on_press(Message::NewProjectCreate(None));
+        }
+
+        column = column.push(empty);
+
+        if !app.templates.is_empty() {
+            column = column
+                .push(widget::divider::horizontal::light())
+                .push(widget::text::heading(fl!("new-project-templates")));
+        }
+
+        for template in &app.templates {
+            let mut button = widget::button::text(template.name());
+
+            if can_create {
+                button = button.on_press(Message::NewProjectCreate(Some(template.path().clone())));
+            }
+
+            column = column.push(button);
+        }
+
+        if let Some(err) = &app.new_project_error {
+            column = column.push(widget::text::caption(err.as_str()));
+        }
+
+        column.into()
+    }
 }
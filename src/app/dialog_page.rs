@@ -0,0 +1,505 @@
+use crate::app::config_repair::ConfigIssue;
+use crate::app::context_page::ContextPage;
+use crate::app::{format_size, icon_handle, shortcut_button, AppModel, Message, BULK_LAUNCH_LIMIT};
+use crate::domain::import::ImportMode;
+use crate::domain::program::Program;
+use crate::fl;
+use cosmic::iced::{Alignment, Length};
+use cosmic::{cosmic_theme, theme, widget, Element};
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DialogPage {
+    DeleteProgram {
+        name: String,
+        affected_projects: Vec<String>,
+    },
+    ImportProject {
+        source: PathBuf,
+    },
+    CommandPalette,
+    ConfigReport {
+        /// `None` while the config is being checked.
+        issues: Option<Vec<ConfigIssue>>,
+        removed: Vec<ConfigIssue>,
+    },
+    /// Launching every project matching a tag or a saved search with one program.
+    BulkLaunch {
+        title: String,
+        projects: Vec<PathBuf>,
+        program: Option<usize>,
+    },
+    /// Leaving the settings with edits that were not saved yet.
+    UnsavedSettings,
+    /// Asking for the values of the prompts of a program before launching it.
+    LaunchPrompt {
+        project_path: PathBuf,
+        program_name: String,
+        labels: Vec<String>,
+    },
+    /// Editing the personal tags of a project, opened from its context menu.
+    ProjectTags {
+        project_path: PathBuf,
+    },
+    /// Giving a project a name shown instead of its folder name.
+    RenameProject {
+        project_path: PathBuf,
+    },
+    /// Another instance changed the programs while ours had changes of their own.
+    ProgramsDiverged {
+        theirs: Vec<Program>,
+    },
+    /// Picking installed applications to add as programs.
+    InstalledApps,
+    /// Deleting the artifact folders of a project, `size` bytes in total.
+    CleanArtifacts {
+        project_path: PathBuf,
+        dirs: Vec<&'static str>,
+        size: u64,
+    },
+    /// Hiding every project suggested for hiding at once.
+    HideArchiveSuggestions {
+        projects: Vec<PathBuf>,
+    },
+}
+
+impl DialogPage {
+    pub fn view<'a>(&'a self, app: &'a AppModel) -> Element<'a, Message> {
+        match self {
+            DialogPage::DeleteProgram {
+                name,
+                affected_projects,
+            } => Self::delete_program(name, affected_projects),
+            DialogPage::ImportProject { source } => Self::import_project(source),
+            DialogPage::CommandPalette => Self::command_palette(app),
+            DialogPage::ConfigReport { issues, removed } => {
+                Self::config_report(issues.as_deref(), removed)
+            }
+            DialogPage::BulkLaunch {
+                title,
+                projects,
+                program,
+            } => Self::bulk_launch(app, title, projects, *program),
+            DialogPage::UnsavedSettings => Self::unsaved_settings(),
+            DialogPage::LaunchPrompt {
+                program_name,
+                labels,
+                ..
+            } => Self::launch_prompt(app, program_name, labels),
+            DialogPage::ProjectTags { project_path } => Self::project_tags(app, project_path),
+            DialogPage::RenameProject { project_path } => Self::rename_project(app, project_path),
+            DialogPage::ProgramsDiverged { theirs } => Self::programs_diverged(app, theirs),
+            DialogPage::InstalledApps => Self::installed_apps(app),
+            DialogPage::CleanArtifacts {
+                project_path,
+                dirs,
+                size,
+            } => Self::clean_artifacts(project_path, dirs, *size),
+            DialogPage::HideArchiveSuggestions { projects } => {
+                Self::hide_archive_suggestions(app, projects)
+            }
+        }
+    }
+
+    fn delete_program<'a>(name: &'a str, affected_projects: &'a [String]) -> Element<'a, Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        let mut projects = widget::column().spacing(space_xxs);
+
+        for project in affected_projects {
+            projects = projects.push(widget::text::caption(project.as_str()));
+        }
+
+        widget::dialog()
+            .title(fl!("delete-program-title", name = name))
+            .body(fl!("delete-program-body"))
+            .control(projects)
+            .primary_action(
+                widget::button::destructive(fl!("delete"))
+                    .on_press(Message::ProgramDeleteConfirmed(name.to_string())),
+            )
+            .secondary_action(
+                widget::button::standard(fl!("cancel")).on_press(Message::CloseDialog),
+            )
+            .into()
+    }
+
+    fn clean_artifacts<'a>(
+        project_path: &'a Path,
+        artifact_dirs: &'a [&'static str],
+        size: u64,
+    ) -> Element<'a, Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        let mut dirs = widget::column().spacing(space_xxs);
+
+        for dir in artifact_dirs {
+            dirs = dirs.push(widget::text::caption(*dir));
+        }
+
+        widget::dialog()
+            .title(fl!(
+                "clean-artifacts-title",
+                project = project_path.display().to_string()
+            ))
+            .body(fl!("clean-artifacts-body", size = format_size(size)))
+            .control(dirs)
+            .primary_action(
+                widget::button::destructive(fl!("clean-artifacts"))
+                    .on_press(Message::ProjectCleanConfirm),
+            )
+            .secondary_action(
+                widget::button::standard(fl!("cancel")).on_press(Message::CloseDialog),
+            )
+            .into()
+    }
+
+    fn hide_archive_suggestions<'a>(
+        app: &'a AppModel,
+        projects: &'a [PathBuf],
+    ) -> Element<'a, Message> {
+        widget::dialog()
+            .title(fl!(
+                "archive-suggestions-hide-title",
+                count = projects.len()
+            ))
+            .body(fl!(
+                "archive-suggestions-hide-body",
+                months = app.config.archive_after()
+            ))
+            .primary_action(
+                widget::button::destructive(fl!("archive-suggestions-hide"))
+                    .on_press(Message::ArchiveSuggestionsHideConfirm),
+            )
+            .secondary_action(
+                widget::button::standard(fl!("cancel")).on_press(Message::CloseDialog),
+            )
+            .into()
+    }
+
+    fn import_project(source: &Path) -> Element<Message> {
+        let import = |mode| Message::ImportProjectStart {
+            source: source.to_path_buf(),
+            mode,
+        };
+
+        widget::dialog()
+            .title(fl!("import-project"))
+            .body(fl!(
+                "import-project-body",
+                path = source.display().to_string()
+            ))
+            .primary_action(
+                widget::button::suggested(fl!("import-project-move"))
+                    .on_press(import(ImportMode::Move)),
+            )
+            .secondary_action(
+                widget::button::standard(fl!("cancel")).on_press(Message::CloseDialog),
+            )
+            .tertiary_action(
+                widget::button::text(fl!("import-project-copy")).on_press(import(ImportMode::Copy)),
+            )
+            .into()
+    }
+
+    fn programs_diverged<'a>(app: &'a AppModel, theirs: &'a [Program]) -> Element<'a, Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        let names = |programs: &'a [Program]| {
+            programs
+                .iter()
+                .map(|program| program.name())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        widget::dialog()
+            .title(fl!("programs-diverged"))
+            .body(fl!("programs-diverged-body"))
+            .control(
+                widget::column()
+                    .push(widget::text::caption(fl!(
+                        "programs-diverged-mine",
+                        programs = names(&app.programs)
+                    )))
+                    .push(widget::text::caption(fl!(
+                        "programs-diverged-theirs",
+                        programs = names(theirs)
+                    )))
+                    .spacing(space_xxs),
+            )
+            .primary_action(
+                widget::button::suggested(fl!("programs-merge")).on_press(Message::ProgramsMerge),
+            )
+            .secondary_action(
+                widget::button::standard(fl!("programs-use-theirs"))
+                    .on_press(Message::ProgramsUseTheirs),
+            )
+            .tertiary_action(
+                widget::button::text(fl!("programs-keep-mine")).on_press(Message::ProgramsKeepMine),
+            )
+            .into()
+    }
+
+    fn unsaved_settings<'a>() -> Element<'a, Message> {
+        widget::dialog()
+            .title(fl!("unsaved-settings"))
+            .body(fl!("unsaved-settings-body"))
+            .primary_action(
+                widget::button::suggested(fl!("save")).on_press(Message::UnsavedSettingsSave),
+            )
+            .secondary_action(
+                widget::button::standard(fl!("cancel")).on_press(Message::CloseDialog),
+            )
+            .tertiary_action(
+                widget::button::text(fl!("unsaved-settings-discard"))
+                    .on_press(Message::UnsavedSettingsDiscard),
+            )
+            .into()
+    }
+
+    fn launch_prompt<'a>(
+        app: &'a AppModel,
+        program_name: &'a str,
+        labels: &'a [String],
+    ) -> Element<'a, Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        let mut inputs = widget::column().spacing(space_xxs);
+
+        for (index, label) in labels.iter().enumerate() {
+            let value = app
+                .prompt_inputs
+                .get(index)
+                .map(String::as_str)
+                .unwrap_or_default();
+
+            inputs = inputs.push(widget::text::caption(label.as_str())).push(
+                widget::text_input(label.as_str(), value)
+                    .on_input(move |value| Message::LaunchPromptInputChanged(index, value))
+                    .on_submit(Message::LaunchPromptConfirm),
+            );
+        }
+
+        widget::dialog()
+            .title(fl!("launch-prompt", program = program_name))
+            .control(inputs)
+            .primary_action(
+                widget::button::suggested(fl!("launch-prompt-confirm"))
+                    .on_press(Message::LaunchPromptConfirm),
+            )
+            .secondary_action(
+                widget::button::standard(fl!("cancel")).on_press(Message::CloseDialog),
+            )
+            .into()
+    }
+
+    fn project_tags<'a>(app: &'a AppModel, project_path: &Path) -> Element<'a, Message> {
+        let project = app
+            .projects
+            .iter()
+            .find(|project| project.path() == project_path);
+
+        let dialog = match project {
+            Some(project) => widget::dialog()
+                .title(fl!(
+                    "project-tags-title",
+                    project = project.label().to_string()
+                ))
+                .control(ContextPage::tags(app, project)),
+            None => widget::dialog()
+                .title(fl!("project-tags"))
+                .body(fl!("project-details-missing")),
+        };
+
+        dialog
+            .primary_action(widget::button::standard(fl!("close")).on_press(Message::CloseDialog))
+            .into()
+    }
+
+    fn rename_project<'a>(app: &'a AppModel, project_path: &Path) -> Element<'a, Message> {
+        let folder_name = project_path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        widget::dialog()
+            .title(fl!("project-rename-title", project = folder_name.as_str()))
+            .body(fl!("project-rename-body"))
+            .control(
+                widget::text_input(folder_name, &app.rename_input)
+                    .on_input(Message::RenameInputChanged)
+                    .on_submit(Message::RenameConfirm),
+            )
+            .primary_action(
+                widget::button::suggested(fl!("project-rename")).on_press(Message::RenameConfirm),
+            )
+            .secondary_action(
+                widget::button::standard(fl!("cancel")).on_press(Message::CloseDialog),
+            )
+            .into()
+    }
+
+    fn command_palette(app: &AppModel) -> Element<Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        let input = widget::text_input(fl!("palette-placeholder"), &app.palette_query)
+            .on_input(Message::PaletteQueryChanged)
+            .on_submit(Message::PaletteSubmit)
+            .id(app.palette_input_id.clone());
+
+        let mut entries = widget::column().spacing(space_xxs);
+
+        for entry in app.palette_entries() {
+            entries = entries.push(
+                shortcut_button(
+                    entry.label().to_string(),
+                    entry.shortcut().map(str::to_string),
+                )
+                .on_press(Message::PaletteRun(Box::new(entry.message().clone()))),
+            );
+        }
+
+        let title = match app.palette_project() {
+            Some(project) => fl!("palette-project-title", project = project.label()),
+            None => fl!("palette-title"),
+        };
+
+        widget::dialog()
+            .title(title)
+            .control(
+                widget::column()
+                    .push(input)
+                    .push(widget::scrollable(entries))
+                    .spacing(space_xxs),
+            )
+            .secondary_action(
+                widget::button::standard(fl!("cancel")).on_press(Message::CloseDialog),
+            )
+            .into()
+    }
+
+    fn installed_apps(app: &AppModel) -> Element<Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        let input =
+            widget::text_input(fl!("installed-apps-placeholder"), &app.installed_apps_query)
+                .on_input(Message::InstalledAppsQueryChanged);
+
+        let mut apps = widget::column().spacing(space_xxs);
+
+        for (index, entry) in app.installed_apps() {
+            let added = app
+                .programs
+                .iter()
+                .any(|program| program.name() == entry.name());
+
+            let add = if added {
+                widget::button::text(fl!("installed-apps-added"))
+            } else {
+                widget::button::text(fl!("add")).on_press(Message::InstalledAppAdd(index))
+            };
+
+            apps = apps.push(
+                widget::row()
+                    .push_maybe(
+                        entry
+                            .icon()
+                            .map(|icon| widget::icon(icon_handle(icon)).size(16)),
+                    )
+                    .push(widget::text::text(entry.name()).width(Length::Fill))
+                    .push(add)
+                    .align_y(Alignment::Center)
+                    .spacing(space_xxs),
+            );
+        }
+
+        widget::dialog()
+            .title(fl!("installed-apps"))
+            .control(
+                widget::column()
+                    .push(input)
+                    .push(widget::scrollable(apps))
+                    .spacing(space_xxs),
+            )
+            .secondary_action(widget::button::standard(fl!("close")).on_press(Message::CloseDialog))
+            .into()
+    }
+
+    fn config_report<'a>(
+        issues: Option<&'a [ConfigIssue]>,
+        removed: &'a [ConfigIssue],
+    ) -> Element<'a, Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        let mut report = widget::column().spacing(space_xxs);
+
+        for issue in issues.unwrap_or_default() {
+            report = report.push(
+                widget::row()
+                    .push(widget::text::text(issue.label()).width(Length::Fill))
+                    .push(
+                        widget::button::destructive(fl!("config-issue-remove"))
+                            .on_press(Message::RemoveConfigIssue(issue.clone())),
+                    )
+                    .spacing(space_xxs),
+            );
+        }
+
+        if !removed.is_empty() {
+            report = report.push(widget::text::heading(fl!("config-issues-removed")));
+
+            for issue in removed {
+                report = report.push(widget::text::caption(issue.label()));
+            }
+        }
+
+        let body = match issues {
+            None => fl!("config-report-checking"),
+            Some([]) => fl!("config-report-valid"),
+            Some(issues) => fl!("config-report-issues", count = issues.len()),
+        };
+
+        widget::dialog()
+            .title(fl!("config-report"))
+            .body(body)
+            .control(widget::scrollable(report))
+            .secondary_action(widget::button::standard(fl!("close")).on_press(Message::CloseDialog))
+            .into()
+    }
+
+    fn bulk_launch<'a>(
+        app: &'a AppModel,
+        title: &'a str,
+        projects: &'a [PathBuf],
+        program: Option<usize>,
+    ) -> Element<'a, Message> {
+        let mut launch = widget::button::suggested(fl!("bulk-launch-confirm"));
+
+        if program.is_some() && !projects.is_empty() {
+            launch = launch.on_press(Message::BulkLaunchConfirm);
+        }
+
+        widget::dialog()
+            .title(title)
+            .body(if projects.len() > BULK_LAUNCH_LIMIT {
+                fl!(
+                    "bulk-launch-body-limited",
+                    count = projects.len(),
+                    limit = BULK_LAUNCH_LIMIT
+                )
+            } else {
+                fl!("bulk-launch-body", count = projects.len())
+            })
+            .control(widget::dropdown(
+                &app.programs,
+                program,
+                Message::BulkLaunchProgramSelected,
+            ))
+            .primary_action(launch)
+            .secondary_action(
+                widget::button::standard(fl!("cancel")).on_press(Message::CloseDialog),
+            )
+            .into()
+    }
+}
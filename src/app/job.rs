@@ -0,0 +1,201 @@
+use crate::app::Message;
+use crate::domain::check::CheckKind;
+use crate::fl;
+use cosmic::app::Task;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Blocking work of a job, producing the message that applies its result.
+///
+/// The flag is set once the job is cancelled, letting work made of several steps stop early.
+pub type Work = Box<dyn FnOnce(&AtomicBool) -> Message + Send>;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum JobKind {
+    Audit,
+    Check(CheckKind),
+    GitStatus,
+    Ownership,
+    Release,
+    Stats,
+}
+
+impl JobKind {
+    pub fn label(&self) -> String {
+        match self {
+            JobKind::Audit => fl!("job-audit"),
+            JobKind::Check(CheckKind::Lint) => fl!("settings-lint-checks"),
+            JobKind::Check(CheckKind::Health) => fl!("settings-health-checks"),
+            JobKind::GitStatus => fl!("job-git-status"),
+            JobKind::Ownership => fl!("job-ownership"),
+            JobKind::Release => fl!("job-release"),
+            JobKind::Stats => fl!("job-stats"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum JobState {
+    Queued,
+    Running,
+    /// Cancelled while running, keeping its slot until the work returns.
+    Cancelling,
+    Failed(String),
+}
+
+pub struct Job {
+    id: u64,
+    kind: JobKind,
+    project: PathBuf,
+    state: JobState,
+    work: Option<Work>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Job {
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn kind(&self) -> JobKind {
+        self.kind
+    }
+
+    pub fn project(&self) -> &Path {
+        &self.project
+    }
+
+    pub fn state(&self) -> &JobState {
+        &self.state
+    }
+
+    fn is_active(&self) -> bool {
+        matches!(self.state, JobState::Queued | JobState::Running)
+    }
+
+    fn is_running(&self) -> bool {
+        matches!(self.state, JobState::Running | JobState::Cancelling)
+    }
+}
+
+/// Background work of all features, started in order while fewer than the limit are running.
+#[derive(Default)]
+pub struct JobQueue {
+    jobs: Vec<Job>,
+    next_id: u64,
+}
+
+impl JobQueue {
+    pub fn jobs(&self) -> &[Job] {
+        self.jobs.as_slice()
+    }
+
+    /// Whether a job of this kind is queued or running for the project.
+    pub fn contains(&self, kind: JobKind, project: &Path) -> bool {
+        self.jobs
+            .iter()
+            .any(|job| job.is_active() && job.kind == kind && job.project == project)
+    }
+
+    /// Queues the work unless the same job is already queued or running.
+    pub fn push(
+        &mut self,
+        kind: JobKind,
+        project: PathBuf,
+        work: impl FnOnce(&AtomicBool) -> Message + Send + 'static,
+    ) {
+        if self.contains(kind, &project) {
+            return;
+        }
+
+        // a failed attempt is replaced, a cancelled one still running is not as it holds a slot
+        self.jobs.retain(|job| {
+            job.kind != kind || job.project != project || job.state == JobState::Cancelling
+        });
+
+        self.jobs.push(Job {
+            id: self.next_id,
+            kind,
+            project,
+            state: JobState::Queued,
+            work: Some(Box::new(work)),
+            cancelled: Arc::default(),
+        });
+
+        self.next_id += 1;
+    }
+
+    /// Starts queued jobs until `limit` of them are running.
+    pub fn start(&mut self, limit: usize) -> Task<Message> {
+        let running = self.jobs.iter().filter(|job| job.is_running()).count();
+
+        let mut tasks = vec![];
+
+        for job in self
+            .jobs
+            .iter_mut()
+            .filter(|job| job.state == JobState::Queued)
+            .take(limit.saturating_sub(running))
+        {
+            let Some(work) = job.work.take() else {
+                continue;
+            };
+
+            let id = job.id;
+            let cancelled = job.cancelled.clone();
+            let task = Task::perform(
+                tokio::task::spawn_blocking(move || work(&cancelled)),
+                move |result| {
+                    cosmic::app::Message::App(Message::JobFinished {
+                        id,
+                        result: result.map(Box::new).map_err(|err| err.to_string()),
+                    })
+                },
+            );
+
+            job.state = JobState::Running;
+            tasks.push(task);
+        }
+
+        Task::batch(tasks)
+    }
+
+    /// Frees the slot of the job, returning whether its result is still wanted.
+    pub fn finish(&mut self, id: u64) -> bool {
+        let wanted = self
+            .jobs
+            .iter()
+            .any(|job| job.id == id && job.state == JobState::Running);
+
+        self.jobs.retain(|job| job.id != id);
+        wanted
+    }
+
+    /// Keeps the job listed as failed until it is dismissed, unless it was cancelled.
+    pub fn fail(&mut self, id: u64, error: String) {
+        let Some(job) = self.jobs.iter_mut().find(|job| job.id == id) else {
+            return;
+        };
+
+        if job.state == JobState::Cancelling {
+            self.finish(id);
+        } else {
+            job.state = JobState::Failed(error);
+        }
+    }
+
+    /// Removes a queued or failed job. A running one is told to stop and keeps its slot until
+    /// its work returns, its result then being dropped.
+    pub fn cancel(&mut self, id: u64) {
+        for job in self.jobs.iter_mut().filter(|job| job.id == id) {
+            if job.state == JobState::Running {
+                job.cancelled.store(true, Ordering::Relaxed);
+                job.state = JobState::Cancelling;
+            }
+        }
+
+        self.jobs
+            .retain(|job| job.id != id || job.state == JobState::Cancelling);
+    }
+}
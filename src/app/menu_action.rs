@@ -1,21 +1,184 @@
 use crate::app::context_page::ContextPage;
+use crate::app::view_mode::{SortOrder, ViewMode};
 use crate::app::Message;
-use cosmic::widget::menu;
+use crate::cli::Page;
+use crate::fl;
+use cosmic::iced::keyboard::Key;
+use cosmic::widget::menu::key_bind::Modifier;
+use cosmic::widget::menu::{self, KeyBind};
 
+/// Menus of the header an action is listed in.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Menu {
+    File,
+    View,
+    Sort,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum MenuAction {
     About,
     Settings,
+    Sessions,
+    Jobs,
+    Trash,
+    Statistics,
+    NewProject,
+    ImportProject,
+    ExportSnapshot,
+    OpenSnapshot,
+    Refresh,
+    ListView,
+    GridView,
+    SortByModified,
+    SortByName,
+    SortByCreated,
+    SortByLaunched,
+    SortManually,
+    UnreleasedOnly,
+    ShowInactive,
+    CommandPalette,
+    ProjectPalette,
     FocusSearch,
 }
 
+impl MenuAction {
+    /// Every action, in the order the menus and the command palette list them.
+    pub const ALL: [MenuAction; 23] = [
+        MenuAction::NewProject,
+        MenuAction::ImportProject,
+        MenuAction::ExportSnapshot,
+        MenuAction::OpenSnapshot,
+        MenuAction::About,
+        MenuAction::Refresh,
+        MenuAction::ListView,
+        MenuAction::GridView,
+        MenuAction::CommandPalette,
+        MenuAction::ProjectPalette,
+        MenuAction::FocusSearch,
+        MenuAction::Sessions,
+        MenuAction::Jobs,
+        MenuAction::Trash,
+        MenuAction::Statistics,
+        MenuAction::Settings,
+        MenuAction::UnreleasedOnly,
+        MenuAction::ShowInactive,
+        MenuAction::SortByModified,
+        MenuAction::SortByName,
+        MenuAction::SortByCreated,
+        MenuAction::SortByLaunched,
+        MenuAction::SortManually,
+    ];
+
+    /// Menu of the header listing the action, `None` for actions only reached otherwise.
+    pub fn menu(&self) -> Option<Menu> {
+        match self {
+            MenuAction::NewProject
+            | MenuAction::ImportProject
+            | MenuAction::ExportSnapshot
+            | MenuAction::OpenSnapshot => Some(Menu::File),
+            MenuAction::SortByModified
+            | MenuAction::SortByName
+            | MenuAction::SortByCreated
+            | MenuAction::SortByLaunched
+            | MenuAction::SortManually => Some(Menu::Sort),
+            MenuAction::FocusSearch => None,
+            _ => Some(Menu::View),
+        }
+    }
+
+    /// Whether the app-wide command palette offers the action.
+    pub fn in_palette(&self) -> bool {
+        !matches!(self.menu(), Some(Menu::Sort)) && *self != MenuAction::CommandPalette
+    }
+
+    /// Shortcut running the action unless changed in the settings, if it has one.
+    pub fn key_bind(&self) -> Option<KeyBind> {
+        let (modifiers, key) = match self {
+            MenuAction::NewProject => (vec![Modifier::Ctrl], "n"),
+            MenuAction::CommandPalette => (vec![Modifier::Ctrl], "k"),
+            MenuAction::ProjectPalette => (vec![Modifier::Ctrl, Modifier::Shift], "P"),
+            MenuAction::FocusSearch => (vec![], "f"),
+            _ => return None,
+        };
+
+        Some(KeyBind {
+            modifiers,
+            key: Key::Character(key.into()),
+        })
+    }
+
+    /// Name of the icon shown next to the label, if the action has one.
+    pub fn icon(&self) -> Option<&'static str> {
+        match self {
+            MenuAction::NewProject => Some("document-new-symbolic"),
+            MenuAction::OpenSnapshot => Some("document-open-symbolic"),
+            MenuAction::Refresh => Some("view-refresh-symbolic"),
+            MenuAction::ListView => Some("view-list-symbolic"),
+            MenuAction::GridView => Some("view-grid-symbolic"),
+            MenuAction::FocusSearch => Some("system-search-symbolic"),
+            MenuAction::Settings => Some("preferences-system-symbolic"),
+            MenuAction::About => Some("help-about-symbolic"),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            MenuAction::About => fl!("about"),
+            MenuAction::Settings => fl!("settings"),
+            MenuAction::Sessions => fl!("sessions"),
+            MenuAction::Jobs => fl!("jobs"),
+            MenuAction::Trash => fl!("trash"),
+            MenuAction::Statistics => fl!("statistics"),
+            MenuAction::NewProject => fl!("new-project"),
+            MenuAction::ImportProject => fl!("import-project"),
+            MenuAction::ExportSnapshot => fl!("snapshot-export"),
+            MenuAction::OpenSnapshot => fl!("snapshot-open"),
+            MenuAction::Refresh => fl!("refresh"),
+            MenuAction::ListView => fl!("list-view"),
+            MenuAction::GridView => fl!("grid-view"),
+            MenuAction::SortByModified => fl!("sort-by-modified"),
+            MenuAction::SortByName => fl!("sort-by-name"),
+            MenuAction::SortByCreated => fl!("sort-by-created"),
+            MenuAction::SortByLaunched => fl!("sort-by-launched"),
+            MenuAction::SortManually => fl!("sort-manually"),
+            MenuAction::UnreleasedOnly => fl!("unreleased-only"),
+            MenuAction::ShowInactive => fl!("show-inactive"),
+            MenuAction::CommandPalette => fl!("palette-title"),
+            MenuAction::ProjectPalette => fl!("palette-project"),
+            MenuAction::FocusSearch => fl!("focus-search"),
+        }
+    }
+}
+
 impl menu::action::MenuAction for MenuAction {
     type Message = Message;
 
     fn message(&self) -> Self::Message {
         match self {
             MenuAction::About => Message::OpenContextDrawer(ContextPage::About),
-            MenuAction::Settings => Message::OpenContextDrawer(ContextPage::Settings),
+            MenuAction::Settings => Message::OpenPage(Page::Settings),
+            MenuAction::Sessions => Message::OpenContextDrawer(ContextPage::Sessions),
+            MenuAction::Jobs => Message::OpenContextDrawer(ContextPage::Jobs),
+            MenuAction::Trash => Message::OpenContextDrawer(ContextPage::Trash),
+            MenuAction::Statistics => Message::OpenPage(Page::Statistics),
+            MenuAction::NewProject => Message::OpenNewProject,
+            MenuAction::ImportProject => Message::ImportProject,
+            MenuAction::ExportSnapshot => Message::ExportSnapshot,
+            MenuAction::OpenSnapshot => Message::OpenSnapshot,
+            MenuAction::Refresh => Message::RefreshProjects,
+            MenuAction::ListView => Message::ViewModeSelected(ViewMode::List),
+            MenuAction::GridView => Message::ViewModeSelected(ViewMode::Grid),
+            MenuAction::SortByModified => Message::SortOrderSelected(SortOrder::Modified),
+            MenuAction::SortByName => Message::SortOrderSelected(SortOrder::Name),
+            MenuAction::SortByCreated => Message::SortOrderSelected(SortOrder::Created),
+            MenuAction::SortByLaunched => Message::SortOrderSelected(SortOrder::Launched),
+            MenuAction::SortManually => Message::SortOrderSelected(SortOrder::Manual),
+            MenuAction::UnreleasedOnly => Message::UnreleasedOnlyToggled,
+            MenuAction::ShowInactive => Message::ShowInactiveToggled,
+            MenuAction::CommandPalette => Message::OpenCommandPalette,
+            MenuAction::ProjectPalette => Message::OpenProjectPalette,
             MenuAction::FocusSearch => Message::FocusSearchInput,
         }
     }
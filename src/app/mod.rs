@@ -1,86 +1,837 @@
+use crate::app::badge::Badge;
+use crate::app::config_repair::ConfigIssue;
 use crate::app::context_page::ContextPage;
-use crate::app::menu_action::MenuAction;
+use crate::app::dialog_page::DialogPage;
+use crate::app::job::{JobKind, JobQueue};
+use crate::app::menu_action::{Menu, MenuAction};
+use crate::app::palette::{self, PaletteEntry};
+use crate::app::project_action::{ProjectAction, ProjectMenu};
+use crate::app::shortcut::{self, KeyBinds, Shortcut};
+use crate::app::view_mode::{AfterLaunch, ClickAction, SortOrder, ViewMode};
+use crate::cli::{self, Page};
 use crate::config::Config;
-use crate::domain::program::Program;
-use crate::domain::project::Project;
+use crate::domain::annotation::{self, Annotation, ProjectAnnotation};
+use crate::domain::audit::{self, Vulnerability};
+use crate::domain::automation::{self, Automation, AutomationEvent};
+use crate::domain::autostart;
+use crate::domain::check::{self, CheckCommand, CheckKind, CheckResult};
+use crate::domain::command;
+use crate::domain::content_search::{self, ProjectMatches};
+use crate::domain::desktop_entry::{self, DesktopEntry};
+use crate::domain::digest::{self, Activity, ActivityKind, Digest};
+use crate::domain::dock::{self, DockPin};
+use crate::domain::env_profile::{self, EnvProfile, ProjectEnvProfile};
+use crate::domain::flatpak::{self, FlatpakApp};
+use crate::domain::forge::Forge;
+use crate::domain::git::{self, GitChanges, GitStatus};
+use crate::domain::history::{self, LaunchRecord};
+use crate::domain::import::{self, ImportMode};
+use crate::domain::launch::{self, LaunchMode};
+use crate::domain::launch_override::LaunchOverride;
+use crate::domain::mount::{self, RemovableRoot};
+use crate::domain::ownership::Ownership;
+use crate::domain::power::PowerState;
+use crate::domain::program::{self, DefaultProgram, Program, PromptAnswers};
+use crate::domain::project::{self, DedupedProjects, Project, ProjectKind};
+use crate::domain::project_icon;
+use crate::domain::ranking::{self, ProjectUsage};
+use crate::domain::readme::{self, ReadmeBadge};
+use crate::domain::release::{self, Release};
+use crate::domain::repo_task::RepoTask;
+use crate::domain::root_label::RootLabel;
+use crate::domain::rule::{self, Rule, RuleAction, RuleSet, RuleSyntax, RuleTarget};
+use crate::domain::scan_diff::ScanDiff;
+use crate::domain::session::Session;
+use crate::domain::snapshot::{self, Snapshot, SnapshotProject};
+use crate::domain::stats::{self, KindStats, ProjectStats};
+use crate::domain::template::{self, HookRun, Template};
+use crate::domain::terminal;
+use crate::domain::trash;
+use crate::domain::watch;
 use crate::fl;
 use cosmic::app::{context_drawer, Core, Task};
 use cosmic::cosmic_config::{self};
-use cosmic::iced::keyboard::{Key, Modifiers};
-use cosmic::iced::{event, keyboard, Event, Length, Subscription};
-use cosmic::widget::menu::{Action, KeyBind};
+use cosmic::iced::keyboard::{key::Named, Key, Modifiers};
+use cosmic::iced::platform_specific::shell::commands::activation;
+use cosmic::iced::{
+    event, keyboard, mouse, Alignment, Background, Border, Color, Event, Length, Subscription,
+};
+use cosmic::widget::menu::Action;
 use cosmic::widget::{self, menu};
-use cosmic::{cosmic_theme, theme, Application, ApplicationExt, Element, Theme};
+use cosmic::{cosmic_theme, dbus_activation, theme, Application, ApplicationExt, Element, Theme};
+use futures_util::SinkExt;
 use iter_tools::Itertools;
-use log::{error, info};
-use std::collections::HashMap;
-use std::fs::read_dir;
+use log::{error, info, warn};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::ops::Not;
-use std::path::PathBuf;
-use std::process::{Command, Stdio};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
+mod badge;
+mod config_repair;
 mod context_page;
+mod dialog_page;
+mod job;
 mod menu_action;
+mod palette;
+mod project_action;
+pub mod shortcut;
+pub mod view_mode;
 
 const REPOSITORY: &str = env!("CARGO_PKG_REPOSITORY");
+/// How long new or moved project rows stay highlighted.
+const ROW_HIGHLIGHT_DURATION: Duration = Duration::from_millis(1200);
+/// Minutes between scheduled health check runs offered in the settings, `0` meaning manual only.
+const HEALTH_CHECK_INTERVALS: [u64; 5] = [0, 15, 60, 360, 1440];
+
+/// Directory levels below the root searched for projects offered in the settings.
+const DISCOVERY_DEPTHS: [usize; 4] = [1, 2, 3, 4];
+
+/// Concurrency limits for background jobs offered in the settings.
+const JOB_CONCURRENCY_LIMITS: [usize; 4] = [1, 2, 4, 8];
+
+/// Shares in percent of launch usage in the search ranking offered in the settings.
+const USAGE_WEIGHTS: [u8; 5] = [0, 25, 50, 75, 100];
+
+/// Months without changes after which projects are hidden offered in the settings, `0` hiding none.
+const MAX_PROJECT_AGES: [u32; 5] = [0, 3, 6, 12, 24];
+
+/// Working directory choices of programs, following the setting, the project and the app's.
+const START_IN_PROJECT_OPTIONS: [Option<bool>; 3] = [None, Some(true), Some(false)];
+
+/// Months of inactivity offered for suggesting to hide projects, `0` suggesting none.
+const ARCHIVE_AFTER_MONTHS: [u32; 4] = [0, 6, 12, 24];
+
+const MONTH: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+/// Keys of the hints shown over the projects while Alt is held, in the order the projects get them.
+const HINT_KEYS: &str = "asdfghjklqwertyuiopzxcvbnm1234567890";
+
+/// Keys programs can be given as mnemonics in the settings.
+const MNEMONIC_KEYS: &str = "abcdefghijklmnopqrstuvwxyz";
+
+/// Time to wait for further changes to the root before rescanning it, e.g. while a repository is cloned.
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Number of projects read by a scan of the root before they are added to the list.
+const SCAN_BATCH_SIZE: usize = 32;
+
+/// Number of projects rendered at first and added by each "show more" press.
+const PROJECT_PAGE_SIZE: usize = 100;
+
+/// Number of toasts kept at once, older ones are dropped first.
+const MAX_TOASTS: usize = 3;
+
+/// Rows of the largest projects and largest artifacts tables of the statistics page.
+const LARGEST_PROJECTS: usize = 5;
+
+/// Projects a bulk launch opens at most, so a broad tag or search cannot flood the desktop.
+const BULK_LAUNCH_LIMIT: usize = 20;
 const APP_ICON: &[u8] = include_bytes!("../../resources/icons/hicolor/scalable/apps/icon.svg");
 
 pub struct AppModel {
+    /// Hides settings and everything changing projects or config, see [`Message::is_allowed_in_kiosk`].
+    kiosk: bool,
+    /// Imported snapshot browsed instead of the root, see [`Message::is_allowed_in_snapshot`].
+    snapshot: Option<Snapshot>,
     /// Application state which is managed by the COSMIC runtime.
     core: Core,
     /// Display a context drawer with the designated page if defined.
     context_page: ContextPage,
-    /// Key bindings for the application's menu bar.
-    key_binds: HashMap<menu::KeyBind, MenuAction>,
+    /// Display a dialog with the designated page if defined.
+    dialog_page: Option<DialogPage>,
+    /// Shortcuts of the menu and project actions, rebuilt when the config changes.
+    key_binds: KeyBinds,
+    /// Action whose new shortcut the next key press sets, from the keyboard shortcut settings.
+    recording_shortcut: Option<Shortcut>,
     // Configuration data that persists between application runs.
     config_handler: Option<cosmic_config::Config>,
     config: Config,
 
     search_text: String,
     search_input_id: widget::Id,
+    program_input_id: widget::Id,
+
+    /// Whether the search input searches file contents instead of project names.
+    content_search: bool,
+    /// Query of the content search currently running, if any.
+    content_search_query: Option<String>,
+    content_search_results: Vec<ProjectMatches>,
 
     root_path_input: String,
+    root_label_input: String,
+    templates_path_input: String,
     program_command_input: String,
     program_name_input: String,
+    /// Installed applications a program can be picked from.
+    desktop_entries: Vec<DesktopEntry>,
+    desktop_entry_labels: Vec<String>,
+    /// Programs of the default applications of the project kinds' MIME types and of directories,
+    /// launching projects no default program was chosen for.
+    default_handlers: HashMap<String, Program>,
+    program_desktop_entry: Option<usize>,
+    flatpak_apps: Vec<FlatpakApp>,
+    flatpak_app_labels: Vec<String>,
+    program_flatpak: Option<usize>,
+    program_run_in_terminal: bool,
+    program_start_in_project: Option<bool>,
+    start_in_project_labels: Vec<String>,
+    program_icon_input: String,
+    /// Variables of the program being added or edited, set when it is saved.
+    program_env: Vec<(String, String)>,
+    program_env_input: String,
+    /// Name of the program loaded into the inputs for editing, `None` while adding one.
+    program_edit: Option<String>,
+    terminal_input: String,
+    env_profile_name_input: String,
+    /// `KEY=value` typed into the variable input of each environment profile, by its name.
+    env_var_inputs: HashMap<String, String>,
+    /// Values typed into the prompts of the program about to be launched.
+    prompt_inputs: Vec<String>,
+    /// Launches waiting for their prompts while the prompts of another launch are asked for.
+    pending_prompts: VecDeque<(PathBuf, String)>,
+    /// Message held back until the unsaved settings are saved or discarded.
+    unsaved_settings_then: Option<Box<Message>>,
 
     projects: Vec<Project>,
     programs: Vec<Program>,
+    /// Programs as last read from or written to the config, telling changes made by other
+    /// instances apart from ours.
+    programs_on_disk: Vec<Program>,
+    root_read_only: bool,
+    /// Removable or network media the root lives on, watched for being mounted and unmounted.
+    root_mount: Option<RemovableRoot>,
+    /// Whether the media of the root is being looked up for a periodic check, which a hanging
+    /// network mount can keep going for longer than the check interval.
+    checking_root_mount: bool,
+    mount_error: Option<String>,
+    /// Maximum number of projects rendered, keeping huge lists cheap to draw.
+    visible_projects: usize,
+    /// Forges hosting the projects' `origin` remotes, `None` if a project has none.
+    project_forges: HashMap<PathBuf, Option<Forge>>,
+    /// Statistics of the projects for the footer, with the modification time they were collected for.
+    project_stats: HashMap<PathBuf, (SystemTime, ProjectStats)>,
+    last_scan: Option<SystemTime>,
+    /// Names of the programs whose executable could not be found at the last check.
+    broken_programs: Vec<String>,
+    /// Incremented with every scan of the root, so batches of a superseded scan are dropped.
+    scan_id: u64,
+    /// Projects found so far by the running scan, `None` while no scan is running.
+    scanned: Option<DedupedProjects>,
+    /// Paths and modification times listed before the running scan started, to tell which
+    /// projects are new or changed.
+    scan_previous: HashMap<PathBuf, SystemTime>,
+    /// Directories listing projects as of the last scan, watched for projects coming and going.
+    watched_dirs: Vec<PathBuf>,
+    /// Changes found by the last rescan, shown until dismissed.
+    scan_diff: Option<ScanDiff>,
+    /// Errors shown at the bottom of the window until dismissed, oldest first.
+    toasts: VecDeque<String>,
+    /// Checked out branches and working tree states, with the modification time they were
+    /// loaded for, `None` outside of repositories.
+    git_status: HashMap<PathBuf, (SystemTime, Option<GitStatus>)>,
+    /// Latest releases of the projects, with the modification time they were detected for.
+    releases: HashMap<PathBuf, (SystemTime, Option<Release>)>,
+    /// Whether only projects with commits after their latest release are listed.
+    unreleased_only: bool,
+    /// Also list the projects older than the maximum project age.
+    show_inactive: bool,
+    /// Tag picked from the chips below the search, listing only projects carrying it.
+    tag_filter: Option<String>,
+    /// Owners and last committers of the projects, loaded if enabled.
+    ownership: HashMap<PathBuf, Ownership>,
+    /// Dependency audit results, `None` if a project has no supported lock file.
+    audits: HashMap<PathBuf, Option<Result<Vec<Vulnerability>, String>>>,
+    /// Annotations from the shared file of the root, keyed by project folder name.
+    shared_annotations: HashMap<String, Annotation>,
+    /// Projects suggested for hiding, worked out again when the projects, their repositories or
+    /// the config change rather than on every render.
+    archive_suggested: HashSet<PathBuf>,
+    tag_input: String,
+    /// Whether Alt is held, showing the hints launching projects, see [`HINT_KEYS`].
+    hints_visible: bool,
+    /// Project being dragged into the manual order, with the project it would be dropped in front of.
+    drag: Option<(PathBuf, Option<PathBuf>)>,
+    /// Project picked with the select click action or the arrow keys.
+    selected_path: Option<PathBuf>,
+    /// Project whose context or overflow menu is open.
+    project_menu: Option<(PathBuf, ProjectMenu)>,
+    rename_input: String,
+    /// Program Enter launches the selected project with, picked with Tab or the number keys,
+    /// `None` for the default program of the project.
+    selected_program: Option<usize>,
+    click_action_labels: Vec<String>,
+    after_launch_labels: Vec<String>,
+    trash_error: Option<String>,
+    automation_event: usize,
+    automation_command_input: String,
+    automation_event_labels: Vec<String>,
+    ignore_pattern_input: String,
+    rule_target: usize,
+    rule_syntax: usize,
+    rule_pattern_input: String,
+    /// Action of the rule being added, its value as entered so far.
+    rule_action: RuleAction,
+    rule_target_labels: Vec<String>,
+    rule_syntax_labels: Vec<String>,
+    /// Labels of [`RuleAction::all`].
+    rule_action_labels: Vec<String>,
+    rule_kind_labels: Vec<String>,
+    jobs: JobQueue,
+    job_concurrency_labels: Vec<String>,
+    discovery_depth_labels: Vec<String>,
+    usage_weight_labels: Vec<String>,
+    /// No key, then the [`MNEMONIC_KEYS`].
+    mnemonic_labels: Vec<String>,
+    max_project_age_labels: Vec<String>,
+    archive_after_labels: Vec<String>,
+    /// `None` until first detected, background work waits for it.
+    power_state: Option<PowerState>,
+    /// Results of the configured checks, per kind and project.
+    check_results: HashMap<CheckKind, HashMap<PathBuf, Vec<CheckResult>>>,
+    check_marker_inputs: HashMap<CheckKind, String>,
+    check_command_inputs: HashMap<CheckKind, String>,
+    health_check_interval_labels: Vec<String>,
+    /// Logos found in the projects, `None` if a project has none.
+    project_icons: HashMap<PathBuf, Option<PathBuf>>,
+    /// Rows that just appeared or changed position, with the time they did so.
+    row_highlights: HashMap<PathBuf, Instant>,
+
+    templates: Vec<Template>,
+    new_project_name_input: String,
+    new_project_error: Option<String>,
+    /// Post-create hook of the template the project being created is scaffolded from.
+    pending_hook: Vec<String>,
+    /// Post-create hook running in the newly created project, kept while it failed.
+    hook_run: Option<HookRun>,
+    /// Files copied and to copy by the running import, `None` while no project is imported.
+    import_progress: Option<(usize, usize)>,
+    import_error: Option<String>,
+
+    /// Project shown in the details context page.
+    details_path: Option<PathBuf>,
+    details_changes: Option<GitChanges>,
+    details_badges: Vec<ReadmeBadge>,
+    /// Program whose launch override is being edited in the details context page.
+    override_program: Option<String>,
+    override_working_directory_input: String,
+    override_path_input: String,
+
+    session_name_input: String,
+    /// Program the details page adds the project to sessions with, the first one if unset.
+    session_program: Option<usize>,
+
+    palette_input_id: widget::Id,
+    palette_query: String,
+    /// Project the command palette runs commands for, `None` for the global palette.
+    palette_project: Option<PathBuf>,
+    /// Tasks of the repository of `palette_project`.
+    palette_tasks: Vec<RepoTask>,
+
+    /// Filter of the installed applications offered as programs.
+    installed_apps_query: String,
 }
 
 #[derive(Debug, Clone)]
 pub enum Message {
     Key(Modifiers, Key),
+    ModifiersChanged(Modifiers),
+    UsageWeightSelected(usize),
+    ProjectDragStart(PathBuf),
+    ProjectDragOver(PathBuf),
+    ProjectDragEnd,
 
     OpenContextDrawer(ContextPage),
+    /// Deep link to a page, e.g. from the command line or a banner.
+    OpenPage(Page),
     CloseContextDrawer,
+    ProgramsKeepMine,
+    ProgramsUseTheirs,
+    ProgramsMerge,
+    UnsavedSettingsSave,
+    UnsavedSettingsDiscard,
+
+    CloseDialog,
 
     LaunchUrl(String),
 
     UpdateConfig(Config),
 
     RootPathInputChanged(String),
+    RootLabelInputChanged(String),
     RootPathSave(PathBuf),
+    RootPathPick,
+    RootPathPicked(Option<PathBuf>),
+
+    TemplatesPathInputChanged(String),
+    TemplatesPathSave(PathBuf),
 
     ProgramCommandInputChanged(String),
     ProgramNameInputChanged(String),
+    ProgramRunInTerminalToggled(bool),
+    ProgramIconInputChanged(String),
+    ProgramEnvInputChanged(String),
+    ProgramEnvAdd,
+    ProgramEnvRemove(String),
+    /// Gives the program the mnemonic at the index into `mnemonic_labels`, `0` removing it.
+    ProgramMnemonicSelected(String, usize),
     ProgramSave,
+    TerminalInputChanged(String),
+    TerminalSave,
+    OpenWith(PathBuf),
+    /// Shows the project directory in the default file manager.
+    OpenFolder(PathBuf),
+    CopyPath(PathBuf),
+    OpenProjectMenu(PathBuf, ProjectMenu),
+    OpenRenameProject(PathBuf),
+    RenameInputChanged(String),
+    /// Renames the project of the open rename dialog, an empty name restoring the folder name.
+    RenameConfirm,
+    CloseProjectMenu,
+    ProjectActionRun(ProjectAction, PathBuf),
+    DockPinAdd(DockPin),
+    DockPinRemove(DockPin),
+    OpenWithFinished(Result<(), String>),
+    DesktopEntriesLoaded(Vec<DesktopEntry>),
+    DefaultHandlersLoaded(HashMap<String, DesktopEntry>),
+    ProgramDesktopEntrySelected(usize),
+    OpenInstalledApps,
+    InstalledAppsQueryChanged(String),
+    /// Adds the installed application at the index of the desktop entries as a program.
+    InstalledAppAdd(usize),
+    FlatpakAppsLoaded(Vec<FlatpakApp>),
+    ProgramFlatpakSelected(usize),
+    ProgramEdit(String),
+    ProgramEditCancel,
+    ProgramMoveUp(usize),
+    ProgramMoveDown(usize),
+    /// Copies the programs to the clipboard as a TOML snippet.
+    ProgramsExport,
+    /// Reads a snippet of shared programs from the clipboard.
+    ProgramsImport,
+    ProgramsPasted(Option<String>),
     ProgramDelete(String),
+    ProgramDeleteConfirmed(String),
+    DefaultProgramSelected(String),
+    /// Sets or, with `None`, clears the default program of a single project.
+    ProjectDefaultProgramSelected(PathBuf, Option<String>),
+    EnvProfileNameInputChanged(String),
+    EnvProfileAdd,
+    EnvProfileDelete(String),
+    EnvVarInputChanged(String, String),
+    EnvVarAdd(String),
+    EnvVarRemove(String, String),
+    /// Sets or, with `None`, clears the environment profile of a single project.
+    ProjectEnvProfileSelected(PathBuf, Option<String>),
 
     UpdateProjects,
+    /// Rescans the root, rereading the git state of every project.
+    RefreshProjects,
+    ValidatePrograms,
+    ProgramsValidated(Vec<String>),
+    /// Read before the projects of the scan, so their annotations apply to every batch.
+    RootLoaded {
+        scan: u64,
+        read_only: bool,
+        shared_annotations: HashMap<String, Annotation>,
+    },
+    ProjectsScanned {
+        scan: u64,
+        projects: Vec<Project>,
+    },
+    /// The scan is done, with the directories listing projects on success.
+    ProjectScanFinished {
+        scan: u64,
+        result: Result<Vec<PathBuf>, String>,
+    },
 
     LaunchProject {
-        project_name: String,
+        project_path: PathBuf,
+        program_name: String,
+    },
+    /// Launches the project once the compositor handed out a token to focus the program's window.
+    LaunchProjectActivated {
+        project_path: PathBuf,
         program_name: String,
+        token: Option<String>,
+        answers: Vec<(String, String)>,
     },
+    LaunchPromptInputChanged(usize, String),
+    LaunchPromptConfirm,
+
+    OpenProjectDetails(PathBuf),
+    ProjectChangesLoaded {
+        project_path: PathBuf,
+        changes: Option<GitChanges>,
+    },
+    ReadmeBadgesLoaded {
+        project_path: PathBuf,
+        badges: Vec<ReadmeBadge>,
+    },
+
+    LaunchOverrideEdit(String),
+    LaunchOverrideWorkingDirectoryInputChanged(String),
+    LaunchOverridePathInputChanged(String),
+    LaunchOverrideSave,
+    LaunchOverrideDelete(String),
 
     SearchTextInputChanged(String),
+    SearchSubmit,
+    ShowMoreProjects,
+    ViewModeSelected(ViewMode),
+    SortOrderSelected(SortOrder),
+    TrackLaunchedToggled(bool),
+    StartInProjectToggled(bool),
+    /// Picks the working directory of the program being edited from `START_IN_PROJECT_OPTIONS`.
+    ProgramStartInProjectSelected(usize),
+    AfterLaunchSelected(usize),
+    HideHeaderControlsToggled(bool),
+    /// Sets the shortcut of the action to the next keys pressed.
+    ShortcutRecord(Shortcut),
+    ShortcutReset(Shortcut),
+    ShortcutClear(Shortcut),
+    ClickActionSelected(ViewMode, usize),
+    ProjectClicked(PathBuf),
+    PinToggled(PathBuf),
+    ProjectHide(PathBuf),
+    ProjectUnhide(PathBuf),
+    ProjectTrash(PathBuf),
+    /// Restores the trashed project that was at the given path.
+    ProjectRestore(PathBuf),
+    TrashForget(PathBuf),
+    OpenProjectTags(PathBuf),
+    TagInputChanged(String),
+    TagFilterToggled(String),
+    TagAdd(PathBuf),
+    TagRemove(PathBuf, String),
+    AnimationTick,
+    ReduceMotionToggled(bool),
+    ColorBlindPaletteToggled(bool),
+    ShowProjectIconsToggled(bool),
+    ProjectIconsLoaded(Vec<(PathBuf, Option<PathBuf>)>),
+    ProjectForgesLoaded(Vec<(PathBuf, Option<Forge>)>),
+    SecurityAuditToggled(bool),
+    ShowOwnershipToggled(bool),
+    ShowFooterToggled(bool),
+    StatsLoaded {
+        project_path: PathBuf,
+        modify: SystemTime,
+        stats: ProjectStats,
+    },
+    /// Asks before deleting the artifact folders of the project.
+    ProjectClean(PathBuf),
+    /// The artifact folders safe to delete were picked, `size` bytes in total.
+    ProjectCleanPrepared {
+        project_path: PathBuf,
+        dirs: Vec<&'static str>,
+        size: u64,
+    },
+    ProjectCleanConfirm,
+    ProjectCleaned {
+        project_path: PathBuf,
+        modify: SystemTime,
+        /// Bytes freed and the statistics after cleaning.
+        result: Result<(u64, ProjectStats), String>,
+    },
+    GitStatusLoaded {
+        project_path: PathBuf,
+        modify: SystemTime,
+        status: Option<GitStatus>,
+    },
+    ReleaseLoaded {
+        project_path: PathBuf,
+        modify: SystemTime,
+        release: Option<Release>,
+    },
+    UnreleasedOnlyToggled,
+    ShowInactiveToggled,
+    MaxProjectAgeSelected(usize),
+    ArchiveAfterSelected(usize),
+    /// Asks before hiding every project suggested for hiding.
+    ArchiveSuggestionsAccept,
+    ArchiveSuggestionsHideConfirm,
+    /// Stops suggesting every project currently suggested.
+    ArchiveSuggestionsDismiss,
+    ArchiveSuggestionAccept(PathBuf),
+    ArchiveSuggestionDismiss(PathBuf),
+    OwnershipLoaded {
+        project_path: PathBuf,
+        ownership: Ownership,
+    },
+    IgnorePowerStateToggled(bool),
+    AutomationEventSelected(usize),
+    AutomationCommandInputChanged(String),
+    AutomationAdd,
+    AutomationDelete(usize),
+    IgnorePatternInputChanged(String),
+    IgnorePatternAdd,
+    IgnorePatternDelete(usize),
+    IgnorePatternsReset,
+    RuleTargetSelected(usize),
+    RuleSyntaxSelected(usize),
+    RulePatternInputChanged(String),
+    RuleActionSelected(usize),
+    RuleKindSelected(usize),
+    RuleValueInputChanged(String),
+    RuleAdd,
+    RuleDelete(usize),
+    ValidateConfig,
+    ConfigValidated(Vec<ConfigIssue>),
+    RemoveConfigIssue(ConfigIssue),
+    JobConcurrencySelected(usize),
+    DiscoveryDepthSelected(usize),
+    JobCancel(u64),
+    JobFinished {
+        id: u64,
+        result: Result<Box<Message>, String>,
+    },
+    /// Scans the root once the media it lives on was looked up.
+    ScanRoot(PathBuf, Option<RemovableRoot>),
+    CheckRootMount,
+    RootMountChecked(PathBuf, Option<RemovableRoot>),
+    MountRoot,
+    RootMounted(Result<(), String>),
+    WeeklyDigestToggled(bool),
+    CheckWeeklyDigest,
+    DetectPowerState,
+    PowerStateDetected(PowerState),
+    CheckMarkerInputChanged(CheckKind, String),
+    CheckCommandInputChanged(CheckKind, String),
+    CheckAdd(CheckKind),
+    CheckDelete(CheckKind, usize),
+    HealthCheckIntervalSelected(usize),
+    /// Reruns the checks of a kind for every project, or only the given one.
+    RunChecks(CheckKind, Option<PathBuf>),
+    ChecksFinished {
+        kind: CheckKind,
+        project_path: PathBuf,
+        results: Vec<CheckResult>,
+    },
+    AuditFinished {
+        project_path: PathBuf,
+        result: Option<Result<Vec<Vulnerability>, String>>,
+    },
     FocusSearchInput,
+
+    ContentSearchToggled(bool),
+    ContentSearchFinished {
+        query: String,
+        results: Vec<ProjectMatches>,
+    },
+
+    OpenFile(PathBuf),
+
+    OpenNewProject,
+    NewProjectNameInputChanged(String),
+    NewProjectCreate(Option<PathBuf>),
+    NewProjectCreated(Result<PathBuf, String>),
+    HookStepFinished(Result<(), String>),
+    DismissHookRun,
+
+    ImportProject,
+    ExportSnapshot,
+    SnapshotExportPicked(Option<PathBuf>),
+    OpenSnapshot,
+    SnapshotPicked(Option<PathBuf>),
+    CloseSnapshot,
+    ImportProjectPicked(Option<PathBuf>),
+    ImportProjectStart {
+        source: PathBuf,
+        mode: ImportMode,
+    },
+    ImportProjectProgress {
+        done: usize,
+        total: usize,
+    },
+    ImportProjectFinished(Result<PathBuf, String>),
+    DismissImportError,
+    DismissScanDiff,
+    SharedAnnotationsMoved(Result<(), String>),
+    ShowToast(String),
+    DismissToast(usize),
+
+    SessionNameInputChanged(String),
+    SessionCreate,
+    SessionDelete(String),
+    SessionLaunch(String),
+    BulkLaunchTag(String),
+    /// Launches the projects matching the saved search, whatever is searched right now.
+    BulkLaunchSavedSearch(String),
+    SavedSearchAdd,
+    SavedSearchDelete(String),
+    BulkLaunchProgramSelected(usize),
+    BulkLaunchConfirm,
+    SessionAutostartToggled(String, bool),
+    SessionProgramSelected(usize),
+    SessionAddProject(String),
+    SessionRemoveEntry {
+        session: String,
+        project: PathBuf,
+        program: String,
+    },
+
+    OpenCommandPalette,
+    OpenProjectPalette,
+    OpenProjectPaletteFor(PathBuf),
+    PaletteQueryChanged(String),
+    PaletteSubmit,
+    PaletteRun(Box<Message>),
+
+    RunRepoTask {
+        project_path: PathBuf,
+        command: String,
+    },
+}
+
+impl Message {
+    /// Whether the message only browses or launches projects, the only things kiosk mode allows.
+    fn is_allowed_in_kiosk(&self) -> bool {
+        match self {
+            Message::OpenContextDrawer(context_page) => matches!(
+                context_page,
+                ContextPage::About | ContextPage::ProjectDetails | ContextPage::CheckLog
+            ),
+            Message::OpenPage(page) => {
+                Message::OpenContextDrawer(ContextPage::from(*page)).is_allowed_in_kiosk()
+            }
+            Message::PaletteRun(message) => message.is_allowed_in_kiosk(),
+            Message::ProjectActionRun(action, project) => {
+                action.message(project).is_allowed_in_kiosk()
+            }
+            Message::RootPathSave(_)
+            | Message::RootPathPick
+            | Message::TemplatesPathSave(_)
+            | Message::ProgramSave
+            | Message::TerminalSave
+            | Message::ProgramEdit(_)
+            | Message::InstalledAppAdd(_)
+            | Message::ProgramsKeepMine
+            | Message::ProjectClean(_)
+            | Message::ProjectCleanPrepared { .. }
+            | Message::ProjectCleanConfirm
+            | Message::ProgramsMerge
+            | Message::ProgramMoveUp(_)
+            | Message::ProgramMoveDown(_)
+            | Message::ProgramsPasted(_)
+            | Message::ProgramDelete(_)
+            | Message::ProgramDeleteConfirmed(_)
+            | Message::DefaultProgramSelected(_)
+            | Message::ProgramMnemonicSelected(..)
+            | Message::ProjectDefaultProgramSelected(..)
+            | Message::EnvProfileAdd
+            | Message::EnvProfileDelete(_)
+            | Message::EnvVarAdd(_)
+            | Message::EnvVarRemove(..)
+            | Message::ProjectEnvProfileSelected(..)
+            | Message::LaunchOverrideSave
+            | Message::LaunchOverrideDelete(_)
+            | Message::ReduceMotionToggled(_)
+            | Message::ColorBlindPaletteToggled(_)
+            | Message::ShowProjectIconsToggled(_)
+            | Message::SecurityAuditToggled(_)
+            | Message::ShowOwnershipToggled(_)
+            | Message::ShowFooterToggled(_)
+            | Message::IgnorePowerStateToggled(_)
+            | Message::AutomationAdd
+            | Message::AutomationDelete(_)
+            | Message::IgnorePatternAdd
+            | Message::IgnorePatternDelete(_)
+            | Message::IgnorePatternsReset
+            | Message::RuleAdd
+            | Message::RuleDelete(_)
+            | Message::DockPinAdd(_)
+            | Message::DockPinRemove(_)
+            | Message::ValidateConfig
+            | Message::RemoveConfigIssue(_)
+            | Message::JobConcurrencySelected(_)
+            | Message::DiscoveryDepthSelected(_)
+            | Message::JobCancel(_)
+            | Message::CheckAdd(_)
+            | Message::CheckDelete(..)
+            | Message::HealthCheckIntervalSelected(_)
+            | Message::OpenNewProject
+            | Message::NewProjectCreate(_)
+            | Message::ImportProject
+            | Message::ImportProjectPicked(_)
+            | Message::ImportProjectStart { .. }
+            | Message::SessionCreate
+            | Message::SessionDelete(_)
+            | Message::SessionAutostartToggled(..)
+            | Message::SessionAddProject(_)
+            | Message::SessionRemoveEntry { .. }
+            | Message::SavedSearchAdd
+            | Message::SavedSearchDelete(_)
+            | Message::RunRepoTask { .. }
+            | Message::PinToggled(_)
+            | Message::ProjectDragStart(_)
+            | Message::ClickActionSelected(..)
+            | Message::HideHeaderControlsToggled(_)
+            | Message::ShortcutRecord(_)
+            | Message::ShortcutReset(_)
+            | Message::ShortcutClear(_)
+            | Message::TrackLaunchedToggled(_)
+            | Message::StartInProjectToggled(_)
+            | Message::AfterLaunchSelected(_)
+            | Message::WeeklyDigestToggled(_)
+            | Message::UsageWeightSelected(_)
+            | Message::MaxProjectAgeSelected(_)
+            | Message::ArchiveAfterSelected(_)
+            | Message::ArchiveSuggestionsAccept
+            | Message::ArchiveSuggestionsHideConfirm
+            | Message::ArchiveSuggestionsDismiss
+            | Message::ArchiveSuggestionAccept(_)
+            | Message::ArchiveSuggestionDismiss(_)
+            | Message::ProjectHide(_)
+            | Message::ProjectUnhide(_)
+            | Message::ProjectTrash(_)
+            | Message::ProjectRestore(_)
+            | Message::TrashForget(_)
+            | Message::OpenProjectTags(_)
+            | Message::OpenRenameProject(_)
+            | Message::RenameConfirm
+            | Message::TagAdd(_)
+            | Message::TagRemove(..) => false,
+            _ => true,
+        }
+    }
+
+    /// Whether the message works without access to the projects, the only things snapshots allow.
+    fn is_allowed_in_snapshot(&self) -> bool {
+        match self {
+            Message::PaletteRun(message) => message.is_allowed_in_snapshot(),
+            Message::ProjectActionRun(action, project) => {
+                action.message(project).is_allowed_in_snapshot()
+            }
+            Message::LaunchProject { .. }
+            | Message::LaunchProjectActivated { .. }
+            | Message::LaunchPromptConfirm
+            | Message::OpenWith(_)
+            | Message::OpenFolder(_)
+            | Message::SessionLaunch(_)
+            | Message::BulkLaunchTag(_)
+            | Message::BulkLaunchSavedSearch(_)
+            | Message::BulkLaunchConfirm
+            | Message::UpdateProjects
+            | Message::RefreshProjects
+            | Message::ScanRoot(..)
+            | Message::CheckRootMount
+            | Message::MountRoot
+            | Message::RunChecks(..)
+            | Message::CheckWeeklyDigest
+            | Message::ProjectClean(_)
+            | Message::ProjectCleanPrepared { .. }
+            | Message::ProjectCleanConfirm
+            | Message::ContentSearchToggled(_) => false,
+            message => message.is_allowed_in_kiosk(),
+        }
+    }
 }
 
 impl Application for AppModel {
     type Executor = cosmic::executor::Default;
 
-    type Flags = ();
+    type Flags = crate::cli::Args;
 
     type Message = Message;
 
@@ -94,7 +845,7 @@ impl Application for AppModel {
         &mut self.core
     }
 
-    fn init(core: Core, _flags: Self::Flags) -> (Self, Task<Self::Message>) {
+    fn init(core: Core, flags: Self::Flags) -> (Self, Task<Self::Message>) {
         let (config_handler, config) = Config::load();
 
         let path = config
@@ -103,32 +854,222 @@ impl Application for AppModel {
             .unwrap_or_default()
             .to_string();
 
-        let programs = config.programs().to_vec();
+        let templates_path = config
+            .templates_path()
+            .map(|path| path.to_str().unwrap_or_default())
+            .unwrap_or_default()
+            .to_string();
 
-        let mut key_binds = HashMap::new();
+        let programs = config.programs().to_vec();
 
-        key_binds.insert(
-            KeyBind {
-                modifiers: vec![],
-                key: Key::Character("f".into()),
-            },
-            MenuAction::FocusSearch,
-        );
+        let key_binds = KeyBinds::new(config.shortcuts());
 
         let mut app = AppModel {
             core,
+            kiosk: flags.kiosk(),
+            snapshot: None,
             context_page: ContextPage::default(),
-            key_binds: key_binds,
+            dialog_page: None,
+            key_binds,
+            recording_shortcut: None,
             // Optional configuration file for an application.
             config_handler,
             config,
-            search_text: "".to_string(),
+            search_text: flags.search().unwrap_or_default().to_string(),
             search_input_id: widget::Id::unique(),
+            program_input_id: widget::Id::unique(),
+            content_search: false,
+            content_search_query: None,
+            content_search_results: vec![],
             root_path_input: path,
+            root_label_input: config
+                .project_root_path()
+                .and_then(|root| config.root_label(root))
+                .unwrap_or_default()
+                .to_string(),
+            templates_path_input: templates_path,
             program_command_input: "".to_string(),
             program_name_input: "".to_string(),
+            desktop_entries: vec![],
+            default_handlers: HashMap::new(),
+            desktop_entry_labels: vec![],
+            program_desktop_entry: None,
+            flatpak_apps: vec![],
+            flatpak_app_labels: vec![],
+            program_flatpak: None,
+            program_run_in_terminal: false,
+            program_start_in_project: None,
+            start_in_project_labels: vec![
+                fl!("settings-program-start-default"),
+                fl!("settings-program-start-in-project"),
+                fl!("settings-program-start-outside-project"),
+            ],
+            program_icon_input: "".to_string(),
+            program_env: vec![],
+            program_env_input: "".to_string(),
+            program_edit: None,
+            terminal_input: config.terminal().unwrap_or_default().to_string(),
+            env_profile_name_input: "".to_string(),
+            env_var_inputs: HashMap::new(),
+            prompt_inputs: vec![],
+            pending_prompts: VecDeque::new(),
+            unsaved_settings_then: None,
             projects: vec![],
+            programs_on_disk: programs.clone(),
             programs,
+            root_read_only: false,
+            root_mount: None,
+            checking_root_mount: false,
+            mount_error: None,
+            visible_projects: PROJECT_PAGE_SIZE,
+            project_forges: HashMap::new(),
+            project_icons: HashMap::new(),
+            audits: HashMap::new(),
+            shared_annotations: HashMap::new(),
+            archive_suggested: HashSet::new(),
+            ownership: HashMap::new(),
+            project_stats: HashMap::new(),
+            last_scan: None,
+            broken_programs: vec![],
+            scan_id: 0,
+            scanned: None,
+            scan_previous: HashMap::new(),
+            watched_dirs: vec![],
+            scan_diff: None,
+            toasts: VecDeque::new(),
+            git_status: HashMap::new(),
+            releases: HashMap::new(),
+            unreleased_only: false,
+            tag_filter: None,
+            show_inactive: false,
+            tag_input: "".to_string(),
+            hints_visible: false,
+            drag: None,
+            selected_path: None,
+            project_menu: None,
+            rename_input: "".to_string(),
+            selected_program: None,
+            click_action_labels: ClickAction::ALL
+                .iter()
+                .map(|action| action.label())
+                .collect(),
+            after_launch_labels: AfterLaunch::ALL
+                .iter()
+                .map(|after_launch| after_launch.label())
+                .collect(),
+            trash_error: None,
+            automation_event: 0,
+            automation_command_input: "".to_string(),
+            automation_event_labels: AutomationEvent::ALL
+                .iter()
+                .map(|event| match event {
+                    AutomationEvent::ProjectLaunched => fl!("automation-project-launched"),
+                    AutomationEvent::ProjectDiscovered => fl!("automation-project-discovered"),
+                    AutomationEvent::ScanFinished => fl!("automation-scan-finished"),
+                })
+                .collect(),
+            ignore_pattern_input: "".to_string(),
+            rule_target: 0,
+            rule_syntax: 0,
+            rule_pattern_input: "".to_string(),
+            rule_action: RuleAction::Tag(String::new()),
+            rule_target_labels: RuleTarget::ALL
+                .iter()
+                .map(|target| match target {
+                    RuleTarget::Name => fl!("rule-target-name"),
+                    RuleTarget::Path => fl!("rule-target-path"),
+                })
+                .collect(),
+            rule_syntax_labels: RuleSyntax::ALL
+                .iter()
+                .map(|syntax| match syntax {
+                    RuleSyntax::Glob => fl!("rule-syntax-glob"),
+                    RuleSyntax::Regex => fl!("rule-syntax-regex"),
+                })
+                .collect(),
+            rule_action_labels: RuleAction::all()
+                .iter()
+                .map(|action| match action {
+                    RuleAction::Tag(_) => fl!("rule-action-tag"),
+                    RuleAction::Kind(_) => fl!("rule-action-kind"),
+                    RuleAction::DisplayName(_) => fl!("rule-action-display-name"),
+                })
+                .collect(),
+            rule_kind_labels: ProjectKind::ALL
+                .iter()
+                .map(|kind| kind.label().to_string())
+                .collect(),
+            jobs: JobQueue::default(),
+            max_project_age_labels: MAX_PROJECT_AGES
+                .iter()
+                .map(|&months| match months {
+                    0 => fl!("settings-max-project-age-off"),
+                    months => fl!("settings-max-project-age-months", months = months),
+                })
+                .collect(),
+            archive_after_labels: ARCHIVE_AFTER_MONTHS
+                .iter()
+                .map(|&months| match months {
+                    0 => fl!("settings-archive-after-off"),
+                    months => fl!("settings-max-project-age-months", months = months),
+                })
+                .collect(),
+            mnemonic_labels: std::iter::once(fl!("settings-program-mnemonic-none"))
+                .chain(MNEMONIC_KEYS.chars().map(|key| key.to_string()))
+                .collect(),
+            usage_weight_labels: USAGE_WEIGHTS
+                .iter()
+                .map(|&weight| match weight {
+                    0 => fl!("settings-usage-weight-off"),
+                    weight => format!("{weight} %"),
+                })
+                .collect(),
+            job_concurrency_labels: JOB_CONCURRENCY_LIMITS
+                .iter()
+                .map(|limit| limit.to_string())
+                .collect(),
+            discovery_depth_labels: DISCOVERY_DEPTHS
+                .iter()
+                .map(|&depth| match depth {
+                    1 => fl!("settings-discovery-depth-root"),
+                    depth => fl!("settings-discovery-depth-levels", levels = depth),
+                })
+                .collect(),
+            power_state: None,
+            check_results: HashMap::new(),
+            check_marker_inputs: HashMap::new(),
+            check_command_inputs: HashMap::new(),
+            health_check_interval_labels: HEALTH_CHECK_INTERVALS
+                .iter()
+                .map(|&minutes| match minutes {
+                    0 => fl!("settings-health-check-manual"),
+                    minutes if minutes % 60 == 0 => {
+                        fl!("settings-health-check-every-hours", hours = minutes / 60)
+                    }
+                    minutes => fl!("settings-health-check-every-minutes", minutes = minutes),
+                })
+                .collect(),
+            row_highlights: HashMap::new(),
+            templates: vec![],
+            new_project_name_input: "".to_string(),
+            new_project_error: None,
+            pending_hook: vec![],
+            hook_run: None,
+            import_progress: None,
+            import_error: None,
+            details_path: None,
+            details_changes: None,
+            details_badges: vec![],
+            override_program: None,
+            override_working_directory_input: "".to_string(),
+            override_path_input: "".to_string(),
+            session_name_input: "".to_string(),
+            session_program: None,
+            palette_input_id: widget::Id::unique(),
+            palette_query: "".to_string(),
+            palette_project: None,
+            palette_tasks: vec![],
+            installed_apps_query: "".to_string(),
         };
 
         info!("{:?}", app.config.project_root_path());
@@ -136,13 +1077,61 @@ impl Application for AppModel {
         let update_title_task = app.update_title();
         let task = Task::batch(vec![
             update_title_task,
+            Task::done(cosmic::app::Message::App(Message::DetectPowerState)),
+            Task::done(cosmic::app::Message::App(Message::ValidatePrograms)),
+            Task::done(cosmic::app::Message::App(Message::CheckWeeklyDigest)),
+            Task::perform(
+                tokio::task::spawn_blocking(desktop_entry::installed),
+                |entries| {
+                    cosmic::app::Message::App(Message::DesktopEntriesLoaded(
+                        entries.unwrap_or_default(),
+                    ))
+                },
+            ),
+            Task::perform(tokio::task::spawn_blocking(flatpak::installed), |apps| {
+                cosmic::app::Message::App(Message::FlatpakAppsLoaded(apps.unwrap_or_default()))
+            }),
             Task::done(cosmic::app::Message::App(Message::UpdateProjects)),
             Task::done(cosmic::app::Message::App(Message::FocusSearchInput)),
         ]);
 
+        let task = match flags.page() {
+            Some(page) => task.chain(Task::done(cosmic::app::Message::App(Message::OpenPage(
+                page,
+            )))),
+            None => task,
+        };
+
         (app, task)
     }
 
+    /// Applies the arguments of a second launch to this instance.
+    fn dbus_activation(&mut self, msg: dbus_activation::Message) -> Task<Self::Message> {
+        let dbus_activation::Details::ActivateAction { action, args } = msg.msg else {
+            return Task::none();
+        };
+
+        let action: cli::Action = match action.parse() {
+            Ok(action) => action,
+            Err(err) => {
+                warn!("{err}, ignoring {args:?}");
+                return Task::none();
+            }
+        };
+
+        let mut tasks = vec![];
+
+        if let Some(term) = action.search() {
+            tasks.push(self.update(Message::SearchTextInputChanged(term.to_string())));
+            tasks.push(self.update(Message::FocusSearchInput));
+        }
+        if let Some(page) = action.page() {
+            tasks.push(self.update(Message::OpenPage(page)));
+        }
+
+        Task::batch(tasks)
+    }
+
     fn context_drawer(&self) -> Option<context_drawer::ContextDrawer<Self::Message>> {
         if !self.core.window.show_context {
             return None;
@@ -151,29 +1140,98 @@ impl Application for AppModel {
         Some(self.context_page.view(self))
     }
 
+    fn dialog(&self) -> Option<Element<Self::Message>> {
+        self.dialog_page
+            .as_ref()
+            .map(|dialog_page| dialog_page.view(self))
+    }
+
     fn header_start(&self) -> Vec<Element<Self::Message>> {
-        let menu_bar = menu::bar(vec![menu::Tree::with_children(
+        let mut trees = vec![];
+
+        let file_items = self.menu_items(Menu::File);
+
+        if !file_items.is_empty() {
+            trees.push(menu::Tree::with_children(
+                menu::root(fl!("file")),
+                menu::items(self.key_binds.menu(), file_items),
+            ));
+        }
+
+        trees.push(menu::Tree::with_children(
             menu::root(fl!("view")),
-            menu::items(
-                &self.key_binds,
-                vec![
-                    menu::Item::Button(fl!("about"), None, MenuAction::About),
-                    menu::Item::Button(fl!("settings"), None, MenuAction::Settings),
-                ],
-            ),
+            menu::items(self.key_binds.menu(), self.menu_items(Menu::View)),
+        ));
+
+        vec![menu::bar(trees).into()]
+    }
+
+    fn header_center(&self) -> Vec<Element<Self::Message>> {
+        let cosmic_theme::Spacing { space_xs, .. } = theme::active().cosmic().spacing;
+
+        let placeholder = if self.content_search {
+            fl!("content-search-input")
+        } else if self.config.show_ownership() {
+            fl!("search-input-person")
+        } else {
+            fl!("search-input")
+        };
+
+        let input = widget::search_input(placeholder, &self.search_text)
+            .on_input(Message::SearchTextInputChanged)
+            .on_submit(Message::SearchSubmit)
+            .id(self.search_input_id.clone())
+            .width(Length::Fixed(360.0));
+
+        let content_search = widget::toggler(self.content_search)
+            .label(fl!("content-search"))
+            .on_toggle(Message::ContentSearchToggled);
+
+        vec![widget::Row::new()
+            .push(input)
+            .push(content_search)
+            .align_y(Alignment::Center)
+            .spacing(space_xs)
+            .into()]
+    }
+
+    fn header_end(&self) -> Vec<Element<Self::Message>> {
+        if self.config.hide_header_controls() {
+            return vec![];
+        }
+
+        let view_action = match self.config.view_mode() {
+            ViewMode::List => MenuAction::GridView,
+            ViewMode::Grid => MenuAction::ListView,
+        };
+
+        let view_toggle = widget::button::icon(widget::icon::from_name(
+            view_action.icon().unwrap_or_default(),
+        ))
+        .on_press(view_action.message());
+
+        let sort = menu::bar(vec![menu::Tree::with_children(
+            menu::root(fl!("sort")),
+            menu::items(self.key_binds.menu(), self.menu_items(Menu::Sort)),
         )]);
 
-        vec![menu_bar.into()]
+        vec![view_toggle.into(), sort.into()]
     }
 
     fn subscription(&self) -> Subscription<Self::Message> {
-        let subscriptions = vec![
+        let mut subscriptions = vec![
             event::listen_with(|event, status, window_id| match event {
                 Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) => match status
                 {
                     event::Status::Ignored => Some(Message::Key(modifiers, key)),
                     event::Status::Captured => None,
                 },
+                Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                    Some(Message::ModifiersChanged(modifiers))
+                }
+                Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                    Some(Message::ProjectDragEnd)
+                }
                 _ => None,
             }),
             self.core()
@@ -181,27 +1239,255 @@ impl Application for AppModel {
                 .map(|update| Message::UpdateConfig(update.config)),
         ];
 
+        if !self.row_highlights.is_empty() {
+            subscriptions.push(
+                cosmic::iced::time::every(Duration::from_millis(32))
+                    .map(|_| Message::AnimationTick),
+            );
+        }
+
+        subscriptions.push(
+            cosmic::iced::time::every(Duration::from_secs(60)).map(|_| Message::DetectPowerState),
+        );
+
+        if !self.programs.is_empty() {
+            subscriptions.push(
+                cosmic::iced::time::every(Duration::from_secs(5 * 60))
+                    .map(|_| Message::ValidatePrograms),
+            );
+        }
+
+        if self.config.weekly_digest() {
+            subscriptions.push(
+                cosmic::iced::time::every(Duration::from_secs(60 * 60))
+                    .map(|_| Message::CheckWeeklyDigest),
+            );
+        }
+
+        // keyed by the directories, so grouping directories created later are watched after the
+        // rescan they trigger found them
+        if self.watched_root().is_some() && !self.watched_dirs.is_empty() {
+            let dirs = self.watched_dirs.clone();
+
+            subscriptions.push(Subscription::run_with_id(
+                dirs.clone(),
+                cosmic::iced::stream::channel(1, move |mut output| async move {
+                    let (sender, mut changes) = tokio::sync::mpsc::unbounded_channel();
+
+                    let _watcher = match watch::watch(&dirs, move || {
+                        let _ = sender.send(());
+                    }) {
+                        Ok(watcher) => watcher,
+                        Err(err) => {
+                            error!("failed to watch {dirs:?}: {err}");
+                            return;
+                        }
+                    };
+
+                    while changes.recv().await.is_some() {
+                        tokio::time::sleep(WATCH_DEBOUNCE).await;
+                        while changes.try_recv().is_ok() {}
+
+                        if output.send(Message::UpdateProjects).await.is_err() {
+                            break;
+                        }
+                    }
+                }),
+            ));
+        }
+
+        if self.root_mount.is_some() {
+            subscriptions.push(
+                cosmic::iced::time::every(Duration::from_secs(5)).map(|_| Message::CheckRootMount),
+            );
+        }
+
+        let health_check_interval = self.config.health_check_interval();
+
+        if health_check_interval > 0 && !self.background_paused() {
+            subscriptions.push(
+                cosmic::iced::time::every(Duration::from_secs(health_check_interval * 60))
+                    .map(|_| Message::RunChecks(CheckKind::Health, None)),
+            );
+        }
+
         Subscription::batch(subscriptions)
     }
 
     fn update(&mut self, message: Self::Message) -> Task<Self::Message> {
+        if !self.is_allowed(&message) {
+            return Task::none();
+        }
+
+        if self.leaves_unsaved_settings(&message) {
+            self.unsaved_settings_then = Some(Box::new(message));
+            self.dialog_page = Some(DialogPage::UnsavedSettings);
+            return Task::none();
+        }
+
         match message {
+            Message::ProjectDragStart(project_path) => {
+                self.drag = Some((project_path, None));
+            }
+            Message::ProjectDragOver(project_path) => {
+                if let Some((dragged, target)) = &mut self.drag {
+                    *target = Some(project_path).filter(|target| target != dragged);
+                }
+            }
+            Message::ProjectDragEnd => {
+                if let Some((dragged, Some(target))) = self.drag.take() {
+                    self.drop_project(&dragged, &target);
+                }
+            }
+            Message::UsageWeightSelected(index) => {
+                let _ = self
+                    .config
+                    .set_usage_weight(self.config_handler.as_ref().unwrap(), USAGE_WEIGHTS[index]);
+            }
+            Message::ModifiersChanged(modifiers) => {
+                self.hints_visible = modifiers == Modifiers::ALT;
+            }
             Message::Key(modifiers, key) => {
-                for (key_bind, action) in self.key_binds.iter() {
-                    if key_bind.matches(modifiers, &key) {
-                        return self.update(action.message());
+                if let Some(recording) = self.recording_shortcut {
+                    if key == Key::Named(Named::Escape) {
+                        self.recording_shortcut = None;
+                    } else if let Some(key_bind) = shortcut::key_bind(modifiers, &key) {
+                        self.recording_shortcut = None;
+                        self.set_shortcut(recording, Some(key_bind));
+                    }
+
+                    return Task::none();
+                }
+
+                if self.hints_visible && modifiers == Modifiers::ALT {
+                    if let Key::Character(character) = &key {
+                        return self.launch_hinted(&character.to_lowercase());
+                    }
+                }
+
+                if let Some(task) = self.navigate(modifiers, &key) {
+                    return task;
+                }
+
+                match self.key_binds.find(modifiers, &key) {
+                    Some(Shortcut::Menu(action)) => return self.update(action.message()),
+                    Some(Shortcut::Project(action)) if self.dialog_page.is_none() => {
+                        if let Some(project) = self.selected_project() {
+                            let project_path = project.path().clone();
+                            return self.update(Message::ProjectActionRun(action, project_path));
+                        }
                     }
+                    _ => {}
                 }
             }
             Message::OpenContextDrawer(context_page) => {
                 self.context_page = context_page;
                 self.core.window.show_context = true;
             }
+            Message::OpenPage(page) => {
+                self.context_page = ContextPage::from(page);
+                self.core.window.show_context = true;
+
+                if page == Page::Programs {
+                    return widget::text_input::focus(self.program_input_id.clone());
+                }
+            }
             Message::CloseContextDrawer => {
                 self.core.window.show_context = false;
+                self.recording_shortcut = None;
+            }
+            Message::CloseDialog => {
+                let dialog_page = self.dialog_page.take();
+                self.unsaved_settings_then = None;
+
+                // skipping one launch of a batch still asks for the others
+                if matches!(dialog_page, Some(DialogPage::LaunchPrompt { .. })) {
+                    return self.next_prompt();
+                }
+            }
+            Message::UnsavedSettingsSave => {
+                let root_path = PathBuf::from(&self.root_path_input);
+                let templates_path = PathBuf::from(&self.templates_path_input);
+                let mut tasks = vec![];
+
+                if root_path.exists() {
+                    tasks.push(self.update(Message::RootPathSave(root_path)));
+                }
+
+                if templates_path.is_dir() {
+                    tasks.push(self.update(Message::TemplatesPathSave(templates_path)));
+                }
+
+                if self.is_valid_program() {
+                    tasks.push(self.update(Message::ProgramSave));
+                }
+
+                tasks.push(self.update(Message::TerminalSave));
+
+                // what could not be saved is dropped, so leaving the settings is not held back again
+                self.reset_settings_inputs();
+                self.dialog_page = None;
+
+                if let Some(then) = self.unsaved_settings_then.take() {
+                    tasks.push(self.update(*then));
+                }
+
+                return Task::batch(tasks);
+            }
+            Message::UnsavedSettingsDiscard => {
+                self.reset_settings_inputs();
+                self.dialog_page = None;
+
+                if let Some(then) = self.unsaved_settings_then.take() {
+                    return self.update(*then);
+                }
             }
             Message::UpdateConfig(config) => {
+                let theirs = config.programs().to_vec();
                 self.config = config;
+                self.key_binds = KeyBinds::new(self.config.shortcuts());
+                self.update_archive_suggestions();
+
+                if theirs == self.programs_on_disk {
+                    return Task::none();
+                }
+
+                // nothing of ours would be lost, so the other instance's programs are taken over,
+                // an open edit being saved onto their version of the program
+                if self.programs == self.programs_on_disk {
+                    self.programs = theirs.clone();
+                    self.programs_on_disk = theirs;
+                    return self.update(Message::ValidatePrograms);
+                }
+
+                self.dialog_page = Some(DialogPage::ProgramsDiverged { theirs });
+            }
+            Message::ProgramsKeepMine => {
+                let Some(DialogPage::ProgramsDiverged { theirs }) = self.dialog_page.take() else {
+                    return Task::none();
+                };
+
+                self.programs_on_disk = theirs;
+                self.save_programs();
+            }
+            Message::ProgramsUseTheirs => {
+                let Some(DialogPage::ProgramsDiverged { theirs }) = self.dialog_page.take() else {
+                    return Task::none();
+                };
+
+                self.programs = theirs.clone();
+                self.programs_on_disk = theirs;
+                return self.update(Message::ValidatePrograms);
+            }
+            Message::ProgramsMerge => {
+                let Some(DialogPage::ProgramsDiverged { theirs }) = self.dialog_page.take() else {
+                    return Task::none();
+                };
+
+                self.programs = program::merge(&self.programs_on_disk, &self.programs, &theirs);
+                self.programs_on_disk = theirs;
+                self.save_programs();
+                return self.update(Message::ValidatePrograms);
             }
             Message::LaunchUrl(url) => match open::that_detached(&url) {
                 Ok(()) => {}
@@ -211,168 +1497,5514 @@ impl Application for AppModel {
             },
             Message::LaunchProject {
                 program_name,
-                project_name,
+                project_path,
             } => {
-                let Some(program) = self
-                    .programs
-                    .iter()
-                    .find(|program| program.name() == program_name)
-                else {
+                let Some(program) = self.program(&program_name) else {
                     return Task::none();
                 };
 
-                let mut path = self.config.project_root_path().unwrap().clone();
-                path.push(project_name);
+                let labels = program.prompts();
+
+                if labels.is_empty() {
+                    return self.request_launch(project_path, program_name, vec![]);
+                }
 
-                let command = program.command().replace("%path%", path.to_str().unwrap());
-                let mut command = command.split_whitespace();
+                // sessions and bulk launches ask one after another instead of replacing the dialog
+                if matches!(self.dialog_page, Some(DialogPage::LaunchPrompt { .. })) {
+                    self.pending_prompts.push_back((project_path, program_name));
+                    return Task::none();
+                }
 
-                let exec = command.next().unwrap();
-                let args: Vec<&str> = command.collect();
+                let previous = self.config.program_prompt_answers(&program_name);
+                self.prompt_inputs = labels
+                    .iter()
+                    .map(|label| {
+                        previous
+                            .iter()
+                            .find(|(answered, _)| answered == label)
+                            .map(|(_, answer)| answer.clone())
+                            .unwrap_or_default()
+                    })
+                    .collect();
 
-                let _ = Command::new(exec)
-                    .args(args)
-                    .stdout(Stdio::null())
-                    .stderr(Stdio::null())
-                    .spawn();
+                self.dialog_page = Some(DialogPage::LaunchPrompt {
+                    project_path,
+                    program_name,
+                    labels,
+                });
             }
-            Message::RootPathInputChanged(path) => {
-                self.root_path_input = path;
+            Message::LaunchPromptInputChanged(index, value) => {
+                if let Some(input) = self.prompt_inputs.get_mut(index) {
+                    *input = value;
+                }
             }
-            Message::RootPathSave(path) => {
-                info!("saving root path - {:?}", path);
+            Message::LaunchPromptConfirm => {
+                let Some(DialogPage::LaunchPrompt {
+                    project_path,
+                    program_name,
+                    labels,
+                }) = self.dialog_page.take()
+                else {
+                    return Task::none();
+                };
+
+                let answers: Vec<(String, String)> = labels
+                    .into_iter()
+                    .zip(std::mem::take(&mut self.prompt_inputs))
+                    .collect();
+
+                let mut prompt_answers = self.config.prompt_answers().to_vec();
+                prompt_answers.retain(|answers| answers.program() != program_name);
+                prompt_answers.push(PromptAnswers::new(program_name.clone(), answers.clone()));
                 let _ = self
                     .config
-                    .set_project_root_path(self.config_handler.as_ref().unwrap(), Some(path));
-            }
-            Message::ProgramCommandInputChanged(cmd) => {
-                self.program_command_input = cmd;
-            }
-            Message::ProgramNameInputChanged(name) => {
-                self.program_name_input = name;
-            }
-            Message::ProgramSave => {
-                let program = Program::new(
-                    self.program_name_input.clone(),
-                    self.program_command_input.clone(),
-                );
-                info!("saving program - {:?}", program);
+                    .set_prompt_answers(self.config_handler.as_ref().unwrap(), prompt_answers);
 
-                self.programs.push(program);
-                self.program_command_input = "".to_string();
-                self.program_name_input = "".to_string();
+                let mut tasks =
+                    vec![self.request_launch(project_path, program_name.clone(), answers.clone())];
 
-                self.save_programs();
-            }
-            Message::ProgramDelete(name) => {
-                self.programs.retain(|program| program.name() != name);
-                self.save_programs();
+                // the rest of the batch launching the same program takes the same answers
+                let pending = std::mem::take(&mut self.pending_prompts);
+                for (project_path, pending_program) in pending {
+                    if pending_program == program_name {
+                        tasks.push(self.request_launch(
+                            project_path,
+                            pending_program,
+                            answers.clone(),
+                        ));
+                    } else {
+                        self.pending_prompts
+                            .push_back((project_path, pending_program));
+                    }
+                }
+
+                tasks.push(self.next_prompt());
+                return Task::batch(tasks);
             }
-            Message::UpdateProjects => {
-                let Some(path) = self.config.project_root_path() else {
+            Message::LaunchProjectActivated {
+                project_path,
+                program_name,
+                token,
+                answers,
+            } => {
+                let Some(program) = self.program(&program_name) else {
                     return Task::none();
                 };
 
-                let result = read_dir(path).unwrap();
+                let launch_override = self.config.launch_override(&project_path, &program_name);
 
-                self.projects = result
-                    .filter_map(|dir| dir.ok())
-                    .filter_map(|dir| match dir.try_into() {
-                        Ok(project) => Some(project),
-                        Err(err) => {
-                            error!("{}", err);
-                            None
-                        }
+                let mut env = self
+                    .config
+                    .project_env_profile(&project_path)
+                    .map(|profile| profile.vars().to_vec())
+                    .unwrap_or_default();
+                if let Some(token) = token {
+                    env.extend(launch::activation_env(&token));
+                }
+
+                if let Err(err) = launch::launch(
+                    program,
+                    &project_path,
+                    launch_override,
+                    self.config.launch_settings(),
+                    &command::Values::new(
+                        self.config.project_root_path().cloned(),
+                        self.git_branch(&project_path),
+                        answers,
+                    ),
+                    &env,
+                ) {
+                    error!("failed to launch {program_name} for {project_path:?}: {err}");
+                    return self.update(Message::ShowToast(fl!(
+                        "launch-failed",
+                        program = program_name,
+                        error = err.to_string()
+                    )));
+                }
+
+                self.record_usage(&project_path);
+                self.record_launch(&project_path, &program_name);
+                self.fire_automations(
+                    AutomationEvent::ProjectLaunched,
+                    &[
+                        ("PROJECT_PATH", project_path.display().to_string()),
+                        ("PROGRAM", program_name),
+                    ],
+                );
+
+                if let Some(id) = self.core.main_window_id() {
+                    match self.config.after_launch() {
+                        AfterLaunch::StayFocused => return cosmic::iced::window::gain_focus(id),
+                        AfterLaunch::YieldFocus => {}
+                        AfterLaunch::Minimize => return cosmic::iced::window::minimize(id, true),
+                    }
+                }
+            }
+            Message::OpenWith(project_path) => {
+                return Task::perform(launch::open_with(project_path), |result| {
+                    cosmic::app::Message::App(Message::OpenWithFinished(result))
+                });
+            }
+            Message::OpenFolder(project_path) => {
+                if let Err(err) = open::that_detached(&project_path) {
+                    error!("failed to open {project_path:?} in the file manager: {err}");
+                }
+            }
+            Message::CopyPath(project_path) => {
+                return cosmic::iced::clipboard::write(project_path.display().to_string());
+            }
+            Message::OpenProjectMenu(project_path, menu) => {
+                self.project_menu = Some((project_path, menu));
+            }
+            Message::OpenRenameProject(project_path) => {
+                self.rename_input = self
+                    .projects
+                    .iter()
+                    .find(|project| *project.path() == project_path)
+                    .map(|project| {
+                        self.annotation(project)
+                            .name()
+                            .unwrap_or(project.name())
+                            .to_string()
+                    })
+                    .unwrap_or_default();
+                self.dialog_page = Some(DialogPage::RenameProject { project_path });
+            }
+            Message::RenameInputChanged(name) => {
+                self.rename_input = name;
+            }
+            Message::RenameConfirm => {
+                let Some(DialogPage::RenameProject { project_path }) = self.dialog_page.take()
+                else {
+                    return Task::none();
+                };
+
+                let name = self.rename_input.trim();
+                let folder_name = project_path.file_name().map(|name| name.to_string_lossy());
+
+                // the folder name itself needs no renaming
+                let name = (!name.is_empty() && folder_name.as_deref() != Some(name))
+                    .then(|| name.to_string());
+
+                let mut annotation = self.personal_annotation(&project_path);
+                annotation.set_name(name);
+                self.save_annotation(project_path.clone(), annotation);
+                self.relabel(&project_path);
+            }
+            Message::CloseProjectMenu => {
+                self.project_menu = None;
+            }
+            Message::ProjectActionRun(action, project_path) => {
+                self.project_menu = None;
+                return self.update(action.message(&project_path));
+            }
+            Message::OpenWithFinished(result) => {
+                if let Err(err) = result {
+                    error!("failed to open with the chosen application: {err}");
+                }
+            }
+            Message::RootPathInputChanged(path) => {
+                if let Some(label) = self.config.root_label(Path::new(&path)) {
+                    self.root_label_input = label.to_string();
+                }
+
+                self.root_path_input = path;
+            }
+            Message::RootLabelInputChanged(label) => {
+                self.root_label_input = label;
+            }
+            Message::RootPathPick => {
+                let current = self.config.project_root_path().cloned();
+
+                return Task::perform(
+                    async move {
+                        let mut dialog =
+                            rfd::AsyncFileDialog::new().set_title(fl!("settings-root-pick"));
+
+                        if let Some(current) = current {
+                            dialog = dialog.set_directory(current);
+                        }
+
+                        dialog
+                            .pick_folder()
+                            .await
+                            .map(|folder| folder.path().to_path_buf())
+                    },
+                    |folder| cosmic::app::Message::App(Message::RootPathPicked(folder)),
+                );
+            }
+            Message::RootPathPicked(folder) => {
+                let Some(folder) = folder else {
+                    return Task::none();
+                };
+
+                self.root_path_input = path_input(Some(&folder));
+
+                if let Some(label) = self.config.root_label(&folder) {
+                    self.root_label_input = label.to_string();
+                }
+
+                return self.update(Message::RootPathSave(folder));
+            }
+            Message::RootPathSave(path) => {
+                info!("saving root path - {:?}", path);
+
+                // projects of another root are neither removed from nor added to this one
+                if self.config.project_root_path() != Some(&path) {
+                    self.projects.clear();
+                    self.scan_previous.clear();
+                    self.scan_diff = None;
+                    self.watched_dirs.clear();
+                }
+
+                let label = self.root_label_input.trim();
+                let mut root_labels = self.config.root_labels().to_vec();
+                root_labels.retain(|root_label| root_label.root() != path);
+
+                if !label.is_empty() {
+                    root_labels.push(RootLabel::new(path.clone(), label.to_string()));
+                }
+
+                let _ = self
+                    .config
+                    .set_root_labels(self.config_handler.as_ref().unwrap(), root_labels);
+                let _ = self
+                    .config
+                    .set_project_root_path(self.config_handler.as_ref().unwrap(), Some(path));
+            }
+            Message::TemplatesPathInputChanged(path) => {
+                self.templates_path_input = path;
+            }
+            Message::TemplatesPathSave(path) => {
+                info!("saving templates path - {:?}", path);
+                let _ = self
+                    .config
+                    .set_templates_path(self.config_handler.as_ref().unwrap(), Some(path));
+            }
+            Message::ProgramCommandInputChanged(cmd) => {
+                self.program_command_input = cmd;
+                self.program_desktop_entry = None;
+                self.program_flatpak = None;
+            }
+            Message::ProgramNameInputChanged(name) => {
+                self.program_name_input = name;
+            }
+            Message::DesktopEntriesLoaded(entries) => {
+                self.desktop_entry_labels = entries
+                    .iter()
+                    .map(|entry| entry.name().to_string())
+                    .collect();
+                self.desktop_entries = entries.clone();
+
+                return Task::perform(
+                    tokio::task::spawn_blocking(move || {
+                        let mime_types: Vec<&str> = ProjectKind::ALL
+                            .iter()
+                            .map(|kind| kind.mime_type())
+                            .chain([desktop_entry::DIRECTORY_MIME_TYPE])
+                            .collect();
+
+                        desktop_entry::default_handlers(&mime_types, &entries)
+                    }),
+                    |handlers| {
+                        cosmic::app::Message::App(Message::DefaultHandlersLoaded(
+                            handlers.unwrap_or_default(),
+                        ))
+                    },
+                );
+            }
+            Message::DefaultHandlersLoaded(handlers) => {
+                self.default_handlers = handlers
+                    .into_iter()
+                    .map(|(mime_type, entry)| {
+                        (
+                            mime_type,
+                            Program::from_desktop_entry(entry.name().to_string(), &entry),
+                        )
                     })
                     .collect();
             }
-            Message::SearchTextInputChanged(text) => {
-                self.search_text = text;
+            Message::ProgramDesktopEntrySelected(index) => {
+                let Some(entry) = self.desktop_entries.get(index) else {
+                    return Task::none();
+                };
+
+                if self.program_name_input.is_empty() {
+                    self.program_name_input = entry.name().to_string();
+                }
+
+                if self.program_icon_input.is_empty() {
+                    self.program_icon_input = entry.icon().unwrap_or_default().to_string();
+                }
+
+                self.program_command_input = entry.path_command();
+                self.program_desktop_entry = Some(index);
+                self.program_flatpak = None;
             }
-            Message::FocusSearchInput => {
-                return widget::text_input::focus(self.search_input_id.clone())
+            Message::OpenInstalledApps => {
+                self.installed_apps_query = "".to_string();
+                self.dialog_page = Some(DialogPage::InstalledApps);
+            }
+            Message::InstalledAppsQueryChanged(query) => {
+                self.installed_apps_query = query;
+            }
+            Message::InstalledAppAdd(index) => {
+                let Some(entry) = self.desktop_entries.get(index) else {
+                    return Task::none();
+                };
+
+                if self
+                    .programs
+                    .iter()
+                    .any(|program| program.name() == entry.name())
+                {
+                    return Task::none();
+                }
+
+                let program = Program::from_desktop_entry(entry.name().to_string(), entry);
+                info!("adding installed app - {:?}", program);
+
+                self.programs.push(program);
+                self.save_programs();
+                return self.update(Message::ValidatePrograms);
+            }
+            Message::FlatpakAppsLoaded(apps) => {
+                self.flatpak_app_labels = apps.iter().map(|app| app.name().to_string()).collect();
+                self.flatpak_apps = apps;
+            }
+            Message::ProgramFlatpakSelected(index) => {
+                let Some(app) = self.flatpak_apps.get(index) else {
+                    return Task::none();
+                };
+
+                if self.program_name_input.is_empty() {
+                    self.program_name_input = app.name().to_string();
+                }
+
+                if self.program_icon_input.is_empty() {
+                    self.program_icon_input = app.id().to_string();
+                }
+
+                self.program_command_input = flatpak::command(app.id());
+                self.program_flatpak = Some(index);
+                self.program_desktop_entry = None;
+            }
+            Message::ProgramRunInTerminalToggled(run_in_terminal) => {
+                self.program_run_in_terminal = run_in_terminal;
+            }
+            Message::ProgramStartInProjectSelected(index) => {
+                self.program_start_in_project = START_IN_PROJECT_OPTIONS[index];
+            }
+            Message::ProgramIconInputChanged(icon) => {
+                self.program_icon_input = icon;
+            }
+            Message::ProgramEnvInputChanged(input) => {
+                self.program_env_input = input;
+            }
+            Message::ProgramEnvAdd => {
+                let Some((key, value)) = env_profile::parse_var(&self.program_env_input) else {
+                    return Task::none();
+                };
+
+                match self
+                    .program_env
+                    .iter_mut()
+                    .find(|(existing, _)| *existing == key)
+                {
+                    Some((_, existing)) => *existing = value,
+                    None => self.program_env.push((key, value)),
+                }
+                self.program_env_input.clear();
+            }
+            Message::ProgramEnvRemove(key) => {
+                self.program_env.retain(|(existing, _)| *existing != key);
+            }
+            Message::TerminalInputChanged(terminal) => {
+                self.terminal_input = terminal;
+            }
+            Message::TerminalSave => {
+                let terminal = self.terminal_input.trim();
+                info!("saving terminal - {:?}", terminal);
+
+                let _ = self.config.set_terminal(
+                    self.config_handler.as_ref().unwrap(),
+                    (!terminal.is_empty()).then(|| terminal.to_string()),
+                );
             }
+            Message::ProgramSave => {
+                let desktop_entry = self
+                    .program_desktop_entry
+                    .and_then(|index| self.desktop_entries.get(index));
+                let flatpak_app = self
+                    .program_flatpak
+                    .and_then(|index| self.flatpak_apps.get(index));
+
+                let mut program = match (desktop_entry, flatpak_app) {
+                    (Some(entry), _) => {
+                        Program::from_desktop_entry(self.program_name_input.clone(), entry)
+                    }
+                    (None, Some(app)) => {
+                        Program::from_flatpak(self.program_name_input.clone(), app)
+                    }
+                    (None, None) => Program::new(
+                        self.program_name_input.clone(),
+                        self.program_command_input.clone(),
+                        self.program_run_in_terminal,
+                    ),
+                };
+
+                let icon = self.program_icon_input.trim();
+                program.set_icon((!icon.is_empty()).then(|| icon.to_string()));
+                program.set_env(std::mem::take(&mut self.program_env));
+                program.set_start_in_project(self.program_start_in_project.take());
+                // the variables may hold credentials, so only the name is logged
+                info!("saving program - {:?}", program.name());
+
+                let edited = self.program_edit.take().and_then(|name| {
+                    self.programs
+                        .iter()
+                        .position(|program| program.name() == name)
+                });
+
+                match edited {
+                    Some(index) => {
+                        let mut program = program;
+                        program.set_mnemonic(self.programs[index].mnemonic());
+
+                        let previous = std::mem::replace(&mut self.programs[index], program);
+                        let name = self.programs[index].name().to_string();
+
+                        if previous.name() != name {
+                            self.rename_program_references(previous.name(), &name);
+                        }
+                    }
+                    None => self.programs.push(program),
+                }
+
+                self.program_command_input = "".to_string();
+                self.program_name_input = "".to_string();
+                self.program_desktop_entry = None;
+                self.program_flatpak = None;
+                self.program_run_in_terminal = false;
+                self.program_icon_input = "".to_string();
+                self.program_env_input = "".to_string();
+
+                self.save_programs();
+                return self.update(Message::ValidatePrograms);
+            }
+            Message::ProgramEdit(name) => {
+                let Some(program) = self
+                    .programs
+                    .iter()
+                    .find(|program| program.name() == name)
+                    .cloned()
+                else {
+                    return Task::none();
+                };
+
+                self.program_name_input = program.name().to_string();
+                self.program_command_input = program.command().to_string();
+                self.program_run_in_terminal = program.run_in_terminal();
+                self.program_start_in_project = program.start_in_project();
+                self.program_icon_input = program.icon().unwrap_or_default().to_string();
+                self.program_env = program.env().to_vec();
+                self.program_env_input.clear();
+                self.program_desktop_entry = program.desktop_entry().and_then(|id| {
+                    self.desktop_entries
+                        .iter()
+                        .position(|entry| entry.id() == id)
+                });
+                self.program_flatpak = program
+                    .flatpak()
+                    .and_then(|id| self.flatpak_apps.iter().position(|app| app.id() == id));
+                self.program_edit = Some(name);
+
+                return widget::text_input::focus(self.program_input_id.clone());
+            }
+            Message::ProgramMoveUp(index) => {
+                if index > 0 && index < self.programs.len() {
+                    self.programs.swap(index - 1, index);
+                    self.save_programs();
+                }
+            }
+            Message::ProgramMoveDown(index) => {
+                if index + 1 < self.programs.len() {
+                    self.programs.swap(index, index + 1);
+                    self.save_programs();
+                }
+            }
+            Message::ProgramsExport => match program::export(&self.programs) {
+                Ok(snippet) => {
+                    return Task::batch([
+                        cosmic::iced::clipboard::write(snippet),
+                        self.update(Message::ShowToast(fl!(
+                            "programs-exported",
+                            count = self.programs.len()
+                        ))),
+                    ]);
+                }
+                Err(err) => error!("failed to export the programs: {err}"),
+            },
+            Message::ProgramsImport => {
+                return cosmic::iced::clipboard::read()
+                    .map(|snippet| cosmic::app::Message::App(Message::ProgramsPasted(snippet)));
+            }
+            Message::ProgramsPasted(snippet) => {
+                let programs = match program::import(&snippet.unwrap_or_default()) {
+                    Ok(programs) => programs,
+                    Err(err) => {
+                        error!("failed to import programs: {err}");
+                        return self.update(Message::ShowToast(fl!("programs-import-failed")));
+                    }
+                };
+
+                // the same checks as the program editor, keeping the first of repeated names
+                let mut names = HashSet::new();
+                let (programs, invalid): (Vec<Program>, Vec<Program>) =
+                    programs.into_iter().partition(|imported| {
+                        imported.name().trim().is_empty().not()
+                            && (imported.desktop_entry().is_some()
+                                || Program::is_valid_command(imported.command()))
+                            && names.insert(imported.name().to_string())
+                    });
+
+                if !invalid.is_empty() {
+                    info!(
+                        "skipping invalid or repeated programs - {:?}",
+                        invalid.iter().map(Program::name).collect::<Vec<_>>()
+                    );
+                }
+
+                // programs keep their local version where the names collide
+                let (new, existing): (Vec<Program>, Vec<Program>) =
+                    programs.into_iter().partition(|imported| {
+                        !self
+                            .programs
+                            .iter()
+                            .any(|program| program.name() == imported.name())
+                    });
+
+                info!(
+                    "importing programs - {:?}",
+                    new.iter().map(Program::name).collect::<Vec<_>>()
+                );
+
+                let toast = fl!(
+                    "programs-imported",
+                    count = new.len(),
+                    skipped = existing.len(),
+                    invalid = invalid.len()
+                );
+
+                if !new.is_empty() {
+                    self.programs.extend(new);
+                    self.save_programs();
+                }
+
+                return Task::batch([
+                    self.update(Message::ShowToast(toast)),
+                    self.update(Message::ValidatePrograms),
+                ]);
+            }
+            Message::ProgramEditCancel => {
+                self.program_edit = None;
+                self.program_command_input.clear();
+                self.program_name_input.clear();
+                self.program_desktop_entry = None;
+                self.program_flatpak = None;
+                self.program_run_in_terminal = false;
+                self.program_start_in_project = None;
+                self.program_icon_input.clear();
+                self.program_env.clear();
+                self.program_env_input.clear();
+            }
+            Message::ProgramMnemonicSelected(name, index) => {
+                let mnemonic = index
+                    .checked_sub(1)
+                    .and_then(|index| MNEMONIC_KEYS.chars().nth(index));
+
+                for program in &mut self.programs {
+                    if program.name() == name {
+                        program.set_mnemonic(mnemonic);
+                    } else if mnemonic.is_some() && program.mnemonic() == mnemonic {
+                        // a key launches a single program
+                        program.set_mnemonic(None);
+                    }
+                }
+
+                self.save_programs();
+            }
+            Message::ValidatePrograms => {
+                let programs = self.programs.clone();
+
+                return Task::perform(
+                    tokio::task::spawn_blocking(move || {
+                        programs
+                            .iter()
+                            .filter(|program| !program.is_installed())
+                            .map(|program| program.name().to_string())
+                            .collect()
+                    }),
+                    |broken| {
+                        cosmic::app::Message::App(Message::ProgramsValidated(
+                            broken.unwrap_or_default(),
+                        ))
+                    },
+                );
+            }
+            Message::ProgramsValidated(broken) => {
+                for name in broken
+                    .iter()
+                    .filter(|name| !self.broken_programs.contains(name))
+                {
+                    warn!("executable of program {name:?} is missing");
+                }
+
+                self.broken_programs = broken;
+            }
+            Message::ProgramDelete(name) => {
+                let affected_projects = self.projects_depending_on(&name);
+
+                if affected_projects.is_empty() {
+                    return self.update(Message::ProgramDeleteConfirmed(name));
+                }
+
+                self.dialog_page = Some(DialogPage::DeleteProgram {
+                    name,
+                    affected_projects,
+                });
+            }
+            Message::ProgramDeleteConfirmed(name) => {
+                self.dialog_page = None;
+                self.programs.retain(|program| program.name() != name);
+                self.save_programs();
+                self.remove_program_references(&name);
+            }
+            Message::DefaultProgramSelected(name) => {
+                let _ = self
+                    .config
+                    .set_default_program(self.config_handler.as_ref().unwrap(), Some(name));
+            }
+            Message::ProjectDefaultProgramSelected(project_path, name) => {
+                let mut default_programs = self.config.default_programs().to_vec();
+                default_programs.retain(|default| default.project() != project_path);
+
+                if let Some(name) = name {
+                    default_programs.push(DefaultProgram::new(project_path, name));
+                }
+
+                let _ = self
+                    .config
+                    .set_default_programs(self.config_handler.as_ref().unwrap(), default_programs);
+            }
+            Message::EnvProfileNameInputChanged(name) => {
+                self.env_profile_name_input = name;
+            }
+            Message::EnvProfileAdd => {
+                if !self.is_valid_env_profile_name() {
+                    return Task::none();
+                }
+
+                let mut profiles = self.config.env_profiles().to_vec();
+                profiles.push(EnvProfile::new(
+                    self.env_profile_name_input.trim().to_string(),
+                ));
+                self.env_profile_name_input = "".to_string();
+
+                let _ = self
+                    .config
+                    .set_env_profiles(self.config_handler.as_ref().unwrap(), profiles);
+            }
+            Message::EnvProfileDelete(name) => {
+                let mut profiles = self.config.env_profiles().to_vec();
+                profiles.retain(|profile| profile.name() != name);
+
+                let _ = self
+                    .config
+                    .set_env_profiles(self.config_handler.as_ref().unwrap(), profiles);
+
+                let mut project_profiles = self.config.project_env_profiles().to_vec();
+                project_profiles.retain(|chosen| chosen.profile() != name);
+
+                let _ = self.config.set_project_env_profiles(
+                    self.config_handler.as_ref().unwrap(),
+                    project_profiles,
+                );
+                self.env_var_inputs.remove(&name);
+            }
+            Message::EnvVarInputChanged(name, input) => {
+                self.env_var_inputs.insert(name, input);
+            }
+            Message::EnvVarAdd(name) => {
+                let Some((key, value)) = self
+                    .env_var_inputs
+                    .get(&name)
+                    .and_then(|input| env_profile::parse_var(input))
+                else {
+                    return Task::none();
+                };
+
+                let mut profiles = self.config.env_profiles().to_vec();
+                if let Some(profile) = profiles.iter_mut().find(|profile| profile.name() == name) {
+                    profile.set_var(key, value);
+                }
+                self.env_var_inputs.remove(&name);
+
+                let _ = self
+                    .config
+                    .set_env_profiles(self.config_handler.as_ref().unwrap(), profiles);
+            }
+            Message::EnvVarRemove(name, key) => {
+                let mut profiles = self.config.env_profiles().to_vec();
+                if let Some(profile) = profiles.iter_mut().find(|profile| profile.name() == name) {
+                    profile.remove_var(&key);
+                }
+
+                let _ = self
+                    .config
+                    .set_env_profiles(self.config_handler.as_ref().unwrap(), profiles);
+            }
+            Message::ProjectEnvProfileSelected(project_path, name) => {
+                let mut project_profiles = self.config.project_env_profiles().to_vec();
+                project_profiles.retain(|chosen| chosen.project() != project_path);
+
+                if let Some(name) = name {
+                    project_profiles.push(ProjectEnvProfile::new(project_path, name));
+                }
+
+                let _ = self.config.set_project_env_profiles(
+                    self.config_handler.as_ref().unwrap(),
+                    project_profiles,
+                );
+            }
+            Message::RefreshProjects => {
+                self.git_status.clear();
+                return self.update(Message::UpdateProjects);
+            }
+            Message::UpdateProjects => {
+                let Some(path) = self.config.project_root_path().cloned() else {
+                    return Task::none();
+                };
+
+                let root = path.clone();
+
+                // looking up the media reads block devices and may hang on network mounts
+                return Task::perform(
+                    tokio::task::spawn_blocking(move || mount::removable_root(&path)),
+                    move |result| {
+                        let root_mount = result.unwrap_or_else(|err| {
+                            error!("failed to look up the media of the root: {err}");
+                            None
+                        });
+
+                        cosmic::app::Message::App(Message::ScanRoot(root, root_mount))
+                    },
+                );
+            }
+            Message::ScanRoot(path, root_mount) => {
+                if self.config.project_root_path() != Some(&path) {
+                    return Task::none();
+                }
+
+                self.root_mount = root_mount;
+
+                if self
+                    .root_mount
+                    .as_ref()
+                    .is_some_and(|root_mount| !root_mount.is_mounted())
+                {
+                    self.scan_id += 1;
+                    self.scanned = None;
+                    self.projects.clear();
+                    return Task::none();
+                }
+
+                self.scan_id += 1;
+                self.scanned = Some(DedupedProjects::default());
+                self.scan_previous = self
+                    .projects
+                    .iter()
+                    .map(|project| (project.path().clone(), *project.modify()))
+                    .collect();
+
+                let scan = self.scan_id;
+                let depth = self.config.discovery_depth();
+                let ignore = rule::glob_set(&self.config.ignore_patterns());
+
+                return Task::run(
+                    cosmic::iced::stream::channel(16, move |mut output| async move {
+                        let mut batches = output.clone();
+
+                        let result = tokio::task::spawn_blocking(move || {
+                            let runtime = tokio::runtime::Handle::current();
+
+                            let read_only = mount::is_read_only(&path);
+                            let shared_annotations =
+                                annotation::load_shared(&path).unwrap_or_else(|err| {
+                                    error!("{}", err);
+                                    HashMap::new()
+                                });
+                            let _ = runtime.block_on(batches.send(Message::RootLoaded {
+                                scan,
+                                read_only,
+                                shared_annotations,
+                            }));
+
+                            project::scan(&path, depth, &ignore, SCAN_BATCH_SIZE, |projects| {
+                                let _ = runtime.block_on(
+                                    batches.send(Message::ProjectsScanned { scan, projects }),
+                                );
+                            })
+                            .map(|()| project::project_dirs(&path, depth, &ignore))
+                            .map_err(|err| format!("failed to read {path:?}: {err}"))
+                        })
+                        .await
+                        .unwrap_or_else(|err| Err(err.to_string()));
+
+                        let _ = output
+                            .send(Message::ProjectScanFinished { scan, result })
+                            .await;
+                    }),
+                    cosmic::app::Message::App,
+                );
+            }
+            Message::RootLoaded {
+                scan,
+                read_only,
+                shared_annotations,
+            } => {
+                if scan != self.scan_id {
+                    return Task::none();
+                }
+
+                self.root_read_only = read_only;
+                self.shared_annotations = shared_annotations;
+                self.update_archive_suggestions();
+            }
+            Message::ProjectsScanned { scan, mut projects } => {
+                if self.scanned.is_none() || scan != self.scan_id {
+                    return Task::none();
+                }
+
+                let rules = RuleSet::new(self.config.rules());
+
+                for project in &mut projects {
+                    project.apply_rules(&rules);
+
+                    if let Some(name) = self.annotation(project).name() {
+                        project.rename(name.to_string());
+                    }
+                }
+
+                let Some(scanned) = self.scanned.as_mut() else {
+                    return Task::none();
+                };
+
+                scanned.extend(projects);
+
+                // Projects of the previous scan stay listed until the scan finishes, so the list only grows.
+                let pending = self
+                    .projects
+                    .iter()
+                    .filter(|project| !scanned.contains_path(project.path()));
+
+                let mut projects: Vec<Project> =
+                    scanned.projects().iter().chain(pending).cloned().collect();
+                Project::disambiguate(&mut projects);
+
+                if !self.scan_previous.is_empty() {
+                    self.highlight_changed_rows(&projects);
+                }
+
+                self.projects = projects;
+                self.update_archive_suggestions();
+            }
+            Message::ProjectScanFinished { scan, result } => {
+                if scan != self.scan_id {
+                    return Task::none();
+                }
+
+                let scanned = self.scanned.take().unwrap_or_default();
+
+                self.watched_dirs = match result {
+                    Ok(dirs) => dirs,
+                    Err(err) => {
+                        error!("{err}");
+                        self.projects.clear();
+                        return self.update(Message::ShowToast(fl!("scan-failed", error = err)));
+                    }
+                };
+
+                let mut projects = scanned.into_projects();
+                Project::disambiguate(&mut projects);
+
+                // rescans finding nothing new, e.g. after changes inside a project, are no news
+                let changed = self.last_scan.is_none()
+                    || self.scan_previous.len() != projects.len()
+                    || projects.iter().any(|project| {
+                        self.scan_previous.get(project.path()) != Some(project.modify())
+                    });
+
+                let mut move_references = Task::none();
+
+                if !self.scan_previous.is_empty() {
+                    let previous: Vec<Project> = self
+                        .projects
+                        .iter()
+                        .filter(|project| self.scan_previous.contains_key(project.path()))
+                        .cloned()
+                        .collect();
+                    let diff = ScanDiff::between(&previous, &projects);
+
+                    move_references = self.move_project_references(diff.renamed());
+
+                    let renamed: HashSet<&PathBuf> =
+                        diff.renamed().iter().map(|(_, new)| new).collect();
+                    // nothing was carried over to possibly renamed projects, so they count as new
+                    let added: HashSet<&PathBuf> = diff
+                        .added()
+                        .iter()
+                        .chain(diff.possibly_renamed().iter().map(|(_, new)| new))
+                        .collect();
+
+                    // the personal name moved along after the batches were named
+                    for project in &mut projects {
+                        if !renamed.contains(project.path()) {
+                            continue;
+                        }
+
+                        if let Some(name) = self.annotation(project).name() {
+                            project.rename(name.to_string());
+                        }
+                    }
+
+                    // renamed projects are no discoveries
+                    let discovered: Vec<&Project> = projects
+                        .iter()
+                        .filter(|project| added.contains(project.path()))
+                        .collect();
+
+                    self.record_activity(
+                        discovered
+                            .iter()
+                            .map(|project| ActivityKind::Discovered(project.path().clone())),
+                    );
+
+                    for project in discovered {
+                        self.fire_automations(
+                            AutomationEvent::ProjectDiscovered,
+                            &[
+                                ("PROJECT_NAME", project.name().clone()),
+                                ("PROJECT_PATH", project.path().display().to_string()),
+                            ],
+                        );
+                    }
+
+                    if !diff.is_empty() {
+                        self.scan_diff = Some(diff);
+                    }
+                }
+
+                self.projects = projects;
+                self.last_scan = Some(SystemTime::now());
+                self.update_archive_suggestions();
+
+                if changed {
+                    let root = self
+                        .config
+                        .project_root_path()
+                        .map(|path| path.display().to_string())
+                        .unwrap_or_default();
+                    self.fire_automations(
+                        AutomationEvent::ScanFinished,
+                        &[
+                            ("ROOT_PATH", root),
+                            ("PROJECT_COUNT", self.projects.len().to_string()),
+                        ],
+                    );
+                }
+
+                return Task::batch(vec![
+                    move_references,
+                    self.load_project_icons(),
+                    self.load_project_forges(),
+                    self.load_ownership(),
+                    self.load_stats(),
+                    self.load_git_status(),
+                    self.load_releases(),
+                    self.run_audits(),
+                    self.run_unchecked(CheckKind::Lint),
+                ]);
+            }
+            Message::OpenProjectDetails(path) => {
+                self.details_path = Some(path.clone());
+                self.details_changes = None;
+                self.details_badges.clear();
+                self.override_program = None;
+                self.context_page = ContextPage::ProjectDetails;
+                self.core.window.show_context = true;
+
+                let load_badges = Task::perform(
+                    tokio::task::spawn_blocking({
+                        let path = path.clone();
+                        move || (readme::badges(&path), path)
+                    }),
+                    |result| {
+                        let (badges, project_path) = result.unwrap_or_default();
+                        cosmic::app::Message::App(Message::ReadmeBadgesLoaded {
+                            project_path,
+                            badges,
+                        })
+                    },
+                );
+
+                let load_changes = Task::perform(
+                    async move {
+                        let changes = tokio::task::spawn_blocking({
+                            let path = path.clone();
+                            move || git::changes(&path)
+                        })
+                        .await
+                        .ok()
+                        .flatten();
+
+                        (path, changes)
+                    },
+                    |(project_path, changes)| {
+                        cosmic::app::Message::App(Message::ProjectChangesLoaded {
+                            project_path,
+                            changes,
+                        })
+                    },
+                );
+
+                return Task::batch([load_changes, load_badges]);
+            }
+            Message::ReadmeBadgesLoaded {
+                project_path,
+                badges,
+            } => {
+                if self.details_path.as_ref() == Some(&project_path) {
+                    self.details_badges = badges;
+                }
+            }
+            Message::ProjectChangesLoaded {
+                project_path,
+                changes,
+            } => {
+                if self.details_path.as_ref() == Some(&project_path) {
+                    self.details_changes = changes;
+                }
+            }
+            Message::LaunchOverrideEdit(program_name) => {
+                let launch_override = self
+                    .details_path
+                    .as_ref()
+                    .and_then(|path| self.config.launch_override(path, &program_name));
+
+                self.override_working_directory_input = launch_override
+                    .and_then(|launch_override| launch_override.working_directory())
+                    .map(|path| path.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                self.override_path_input = launch_override
+                    .and_then(|launch_override| launch_override.path())
+                    .map(|path| path.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                self.override_program = Some(program_name);
+            }
+            Message::LaunchOverrideWorkingDirectoryInputChanged(path) => {
+                self.override_working_directory_input = path;
+            }
+            Message::LaunchOverridePathInputChanged(path) => {
+                self.override_path_input = path;
+            }
+            Message::LaunchOverrideSave => {
+                let (Some(project), Some(program)) =
+                    (self.details_path.clone(), self.override_program.take())
+                else {
+                    return Task::none();
+                };
+
+                let optional_path = |input: &str| {
+                    let input = input.trim();
+                    input.is_empty().not().then(|| PathBuf::from(input))
+                };
+
+                let launch_override = LaunchOverride::new(
+                    project,
+                    program,
+                    optional_path(&self.override_working_directory_input),
+                    optional_path(&self.override_path_input),
+                );
+                info!("saving launch override - {:?}", launch_override);
+
+                self.save_launch_override(launch_override);
+            }
+            Message::LaunchOverrideDelete(program) => {
+                let Some(project) = self.details_path.clone() else {
+                    return Task::none();
+                };
+
+                self.save_launch_override(LaunchOverride::new(project, program, None, None));
+            }
+            Message::SearchTextInputChanged(text) => {
+                self.search_text = text;
+                self.visible_projects = PROJECT_PAGE_SIZE;
+            }
+            Message::ShowMoreProjects => {
+                self.visible_projects += PROJECT_PAGE_SIZE;
+            }
+            Message::ViewModeSelected(view_mode) => {
+                let _ = self
+                    .config
+                    .set_view_mode(self.config_handler.as_ref().unwrap(), view_mode);
+            }
+            Message::SortOrderSelected(sort_order) => {
+                let _ = self
+                    .config
+                    .set_sort_order(self.config_handler.as_ref().unwrap(), sort_order);
+            }
+            Message::TrackLaunchedToggled(track) => {
+                let launch_mode = if track {
+                    LaunchMode::Tracked
+                } else {
+                    LaunchMode::Detached
+                };
+
+                let _ = self
+                    .config
+                    .set_launch_mode(self.config_handler.as_ref().unwrap(), launch_mode);
+            }
+            Message::StartInProjectToggled(start_in_project) => {
+                let _ = self.config.set_start_outside_project(
+                    self.config_handler.as_ref().unwrap(),
+                    !start_in_project,
+                );
+            }
+            Message::HideHeaderControlsToggled(hide) => {
+                let _ = self
+                    .config
+                    .set_hide_header_controls(self.config_handler.as_ref().unwrap(), hide);
+            }
+            Message::ShortcutRecord(shortcut) => {
+                self.recording_shortcut = Some(shortcut);
+            }
+            Message::ShortcutReset(shortcut) => {
+                self.set_shortcut(shortcut, shortcut.default_key_bind());
+            }
+            Message::ShortcutClear(shortcut) => {
+                self.set_shortcut(shortcut, None);
+            }
+            Message::AfterLaunchSelected(index) => {
+                let _ = self.config.set_after_launch(
+                    self.config_handler.as_ref().unwrap(),
+                    AfterLaunch::ALL[index],
+                );
+            }
+            Message::ClickActionSelected(view_mode, index) => {
+                let handler = self.config_handler.as_ref().unwrap();
+                let click_action = ClickAction::ALL[index];
+
+                let _ = match view_mode {
+                    ViewMode::List => self.config.set_list_click_action(handler, click_action),
+                    ViewMode::Grid => self.config.set_grid_click_action(handler, click_action),
+                };
+            }
+            Message::ProjectClicked(project_path) => {
+                match self.config.click_action(self.config.view_mode()) {
+                    ClickAction::OpenDetails => {
+                        return self.update(Message::OpenProjectDetails(project_path));
+                    }
+                    ClickAction::LaunchDefault => return self.launch_default(project_path),
+                    ClickAction::Select => {
+                        self.selected_path = Some(project_path);
+                    }
+                }
+            }
+            Message::PinToggled(project_path) => {
+                let mut annotation = self.personal_annotation(&project_path);
+                annotation.set_pinned(!annotation.pinned());
+                self.save_annotation(project_path, annotation);
+            }
+            Message::ProjectHide(project_path) => {
+                self.hide_projects(vec![project_path]);
+                self.core.window.show_context = false;
+            }
+            Message::ProjectUnhide(project_path) => {
+                let hidden_projects = self
+                    .config
+                    .hidden_projects()
+                    .iter()
+                    .filter(|hidden| **hidden != project_path)
+                    .cloned()
+                    .collect();
+
+                let _ = self
+                    .config
+                    .set_hidden_projects(self.config_handler.as_ref().unwrap(), hidden_projects);
+            }
+            Message::ProjectTrash(project_path) => {
+                if self.is_frozen(&project_path) {
+                    return Task::none();
+                }
+
+                match trash::trash(&project_path) {
+                    Ok(trashed) => {
+                        let mut trashed_projects = self.config.trashed_projects().to_vec();
+                        trashed_projects.push(trashed);
+
+                        let _ = self.config.set_trashed_projects(
+                            self.config_handler.as_ref().unwrap(),
+                            trashed_projects,
+                        );
+                        self.trash_error = None;
+                        self.core.window.show_context = false;
+
+                        return self.update(Message::UpdateProjects);
+                    }
+                    Err(err) => {
+                        error!("failed to trash {project_path:?}: {err}");
+                        self.trash_error = Some(err.to_string());
+                        self.context_page = ContextPage::Trash;
+                    }
+                }
+            }
+            Message::ProjectRestore(original) => {
+                let Some(trashed) = self
+                    .config
+                    .trashed_projects()
+                    .iter()
+                    .find(|trashed| trashed.original() == original)
+                else {
+                    return Task::none();
+                };
+
+                if let Err(err) = trash::restore(trashed) {
+                    error!("failed to restore {original:?}: {err}");
+                    self.trash_error = Some(err.to_string());
+                    return Task::none();
+                }
+
+                self.trash_error = None;
+
+                return self.update(Message::TrashForget(original));
+            }
+            Message::TrashForget(original) => {
+                let trashed_projects = self
+                    .config
+                    .trashed_projects()
+                    .iter()
+                    .filter(|trashed| trashed.original() != original)
+                    .cloned()
+                    .collect();
+
+                let _ = self
+                    .config
+                    .set_trashed_projects(self.config_handler.as_ref().unwrap(), trashed_projects);
+
+                return self.update(Message::UpdateProjects);
+            }
+            Message::OpenProjectTags(project_path) => {
+                self.tag_input.clear();
+                self.dialog_page = Some(DialogPage::ProjectTags { project_path });
+            }
+            Message::TagInputChanged(tag) => {
+                self.tag_input = tag;
+            }
+            Message::TagFilterToggled(tag) => {
+                self.tag_filter = if self.tag_filter.as_ref() == Some(&tag) {
+                    None
+                } else {
+                    Some(tag)
+                };
+            }
+            Message::TagAdd(project_path) => {
+                let tag = self.tag_input.trim().to_string();
+
+                if tag.is_empty() {
+                    return Task::none();
+                }
+
+                let mut annotation = self.personal_annotation(&project_path);
+                annotation.add_tag(tag);
+                self.tag_input.clear();
+                self.save_annotation(project_path, annotation);
+            }
+            Message::TagRemove(project_path, tag) => {
+                let mut annotation = self.personal_annotation(&project_path);
+                annotation.remove_tag(&tag);
+                self.save_annotation(project_path, annotation);
+            }
+            Message::AnimationTick => {
+                self.row_highlights
+                    .retain(|_, start| start.elapsed() < ROW_HIGHLIGHT_DURATION);
+            }
+            Message::ShowProjectIconsToggled(show_project_icons) => {
+                let _ = self.config.set_show_project_icons(
+                    self.config_handler.as_ref().unwrap(),
+                    show_project_icons,
+                );
+
+                return self.load_project_icons();
+            }
+            Message::ShowOwnershipToggled(show_ownership) => {
+                let _ = self
+                    .config
+                    .set_show_ownership(self.config_handler.as_ref().unwrap(), show_ownership);
+
+                return self.load_ownership();
+            }
+            Message::ShowFooterToggled(show_footer) => {
+                let _ = self
+                    .config
+                    .set_show_footer(self.config_handler.as_ref().unwrap(), show_footer);
+
+                return self.load_stats();
+            }
+            Message::StatsLoaded {
+                project_path,
+                modify,
+                stats,
+            } => {
+                self.project_stats.insert(project_path, (modify, stats));
+            }
+            Message::ProjectClean(project_path) => {
+                if self.is_frozen(&project_path) {
+                    return Task::none();
+                }
+
+                let kind = self
+                    .projects
+                    .iter()
+                    .find(|project| *project.path() == project_path)
+                    .and_then(Project::kind);
+
+                return Task::perform(
+                    tokio::task::spawn_blocking({
+                        let project_path = project_path.clone();
+                        move || {
+                            let dirs = stats::artifact_dirs(&project_path, kind);
+                            let size = stats::artifacts_size(&project_path, &dirs);
+                            (dirs, size)
+                        }
+                    }),
+                    move |result| {
+                        let (dirs, size) = result.unwrap_or_default();
+                        cosmic::app::Message::App(Message::ProjectCleanPrepared {
+                            project_path,
+                            dirs,
+                            size,
+                        })
+                    },
+                );
+            }
+            Message::ProjectCleanPrepared {
+                project_path,
+                dirs,
+                size,
+            } => {
+                if dirs.is_empty() {
+                    return self.update(Message::ShowToast(fl!("clean-nothing")));
+                }
+
+                self.dialog_page = Some(DialogPage::CleanArtifacts {
+                    project_path,
+                    dirs,
+                    size,
+                });
+            }
+            Message::ProjectCleanConfirm => {
+                let Some(DialogPage::CleanArtifacts {
+                    project_path, dirs, ..
+                }) = self.dialog_page.take()
+                else {
+                    return Task::none();
+                };
+
+                if self.is_frozen(&project_path) {
+                    return Task::none();
+                }
+
+                let Some((modify, kind)) = self
+                    .projects
+                    .iter()
+                    .find(|project| *project.path() == project_path)
+                    .map(|project| (*project.modify(), project.kind()))
+                else {
+                    return Task::none();
+                };
+
+                info!("cleaning artifacts of {project_path:?}");
+
+                return Task::perform(
+                    tokio::task::spawn_blocking({
+                        let project_path = project_path.clone();
+                        move || {
+                            stats::clean_artifacts(&project_path, &dirs)
+                                .map(|freed| (freed, ProjectStats::collect(&project_path, kind)))
+                                .map_err(|err| err.to_string())
+                        }
+                    }),
+                    move |result| {
+                        cosmic::app::Message::App(Message::ProjectCleaned {
+                            project_path,
+                            modify,
+                            result: result.unwrap_or_else(|err| Err(err.to_string())),
+                        })
+                    },
+                );
+            }
+            Message::ProjectCleaned {
+                project_path,
+                modify,
+                result,
+            } => match result {
+                Ok((freed, stats)) => {
+                    self.record_activity([ActivityKind::Cleaned(project_path.clone(), freed)]);
+
+                    return self.update(Message::StatsLoaded {
+                        project_path,
+                        modify,
+                        stats,
+                    });
+                }
+                Err(err) => {
+                    error!("failed to clean the artifacts of {project_path:?}: {err}");
+                    return self.update(Message::ShowToast(fl!("clean-failed", error = err)));
+                }
+            },
+            Message::GitStatusLoaded {
+                project_path,
+                modify,
+                status,
+            } => {
+                self.git_status.insert(project_path, (modify, status));
+                self.update_archive_suggestions();
+            }
+            Message::ReleaseLoaded {
+                project_path,
+                modify,
+                release,
+            } => {
+                self.releases.insert(project_path, (modify, release));
+            }
+            Message::UnreleasedOnlyToggled => {
+                self.unreleased_only = !self.unreleased_only;
+            }
+            Message::ShowInactiveToggled => {
+                self.show_inactive = !self.show_inactive;
+            }
+            Message::MaxProjectAgeSelected(index) => {
+                let _ = self.config.set_max_project_age(
+                    self.config_handler.as_ref().unwrap(),
+                    MAX_PROJECT_AGES[index],
+                );
+            }
+            Message::ArchiveAfterSelected(index) => {
+                let _ = self.config.set_archive_after(
+                    self.config_handler.as_ref().unwrap(),
+                    ARCHIVE_AFTER_MONTHS[index],
+                );
+                self.update_archive_suggestions();
+            }
+            Message::ArchiveSuggestionsAccept => {
+                let projects = self
+                    .archive_suggestions()
+                    .into_iter()
+                    .map(|project| project.path().clone())
+                    .collect();
+
+                self.dialog_page = Some(DialogPage::HideArchiveSuggestions { projects });
+            }
+            Message::ArchiveSuggestionsHideConfirm => {
+                let Some(DialogPage::HideArchiveSuggestions { projects }) = self.dialog_page.take()
+                else {
+                    return Task::none();
+                };
+
+                self.hide_projects(projects);
+            }
+            Message::ArchiveSuggestionsDismiss => {
+                let suggestions = self
+                    .archive_suggestions()
+                    .into_iter()
+                    .map(|project| project.path().clone())
+                    .collect();
+
+                self.dismiss_archive_suggestions(suggestions);
+            }
+            Message::ArchiveSuggestionAccept(project_path) => {
+                self.hide_projects(vec![project_path]);
+            }
+            Message::ArchiveSuggestionDismiss(project_path) => {
+                self.dismiss_archive_suggestions(vec![project_path]);
+            }
+            Message::OwnershipLoaded {
+                project_path,
+                ownership,
+            } => {
+                self.ownership.insert(project_path, ownership);
+            }
+            Message::ProjectIconsLoaded(icons) => {
+                self.project_icons.extend(icons);
+            }
+            Message::ProjectForgesLoaded(forges) => {
+                self.project_forges.extend(forges);
+            }
+            Message::SecurityAuditToggled(security_audit) => {
+                let _ = self
+                    .config
+                    .set_security_audit(self.config_handler.as_ref().unwrap(), security_audit);
+
+                return self.run_audits();
+            }
+            Message::IgnorePowerStateToggled(ignore_power_state) => {
+                let _ = self.config.set_ignore_power_state(
+                    self.config_handler.as_ref().unwrap(),
+                    ignore_power_state,
+                );
+
+                return self.resume_background_work();
+            }
+            Message::AutomationEventSelected(index) => {
+                self.automation_event = index;
+            }
+            Message::AutomationCommandInputChanged(command) => {
+                self.automation_command_input = command;
+            }
+            Message::AutomationAdd => {
+                let command = self.automation_command_input.trim();
+
+                if command.is_empty() {
+                    return Task::none();
+                }
+
+                let mut automations = self.config.automations().to_vec();
+                automations.push(Automation::new(
+                    AutomationEvent::ALL[self.automation_event],
+                    command.to_string(),
+                ));
+                self.automation_command_input.clear();
+
+                let _ = self
+                    .config
+                    .set_automations(self.config_handler.as_ref().unwrap(), automations);
+            }
+            Message::DockPinAdd(pin) => {
+                let name = self
+                    .projects
+                    .iter()
+                    .find(|project| project.path() == pin.project())
+                    .map(|project| project.label().to_string())
+                    .unwrap_or_else(|| pin.project().to_string_lossy().to_string());
+
+                if let Err(err) = dock::pin(&pin, &name) {
+                    error!("failed to pin {pin:?} to the dock: {err}");
+                    return Task::none();
+                }
+
+                let mut pins = self.config.dock_pins().to_vec();
+                pins.retain(|existing| *existing != pin);
+                pins.push(pin);
+
+                let _ = self
+                    .config
+                    .set_dock_pins(self.config_handler.as_ref().unwrap(), pins);
+            }
+            Message::DockPinRemove(pin) => {
+                if let Err(err) = dock::unpin(&pin) {
+                    error!("failed to unpin {pin:?} from the dock: {err}");
+                }
+
+                let mut pins = self.config.dock_pins().to_vec();
+                pins.retain(|existing| *existing != pin);
+
+                let _ = self
+                    .config
+                    .set_dock_pins(self.config_handler.as_ref().unwrap(), pins);
+            }
+            Message::AutomationDelete(index) => {
+                let mut automations = self.config.automations().to_vec();
+
+                if index < automations.len() {
+                    automations.remove(index);
+                }
+
+                let _ = self
+                    .config
+                    .set_automations(self.config_handler.as_ref().unwrap(), automations);
+            }
+            Message::IgnorePatternInputChanged(pattern) => {
+                self.ignore_pattern_input = pattern;
+            }
+            Message::IgnorePatternAdd => {
+                let pattern = self.ignore_pattern_input.trim();
+
+                if pattern.is_empty() {
+                    return Task::none();
+                }
+
+                let mut ignore_patterns = self.config.ignore_patterns();
+
+                if !ignore_patterns.iter().any(|existing| existing == pattern) {
+                    ignore_patterns.push(pattern.to_string());
+                }
+                self.ignore_pattern_input.clear();
+
+                let _ = self.config.set_ignore_patterns(
+                    self.config_handler.as_ref().unwrap(),
+                    Some(ignore_patterns),
+                );
+
+                return self.update(Message::UpdateProjects);
+            }
+            Message::IgnorePatternDelete(index) => {
+                let mut ignore_patterns = self.config.ignore_patterns();
+
+                if index < ignore_patterns.len() {
+                    ignore_patterns.remove(index);
+                }
+
+                let _ = self.config.set_ignore_patterns(
+                    self.config_handler.as_ref().unwrap(),
+                    Some(ignore_patterns),
+                );
+
+                return self.update(Message::UpdateProjects);
+            }
+            Message::IgnorePatternsReset => {
+                let _ = self
+                    .config
+                    .set_ignore_patterns(self.config_handler.as_ref().unwrap(), None);
+
+                return self.update(Message::UpdateProjects);
+            }
+            Message::RuleTargetSelected(index) => {
+                self.rule_target = index;
+            }
+            Message::RulePatternInputChanged(pattern) => {
+                self.rule_pattern_input = pattern;
+            }
+            Message::RuleSyntaxSelected(index) => {
+                self.rule_syntax = index;
+            }
+            Message::RuleActionSelected(index) => {
+                if let Some(action) = RuleAction::all().into_iter().nth(index) {
+                    self.rule_action = action;
+                }
+            }
+            Message::RuleKindSelected(index) => {
+                self.rule_action = RuleAction::Kind(ProjectKind::ALL[index]);
+            }
+            Message::RuleValueInputChanged(input) => {
+                if let RuleAction::Tag(value) | RuleAction::DisplayName(value) =
+                    &mut self.rule_action
+                {
+                    *value = input;
+                }
+            }
+            Message::RuleAdd => {
+                let pattern = self.rule_pattern_input.trim();
+                let syntax = RuleSyntax::ALL[self.rule_syntax];
+
+                if pattern.is_empty()
+                    || Rule::pattern_error(syntax, pattern).is_some()
+                    || !self.rule_action.is_complete()
+                {
+                    return Task::none();
+                }
+
+                let action = match &self.rule_action {
+                    RuleAction::Tag(tag) => RuleAction::Tag(tag.trim().to_string()),
+                    RuleAction::Kind(kind) => RuleAction::Kind(*kind),
+                    RuleAction::DisplayName(name) => {
+                        RuleAction::DisplayName(name.trim().to_string())
+                    }
+                };
+
+                let mut rules = self.config.rules().to_vec();
+                rules.push(Rule::new(
+                    RuleTarget::ALL[self.rule_target],
+                    syntax,
+                    pattern.to_string(),
+                    action,
+                ));
+                self.rule_pattern_input.clear();
+                if let RuleAction::Tag(value) | RuleAction::DisplayName(value) =
+                    &mut self.rule_action
+                {
+                    value.clear();
+                }
+
+                let _ = self
+                    .config
+                    .set_rules(self.config_handler.as_ref().unwrap(), rules);
+
+                return self.update(Message::UpdateProjects);
+            }
+            Message::RuleDelete(index) => {
+                let mut rules = self.config.rules().to_vec();
+
+                if index < rules.len() {
+                    rules.remove(index);
+                }
+
+                let _ = self
+                    .config
+                    .set_rules(self.config_handler.as_ref().unwrap(), rules);
+
+                return self.update(Message::UpdateProjects);
+            }
+            Message::ValidateConfig => {
+                self.dialog_page = Some(DialogPage::ConfigReport {
+                    issues: None,
+                    removed: vec![],
+                });
+
+                return self.validate_config();
+            }
+            Message::ConfigValidated(validated) => {
+                // the report may have been closed while checking
+                if let Some(DialogPage::ConfigReport { issues, .. }) = &mut self.dialog_page {
+                    *issues = Some(validated);
+                }
+            }
+            Message::RemoveConfigIssue(issue) => {
+                if let ConfigIssue::InvalidProgram { name, .. } = &issue {
+                    // asks before removing the program together with everything referring to it
+                    return self.update(Message::ProgramDelete(name.clone()));
+                }
+
+                self.remove_config_issue(&issue);
+
+                if let Some(DialogPage::ConfigReport { removed, .. }) = &mut self.dialog_page {
+                    removed.push(issue);
+                }
+
+                return self.validate_config();
+            }
+            Message::JobConcurrencySelected(index) => {
+                let _ = self.config.set_job_concurrency(
+                    self.config_handler.as_ref().unwrap(),
+                    JOB_CONCURRENCY_LIMITS[index],
+                );
+
+                return self.jobs.start(self.config.job_concurrency());
+            }
+            Message::DiscoveryDepthSelected(index) => {
+                let _ = self.config.set_discovery_depth(
+                    self.config_handler.as_ref().unwrap(),
+                    DISCOVERY_DEPTHS[index],
+                );
+
+                return self.update(Message::UpdateProjects);
+            }
+            Message::JobCancel(id) => {
+                self.jobs.cancel(id);
+
+                return self.jobs.start(self.config.job_concurrency());
+            }
+            Message::JobFinished { id, result } => {
+                let task = match result {
+                    Ok(message) if self.jobs.finish(id) => self.update(*message),
+                    Ok(_) => Task::none(),
+                    Err(err) => {
+                        error!("background job failed: {err}");
+                        self.jobs.fail(id, err);
+                        Task::none()
+                    }
+                };
+
+                return Task::batch([task, self.jobs.start(self.config.job_concurrency())]);
+            }
+            Message::CheckRootMount => {
+                let Some(path) = self.config.project_root_path().cloned() else {
+                    return Task::none();
+                };
+
+                if self.checking_root_mount {
+                    return Task::none();
+                }
+
+                self.checking_root_mount = true;
+                let root = path.clone();
+
+                return Task::perform(
+                    tokio::task::spawn_blocking(move || mount::removable_root(&path)),
+                    move |result| {
+                        cosmic::app::Message::App(match result {
+                            Ok(root_mount) => Message::RootMountChecked(root, root_mount),
+                            Err(err) => {
+                                error!("failed to look up the media of the root: {err}");
+                                // an unchanged state only ends the check
+                                Message::RootMountChecked(PathBuf::new(), None)
+                            }
+                        })
+                    },
+                );
+            }
+            Message::RootMountChecked(path, root_mount) => {
+                self.checking_root_mount = false;
+
+                if self.config.project_root_path() == Some(&path) && root_mount != self.root_mount {
+                    return self.update(Message::ScanRoot(path, root_mount));
+                }
+            }
+            Message::MountRoot => {
+                let Some(root_mount) = self.root_mount.clone() else {
+                    return Task::none();
+                };
+
+                self.mount_error = None;
+
+                return Task::perform(
+                    tokio::task::spawn_blocking(move || mount::mount(&root_mount)),
+                    |result| {
+                        cosmic::app::Message::App(Message::RootMounted(
+                            result
+                                .map_err(|err| err.to_string())
+                                .and_then(|result| result),
+                        ))
+                    },
+                );
+            }
+            Message::RootMounted(result) => match result {
+                Ok(()) => return self.update(Message::UpdateProjects),
+                Err(err) => self.mount_error = Some(err),
+            },
+            Message::WeeklyDigestToggled(weekly_digest) => {
+                let handler = self.config_handler.as_ref().unwrap();
+
+                // the first digest comes a week after enabling it
+                if weekly_digest {
+                    let _ = self.config.set_last_digest(handler, digest::now());
+                }
+
+                let _ = self.config.set_weekly_digest(handler, weekly_digest);
+            }
+            Message::CheckWeeklyDigest => {
+                let now = digest::now();
+
+                if !self.config.weekly_digest()
+                    || now.saturating_sub(self.config.last_digest()) < digest::WEEK
+                {
+                    return Task::none();
+                }
+
+                let week = self.digest(now - digest::WEEK);
+
+                if let Err(err) = digest::notify(&fl!("weekly-digest"), &digest_summary(&week)) {
+                    error!("failed to show the weekly digest: {err}");
+                }
+
+                let _ = self
+                    .config
+                    .set_last_digest(self.config_handler.as_ref().unwrap(), now);
+            }
+            Message::DetectPowerState => {
+                return Task::perform(
+                    tokio::task::spawn_blocking(PowerState::detect),
+                    |power_state| {
+                        cosmic::app::Message::App(Message::PowerStateDetected(
+                            power_state.unwrap_or_default(),
+                        ))
+                    },
+                );
+            }
+            Message::PowerStateDetected(power_state) => {
+                let was_paused = self.background_paused();
+                self.power_state = Some(power_state);
+
+                if was_paused {
+                    return self.resume_background_work();
+                }
+            }
+            Message::CheckMarkerInputChanged(kind, marker) => {
+                self.check_marker_inputs.insert(kind, marker);
+            }
+            Message::CheckCommandInputChanged(kind, command) => {
+                self.check_command_inputs.insert(kind, command);
+            }
+            Message::CheckAdd(kind) => {
+                let marker = self.check_marker_inputs.remove(&kind).unwrap_or_default();
+                let command = self.check_command_inputs.remove(&kind).unwrap_or_default();
+
+                if marker.trim().is_empty() || command.trim().is_empty() {
+                    return Task::none();
+                }
+
+                let mut checks = self.config.checks(kind).to_vec();
+                checks.push(CheckCommand::new(
+                    marker.trim().to_string(),
+                    command.trim().to_string(),
+                ));
+
+                return self.save_checks(kind, checks);
+            }
+            Message::CheckDelete(kind, index) => {
+                let mut checks = self.config.checks(kind).to_vec();
+
+                if index < checks.len() {
+                    checks.remove(index);
+                }
+
+                return self.save_checks(kind, checks);
+            }
+            Message::HealthCheckIntervalSelected(index) => {
+                let _ = self.config.set_health_check_interval(
+                    self.config_handler.as_ref().unwrap(),
+                    HEALTH_CHECK_INTERVALS[index],
+                );
+            }
+            Message::RunChecks(kind, project_path) => {
+                let projects = match project_path {
+                    Some(project_path) => vec![project_path],
+                    None => self
+                        .projects
+                        .iter()
+                        .map(|project| project.path().clone())
+                        .collect(),
+                };
+
+                return self.run_checks(kind, projects);
+            }
+            Message::ChecksFinished {
+                kind,
+                project_path,
+                results,
+            } => {
+                self.check_results
+                    .entry(kind)
+                    .or_default()
+                    .insert(project_path, results);
+            }
+            Message::AuditFinished {
+                project_path,
+                result,
+            } => {
+                if let Some(Err(err)) = &result {
+                    error!("audit of {project_path:?} failed: {err}");
+                }
+
+                self.audits.insert(project_path, result);
+            }
+            Message::ColorBlindPaletteToggled(color_blind_palette) => {
+                let _ = self.config.set_color_blind_palette(
+                    self.config_handler.as_ref().unwrap(),
+                    color_blind_palette,
+                );
+            }
+            Message::ReduceMotionToggled(reduce_motion) => {
+                if reduce_motion {
+                    self.row_highlights.clear();
+                }
+
+                let _ = self
+                    .config
+                    .set_reduce_motion(self.config_handler.as_ref().unwrap(), reduce_motion);
+            }
+            Message::SearchSubmit => {
+                if self.search_text.trim().is_empty() {
+                    return Task::none();
+                }
+
+                if !self.content_search {
+                    let Some(project_path) = self
+                        .filter_projects()
+                        .first()
+                        .map(|project| project.path().clone())
+                    else {
+                        return Task::none();
+                    };
+
+                    let (_, program) = quick_launch(&self.search_text);
+
+                    let Some(program) = program.filter(|program| !program.is_empty()) else {
+                        return self.launch_default(project_path);
+                    };
+
+                    let program = program.to_lowercase();
+
+                    let Some(program_name) = self
+                        .programs
+                        .iter()
+                        .map(|program| program.name())
+                        .find(|name| name.to_lowercase().starts_with(&program))
+                    else {
+                        return Task::none();
+                    };
+
+                    return self.update(Message::LaunchProject {
+                        program_name: program_name.to_string(),
+                        project_path,
+                    });
+                }
+
+                let query = self.search_text.trim().to_string();
+                let projects = self
+                    .projects
+                    .iter()
+                    .map(|project| project.path().clone())
+                    .collect();
+
+                self.content_search_query = Some(query.clone());
+
+                return Task::perform(
+                    async move {
+                        let results = tokio::task::spawn_blocking({
+                            let query = query.clone();
+                            move || content_search::search(projects, &query)
+                        })
+                        .await
+                        .unwrap_or_default();
+
+                        (query, results)
+                    },
+                    |(query, results)| {
+                        cosmic::app::Message::App(Message::ContentSearchFinished { query, results })
+                    },
+                );
+            }
+            Message::ContentSearchToggled(content_search) => {
+                self.content_search = content_search;
+                self.content_search_query = None;
+                self.content_search_results.clear();
+            }
+            Message::ContentSearchFinished { query, results } => {
+                if self.content_search_query.as_ref() != Some(&query) {
+                    return Task::none();
+                }
+
+                self.content_search_query = None;
+                self.content_search_results = results;
+            }
+            Message::OpenFile(path) => {
+                if let Err(err) = open::that_detached(&path) {
+                    error!("failed to open {path:?}: {err}");
+                }
+            }
+            Message::OpenNewProject => {
+                self.templates = self
+                    .config
+                    .templates_path()
+                    .map(|path| Template::list(path))
+                    .unwrap_or_default();
+                self.new_project_error = None;
+
+                return self.update(Message::OpenContextDrawer(ContextPage::NewProject));
+            }
+            Message::NewProjectNameInputChanged(name) => {
+                self.new_project_name_input = name;
+            }
+            Message::NewProjectCreate(template) => {
+                let Some(target) = self.new_project_target() else {
+                    return Task::none();
+                };
+
+                let name = self.new_project_name_input.trim().to_string();
+                info!("creating project {:?} from template {:?}", target, template);
+
+                self.pending_hook = self
+                    .templates
+                    .iter()
+                    .find(|candidate| Some(candidate.path()) == template.as_ref())
+                    .map(|template| template.hook().to_vec())
+                    .unwrap_or_default();
+
+                return Task::perform(
+                    tokio::task::spawn_blocking(move || {
+                        template::scaffold(template.as_deref(), &target, &name)
+                            .map(|()| target.canonicalize().unwrap_or(target))
+                            .map_err(|err| err.to_string())
+                    }),
+                    |result| {
+                        let result = result.unwrap_or_else(|err| Err(err.to_string()));
+                        cosmic::app::Message::App(Message::NewProjectCreated(result))
+                    },
+                );
+            }
+            Message::NewProjectCreated(result) => match result {
+                Ok(path) => {
+                    self.new_project_name_input = "".to_string();
+
+                    let hook = std::mem::take(&mut self.pending_hook);
+                    if !hook.is_empty() {
+                        self.hook_run = Some(HookRun::new(path.clone(), hook));
+                    }
+
+                    return Task::batch(vec![
+                        self.update(Message::UpdateProjects),
+                        self.update(Message::OpenProjectDetails(path)),
+                        self.run_hook_step(),
+                    ]);
+                }
+                Err(err) => {
+                    error!("failed to create project: {err}");
+                    self.new_project_error = Some(err);
+                }
+            },
+            Message::HookStepFinished(result) => {
+                let Some(hook_run) = &mut self.hook_run else {
+                    return Task::none();
+                };
+
+                if let Err(err) = &result {
+                    error!("post-create hook failed: {err}");
+                }
+
+                hook_run.finish_step(result);
+
+                if hook_run.current_step().is_some() {
+                    return self.run_hook_step();
+                }
+
+                if hook_run.error().is_none() {
+                    self.hook_run = None;
+                    return self.update(Message::UpdateProjects);
+                }
+            }
+            Message::DismissHookRun => {
+                self.hook_run = None;
+            }
+            Message::ExportSnapshot => {
+                return Task::perform(
+                    async {
+                        rfd::AsyncFileDialog::new()
+                            .set_title(fl!("snapshot-export"))
+                            .set_file_name("projects.json")
+                            .save_file()
+                            .await
+                            .map(|file| file.path().to_path_buf())
+                    },
+                    |file| cosmic::app::Message::App(Message::SnapshotExportPicked(file)),
+                );
+            }
+            Message::SnapshotExportPicked(file) => {
+                let Some(file) = file else {
+                    return Task::none();
+                };
+
+                if let Err(err) = snapshot::save(&file, &self.create_snapshot()) {
+                    error!("failed to export snapshot to {file:?}: {err}");
+                }
+            }
+            Message::OpenSnapshot => {
+                return Task::perform(
+                    async {
+                        rfd::AsyncFileDialog::new()
+                            .set_title(fl!("snapshot-open"))
+                            .add_filter("JSON", &["json"])
+                            .pick_file()
+                            .await
+                            .map(|file| file.path().to_path_buf())
+                    },
+                    |file| cosmic::app::Message::App(Message::SnapshotPicked(file)),
+                );
+            }
+            Message::SnapshotPicked(file) => {
+                let Some(file) = file else {
+                    return Task::none();
+                };
+
+                match snapshot::load(&file) {
+                    Ok(snapshot) => self.open_snapshot(snapshot),
+                    Err(err) => error!("failed to open snapshot {file:?}: {err}"),
+                }
+            }
+            Message::CloseSnapshot => {
+                self.snapshot = None;
+                self.projects.clear();
+                self.project_stats.clear();
+                self.ownership.clear();
+                self.archive_suggested.clear();
+
+                return self.update(Message::UpdateProjects);
+            }
+            Message::ImportProject => {
+                if self.root_read_only || self.import_progress.is_some() {
+                    return Task::none();
+                }
+
+                return Task::perform(
+                    async {
+                        rfd::AsyncFileDialog::new()
+                            .set_title(fl!("import-project"))
+                            .pick_folder()
+                            .await
+                            .map(|folder| folder.path().to_path_buf())
+                    },
+                    |folder| cosmic::app::Message::App(Message::ImportProjectPicked(folder)),
+                );
+            }
+            Message::ImportProjectPicked(folder) => {
+                if let Some(source) = folder {
+                    self.dialog_page = Some(DialogPage::ImportProject { source });
+                }
+            }
+            Message::ImportProjectStart { source, mode } => {
+                self.dialog_page = None;
+
+                let (Some(root), Some(name)) =
+                    (self.config.project_root_path(), source.file_name())
+                else {
+                    return Task::none();
+                };
+
+                let target = root.join(name);
+                info!("importing {:?} to {:?} ({:?})", source, target, mode);
+
+                self.import_progress = Some((0, 0));
+                self.import_error = None;
+
+                return Task::run(
+                    cosmic::iced::stream::channel(16, move |mut output| async move {
+                        let mut progress = output.clone();
+
+                        let result = tokio::task::spawn_blocking(move || {
+                            import::import(&source, &target, mode, |done, total| {
+                                let _ = progress
+                                    .try_send(Message::ImportProjectProgress { done, total });
+                            })
+                            .map(|()| target.canonicalize().unwrap_or(target))
+                            .map_err(|err| err.to_string())
+                        })
+                        .await
+                        .unwrap_or_else(|err| Err(err.to_string()));
+
+                        let _ = output.send(Message::ImportProjectFinished(result)).await;
+                    }),
+                    cosmic::app::Message::App,
+                );
+            }
+            Message::ImportProjectProgress { done, total } => {
+                if self.import_progress.is_some() {
+                    self.import_progress = Some((done, total));
+                }
+            }
+            Message::ImportProjectFinished(result) => {
+                self.import_progress = None;
+
+                match result {
+                    Ok(path) => {
+                        return Task::batch(vec![
+                            self.update(Message::UpdateProjects),
+                            self.update(Message::OpenProjectDetails(path)),
+                        ]);
+                    }
+                    Err(err) => {
+                        error!("failed to import project: {err}");
+                        self.import_error = Some(err);
+                    }
+                }
+            }
+            Message::SharedAnnotationsMoved(result) => {
+                if let Err(err) = result {
+                    error!("failed to move shared annotations: {err}");
+                    return self.update(Message::ShowToast(err));
+                }
+            }
+            Message::DismissScanDiff => {
+                self.scan_diff = None;
+            }
+            Message::ShowToast(text) => {
+                if !self.toasts.contains(&text) {
+                    self.toasts.push_back(text);
+                }
+
+                while self.toasts.len() > MAX_TOASTS {
+                    self.toasts.pop_front();
+                }
+            }
+            Message::DismissToast(index) => {
+                self.toasts.remove(index);
+            }
+            Message::DismissImportError => {
+                self.import_error = None;
+            }
+            Message::SessionNameInputChanged(name) => {
+                self.session_name_input = name;
+            }
+            Message::SessionCreate => {
+                if !self.is_valid_session_name() {
+                    return Task::none();
+                }
+
+                let mut sessions = self.config.sessions().to_vec();
+                sessions.push(Session::new(self.session_name_input.trim().to_string()));
+                self.session_name_input = "".to_string();
+
+                self.save_sessions(sessions);
+            }
+            Message::SessionDelete(name) => {
+                if let Err(err) = autostart::set_session_autostart(&name, false) {
+                    error!("failed to remove autostart entry of session {name:?}: {err}");
+                }
+
+                let mut sessions = self.config.sessions().to_vec();
+                sessions.retain(|session| session.name() != name);
+
+                self.save_sessions(sessions);
+            }
+            Message::SessionLaunch(name) => {
+                let Some(session) = self
+                    .config
+                    .sessions()
+                    .iter()
+                    .find(|session| session.name() == name)
+                else {
+                    return Task::none();
+                };
+
+                info!("launching session - {:?}", session);
+
+                let launches: Vec<Message> = session
+                    .entries()
+                    .iter()
+                    .map(|entry| Message::LaunchProject {
+                        project_path: entry.project().to_path_buf(),
+                        program_name: entry.program().to_string(),
+                    })
+                    .collect();
+
+                return Task::batch(launches.into_iter().map(|launch| self.update(launch)));
+            }
+            Message::BulkLaunchTag(tag) => {
+                let projects = self
+                    .projects
+                    .iter()
+                    .filter(|project| !self.config.is_hidden(project.path()))
+                    .filter(|project| self.annotation(project).has_tag(&tag))
+                    .map(|project| project.path().clone())
+                    .collect();
+
+                self.dialog_page = Some(DialogPage::BulkLaunch {
+                    title: fl!("bulk-launch-tag", tag = tag.as_str()),
+                    projects,
+                    program: None,
+                });
+            }
+            Message::BulkLaunchSavedSearch(search) => {
+                let projects = self
+                    .projects
+                    .iter()
+                    .filter(|project| !self.config.is_hidden(project.path()))
+                    .filter(|project| {
+                        self.search_match(project, &self.annotation(project), &search)
+                            .is_some()
+                    })
+                    .map(|project| project.path().clone())
+                    .collect();
+
+                self.dialog_page = Some(DialogPage::BulkLaunch {
+                    title: fl!("bulk-launch-matching", search = search.as_str()),
+                    projects,
+                    program: None,
+                });
+            }
+            Message::SavedSearchAdd => {
+                let (search, _) = quick_launch(&self.search_text);
+                let search = search.trim().to_string();
+
+                if search.is_empty() || self.config.saved_searches().contains(&search) {
+                    return Task::none();
+                }
+
+                let mut saved_searches = self.config.saved_searches().to_vec();
+                saved_searches.push(search);
+
+                let _ = self
+                    .config
+                    .set_saved_searches(self.config_handler.as_ref().unwrap(), saved_searches);
+            }
+            Message::SavedSearchDelete(search) => {
+                let mut saved_searches = self.config.saved_searches().to_vec();
+                saved_searches.retain(|saved| *saved != search);
+
+                let _ = self
+                    .config
+                    .set_saved_searches(self.config_handler.as_ref().unwrap(), saved_searches);
+            }
+            Message::BulkLaunchProgramSelected(index) => {
+                if let Some(DialogPage::BulkLaunch { program, .. }) = &mut self.dialog_page {
+                    *program = Some(index);
+                }
+            }
+            Message::BulkLaunchConfirm => {
+                let Some(DialogPage::BulkLaunch {
+                    projects,
+                    program: Some(program),
+                    ..
+                }) = self.dialog_page.take()
+                else {
+                    return Task::none();
+                };
+
+                let Some(program) = self.programs.get(program) else {
+                    return Task::none();
+                };
+
+                info!(
+                    "launching {} projects with {}",
+                    projects.len().min(BULK_LAUNCH_LIMIT),
+                    program.name()
+                );
+
+                let launches: Vec<Message> = projects
+                    .into_iter()
+                    .take(BULK_LAUNCH_LIMIT)
+                    .map(|project_path| Message::LaunchProject {
+                        project_path,
+                        program_name: program.name().to_string(),
+                    })
+                    .collect();
+
+                return Task::batch(launches.into_iter().map(|launch| self.update(launch)));
+            }
+            Message::OpenCommandPalette => {
+                self.palette_project = None;
+                self.palette_tasks.clear();
+
+                return self.open_palette();
+            }
+            Message::OpenProjectPalette => {
+                let Some(project_path) = self
+                    .selected_project()
+                    .map(|project| project.path().clone())
+                else {
+                    return Task::none();
+                };
+
+                return self.update(Message::OpenProjectPaletteFor(project_path));
+            }
+            Message::OpenProjectPaletteFor(project_path) => {
+                let Some(project) = self
+                    .projects
+                    .iter()
+                    .find(|project| *project.path() == project_path)
+                else {
+                    return Task::none();
+                };
+
+                self.palette_project = Some(project.path().clone());
+                self.palette_tasks = if project.is_protected() {
+                    vec![]
+                } else {
+                    RepoTask::detect(project.path())
+                };
+
+                return self.open_palette();
+            }
+            Message::PaletteQueryChanged(query) => {
+                self.palette_query = query;
+            }
+            Message::PaletteSubmit => {
+                if let Some(entry) = self.palette_entries().first() {
+                    return self.update(Message::PaletteRun(Box::new(entry.message().clone())));
+                }
+            }
+            Message::PaletteRun(message) => {
+                self.dialog_page = None;
+                return self.update(*message);
+            }
+            Message::RunRepoTask {
+                project_path,
+                command,
+            } => {
+                if self
+                    .projects
+                    .iter()
+                    .any(|project| project.path() == &project_path && project.is_protected())
+                {
+                    return Task::none();
+                }
+
+                info!("running {command:?} in {project_path:?}");
+
+                if let Err(err) = terminal::spawn(&command, &project_path, self.config.terminal()) {
+                    error!("failed to run {command:?} in {project_path:?}: {err}");
+                }
+            }
+            Message::SessionAutostartToggled(name, enabled) => {
+                if let Err(err) = autostart::set_session_autostart(&name, enabled) {
+                    error!("failed to update autostart entry of session {name:?}: {err}");
+                    return Task::none();
+                }
+
+                let mut sessions = self.config.sessions().to_vec();
+
+                if let Some(session) = sessions.iter_mut().find(|session| session.name() == name) {
+                    session.set_autostart(enabled);
+                }
+
+                self.save_sessions(sessions);
+            }
+            Message::SessionProgramSelected(index) => {
+                self.session_program = Some(index);
+            }
+            Message::SessionAddProject(name) => {
+                let (Some(project), Some(program)) = (
+                    self.details_path.clone(),
+                    self.programs.get(self.session_program.unwrap_or_default()),
+                ) else {
+                    return Task::none();
+                };
+
+                let program = program.name().to_string();
+                let mut sessions = self.config.sessions().to_vec();
+
+                if let Some(session) = sessions.iter_mut().find(|session| session.name() == name) {
+                    session.add(project, program);
+                }
+
+                self.save_sessions(sessions);
+            }
+            Message::SessionRemoveEntry {
+                session: session_name,
+                project,
+                program,
+            } => {
+                let mut sessions = self.config.sessions().to_vec();
+
+                if let Some(session) = sessions
+                    .iter_mut()
+                    .find(|session| session.name() == session_name)
+                {
+                    session.remove(&project, &program);
+                }
+
+                self.save_sessions(sessions);
+            }
+            Message::FocusSearchInput => {
+                return widget::text_input::focus(self.search_input_id.clone())
+            }
+        }
+        Task::none()
+    }
+
+    fn footer(&self) -> Option<Element<Self::Message>> {
+        if !self.config.show_footer() {
+            return None;
+        }
+
+        let cosmic_theme::Spacing { space_m, .. } = theme::active().cosmic().spacing;
+
+        let stats: Vec<&ProjectStats> = self
+            .projects
+            .iter()
+            .filter_map(|project| self.project_stats.get(project.path()))
+            .map(|(_, stats)| stats)
+            .collect();
+
+        let dirty = stats
+            .iter()
+            .filter(|stats| stats.dirty() == Some(true))
+            .count();
+        let artifact_size: u64 = stats.iter().map(|stats| stats.artifact_size()).sum();
+
+        let mut footer = widget::row()
+            .push(widget::text::caption(fl!(
+                "footer-projects",
+                count = self.projects.len()
+            )))
+            .push(widget::text::caption(fl!("footer-dirty", count = dirty)))
+            .push(widget::text::caption(fl!(
+                "footer-artifacts",
+                size = format_size(artifact_size)
+            )))
+            .spacing(space_m);
+
+        if let Some(last_scan) = self.last_scan {
+            let time = chrono::DateTime::<chrono::Local>::from(last_scan).format("%H:%M");
+
+            footer = footer.push(widget::text::caption(fl!(
+                "footer-last-scan",
+                time = time.to_string()
+            )));
+        }
+
+        if stats.len() < self.projects.len() {
+            footer = footer.push(widget::text::caption(fl!(
+                "footer-collecting",
+                done = stats.len(),
+                total = self.projects.len()
+            )));
+        }
+
+        Some(footer.into())
+    }
+
+    fn view(&self) -> Element<Self::Message> {
+        let theme = theme::active();
+        let cosmic_theme::Spacing { space_xs, .. } = theme.cosmic().spacing;
+
+        let mut column = widget::Column::new();
+
+        if let Some(snapshot) = &self.snapshot {
+            let created = chrono::DateTime::<chrono::Local>::from(snapshot.created())
+                .format("%Y-%m-%d %H:%M");
+
+            column = column.push(
+                widget::Row::new()
+                    .push(
+                        widget::text::caption(fl!(
+                            "snapshot-browsing",
+                            root = self.root_name(snapshot.root()),
+                            created = created.to_string()
+                        ))
+                        .width(Length::Fill),
+                    )
+                    .push(
+                        widget::button::standard(fl!("snapshot-close"))
+                            .on_press(Message::CloseSnapshot),
+                    )
+                    .align_y(Alignment::Center),
+            );
+        }
+
+        if let Some(root_mount) = self
+            .root_mount
+            .as_ref()
+            .filter(|root_mount| !root_mount.is_mounted())
+        {
+            column = column.push(
+                widget::Row::new()
+                    .push(
+                        widget::text::caption(fl!(
+                            "root-not-mounted",
+                            path = root_mount.mount_point().display().to_string()
+                        ))
+                        .width(Length::Fill),
+                    )
+                    .push(widget::button::standard(fl!("mount")).on_press(Message::MountRoot))
+                    .align_y(Alignment::Center),
+            );
+
+            if let Some(err) = &self.mount_error {
+                column = column.push(widget::text::caption(fl!(
+                    "mount-failed",
+                    error = err.as_str()
+                )));
+            }
+        }
+
+        if self.root_read_only {
+            column = column.push(widget::text::caption(fl!("root-read-only")));
+        }
+
+        if let Some(hook_run) = &self.hook_run {
+            column = column.push(self.hook_run(hook_run));
+        }
+
+        if let Some((done, total)) = self.import_progress {
+            column = column.push(widget::text::caption(fl!(
+                "import-project-progress",
+                done = done,
+                total = total
+            )));
+        }
+
+        if let Some(diff) = &self.scan_diff {
+            column = column.push(self.scan_diff(diff));
+        }
+
+        let archive_suggestions = self.archive_suggested.len();
+
+        if archive_suggestions > 0 && !self.kiosk && self.snapshot.is_none() {
+            column = column.push(self.archive_banner(archive_suggestions));
+        }
+
+        if let Some(err) = &self.import_error {
+            column = column.push(
+                widget::Row::new()
+                    .push(
+                        widget::text::caption(fl!("import-project-failed", error = err.as_str()))
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        widget::button::icon(widget::icon::from_name("window-close-symbolic"))
+                            .on_press(Message::DismissImportError),
+                    )
+                    .align_y(Alignment::Center),
+            );
+        }
+
+        let content = if self.content_search {
+            self.content_search_results(&theme)
+        } else {
+            column = column.push_maybe(self.tag_chips(&theme));
+            self.projects(&theme)
+        };
+
+        column
+            .push(content)
+            .push_maybe(self.toasts())
+            .spacing(space_xs)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+}
+
+impl AppModel {
+    /// Items of the header menu, the toggles and options checked while active.
+    fn menu_items(&self, menu: Menu) -> Vec<menu::Item<MenuAction, String>> {
+        MenuAction::ALL
+            .into_iter()
+            .filter(|&action| action.menu() == Some(menu) && self.is_action_available(action))
+            .map(|action| {
+                let icon = action
+                    .icon()
+                    .map(|icon| widget::icon::from_name(icon).handle());
+
+                match self.is_action_checked(action) {
+                    Some(checked) => menu::Item::CheckBox(action.label(), icon, checked, action),
+                    None => menu::Item::Button(action.label(), icon, action),
+                }
+            })
+            .collect()
+    }
+
+    /// Whether the action is offered right now, leaving out the ones the mode or config rule out.
+    fn is_action_available(&self, action: MenuAction) -> bool {
+        match action {
+            MenuAction::ShowInactive if self.config.max_project_age() == 0 => false,
+            action => self.is_allowed(&action.message()),
+        }
+    }
+
+    /// Whether the toggle or option the action selects is active, `None` for plain actions.
+    fn is_action_checked(&self, action: MenuAction) -> Option<bool> {
+        let sort_order = match action {
+            MenuAction::UnreleasedOnly => return Some(self.unreleased_only),
+            MenuAction::ShowInactive => return Some(self.show_inactive),
+            MenuAction::SortByModified => SortOrder::Modified,
+            MenuAction::SortByName => SortOrder::Name,
+            MenuAction::SortByCreated => SortOrder::Created,
+            MenuAction::SortByLaunched => SortOrder::Launched,
+            MenuAction::SortManually => SortOrder::Manual,
+            _ => return None,
+        };
+
+        Some(self.config.sort_order() == sort_order)
+    }
+
+    /// Projects matching the search, pinned ones first, ranked by relevance and usage while
+    /// searching by name or tag.
+    fn filter_projects(&self) -> Vec<&Project> {
+        let frecencies = ranking::frecencies(self.config.project_usage(), digest::now());
+        let frecency = |project: &Project| {
+            frecencies
+                .get(project.path().as_path())
+                .copied()
+                .unwrap_or_default()
+        };
+        let last_launches = history::last_launches(self.config.launch_history());
+
+        let (search, _) = quick_launch(&self.search_text);
+
+        let matches: Vec<(&Project, Annotation, Option<f64>)> = self
+            .projects
+            .iter()
+            .filter(|project| !self.config.is_hidden(project.path()))
+            .filter(|project| !self.unreleased_only || self.has_unreleased_changes(project))
+            .map(|project| (project, self.annotation(project)))
+            .filter(|(project, annotation)| {
+                self.show_inactive || annotation.pinned() || !self.is_inactive(project)
+            })
+            .filter(|(_, annotation)| {
+                self.tag_filter
+                    .as_ref()
+                    .is_none_or(|tag| annotation.has_tag(tag))
+            })
+            .filter_map(|(project, annotation)| {
+                let score = self.search_match(project, &annotation, search)?;
+                Some((project, annotation, score))
+            })
+            .collect();
+
+        let max_frecency = matches
+            .iter()
+            .map(|(project, ..)| frecency(project))
+            .fold(0.0, f64::max);
+        let weight = f64::from(self.config.usage_weight()) / 100.0;
+
+        matches
+            .into_iter()
+            .map(|(project, annotation, score)| {
+                let rank = score
+                    .map(|score| ranking::blend(score, frecency(project), max_frecency, weight));
+                (project, annotation, rank)
+            })
+            .sorted_by(|(a, a_annotation, a_rank), (b, b_annotation, b_rank)| {
+                ranking::compare(
+                    a_annotation.pinned(),
+                    *a_rank,
+                    b_annotation.pinned(),
+                    *b_rank,
+                )
+                .then_with(|| match self.config.sort_order() {
+                    SortOrder::Modified => b.modify().cmp(a.modify()),
+                    SortOrder::Name => a.label().cmp(b.label()),
+                    SortOrder::Created => b
+                        .created()
+                        .cmp(&a.created())
+                        .then_with(|| b.modify().cmp(a.modify())),
+                    SortOrder::Launched => last_launches
+                        .get(b.path().as_path())
+                        .cmp(&last_launches.get(a.path().as_path()))
+                        .then_with(|| b.modify().cmp(a.modify())),
+                    SortOrder::Manual => self.compare_manually(a, b),
+                })
+            })
+            .map(|(project, ..)| project)
+            .collect()
+    }
+
+    /// How the project matches the search, `None` if it does not and the fuzzy score of the match
+    /// when searching by name.
+    fn search_match(
+        &self,
+        project: &Project,
+        annotation: &Annotation,
+        search: &str,
+    ) -> Option<Option<f64>> {
+        if let Some(person) = search.strip_prefix('@') {
+            return self
+                .ownership
+                .get(project.path())
+                .is_some_and(|ownership| ownership.involves(person))
+                .then_some(None);
+        }
+
+        if let Some(tag) = search.strip_prefix('#') {
+            let tag = tag.trim().to_lowercase();
+
+            return annotation
+                .tags()
+                .iter()
+                .any(|project_tag| project_tag.to_lowercase().starts_with(&tag))
+                .then_some(None);
+        }
+
+        if let Some(kind) = search.strip_prefix("kind:") {
+            let kind = kind.trim().to_lowercase();
+
+            return project
+                .kind()
+                .is_some_and(|project_kind| project_kind.name().starts_with(&kind))
+                .then_some(None);
+        }
+
+        if search.is_empty() {
+            return Some(None);
+        }
+
+        let score = [project.name().as_str(), project.label()]
+            .into_iter()
+            .chain(annotation.tags().iter().map(String::as_str))
+            .filter_map(|text| ranking::fuzzy_score(text, search))
+            .max_by(f64::total_cmp)?;
+
+        Some(Some(score))
+    }
+
+    /// Projects suggested for hiding, in the order they are listed.
+    fn archive_suggestions(&self) -> Vec<&Project> {
+        self.projects
+            .iter()
+            .filter(|project| self.archive_suggested.contains(project.path()))
+            .collect()
+    }
+
+    /// Suggests hiding the projects neither modified nor launched within the months of
+    /// [`Config::archive_after`], leaving out pinned ones and those whose suggestion was dismissed.
+    fn update_archive_suggestions(&mut self) {
+        let months = self.config.archive_after();
+
+        if months == 0 {
+            self.archive_suggested.clear();
+            return;
+        }
+
+        let inactive_since = digest::now().saturating_sub((MONTH * months).as_secs());
+        let last_launches = history::last_launches(self.config.launch_history());
+        let hidden: HashSet<&PathBuf> = self.config.hidden_projects().iter().collect();
+        let dismissed: HashSet<&PathBuf> =
+            self.config.dismissed_archive_suggestions().iter().collect();
+
+        self.archive_suggested = self
+            .projects
+            .iter()
+            .filter(|project| {
+                !hidden.contains(project.path())
+                    && !dismissed.contains(project.path())
+                    && !self.annotation(project).pinned()
+                    && self
+                        .last_change(project)
+                        .elapsed()
+                        .is_ok_and(|age| age > MONTH * months)
+                    && last_launches
+                        .get(project.path().as_path())
+                        .is_none_or(|&time| time < inactive_since)
+            })
+            .map(|project| project.path().clone())
+            .collect();
+    }
+
+    /// Whether destructive actions are off for the project, as it or the whole root is protected.
+    fn is_frozen(&self, project_path: &Path) -> bool {
+        self.root_read_only
+            || self
+                .projects
+                .iter()
+                .find(|project| project.path() == project_path)
+                .is_none_or(|project| project.is_protected())
+    }
+
+    fn hide_projects(&mut self, projects: Vec<PathBuf>) {
+        let mut hidden_projects = self.config.hidden_projects().to_vec();
+        for project in projects {
+            if !hidden_projects.contains(&project) {
+                hidden_projects.push(project);
+            }
+        }
+
+        let _ = self
+            .config
+            .set_hidden_projects(self.config_handler.as_ref().unwrap(), hidden_projects);
+        self.update_archive_suggestions();
+    }
+
+    fn dismiss_archive_suggestions(&mut self, projects: Vec<PathBuf>) {
+        let mut dismissed = self.config.dismissed_archive_suggestions().to_vec();
+        dismissed.extend(projects);
+
+        let _ = self
+            .config
+            .set_dismissed_archive_suggestions(self.config_handler.as_ref().unwrap(), dismissed);
+        self.update_archive_suggestions();
+    }
+
+    /// Whether the project was not modified within the maximum project age.
+    fn is_inactive(&self, project: &Project) -> bool {
+        let months = self.config.max_project_age();
+
+        months > 0
+            && self
+                .last_change(project)
+                .elapsed()
+                .is_ok_and(|age| age > MONTH * months)
+    }
+
+    /// Checked out branch of the project's repository as of its last loaded status, as asking git
+    /// for it would block the window.
+    fn git_branch(&self, project_path: &Path) -> Option<String> {
+        self.git_status
+            .get(project_path)
+            .and_then(|(_, status)| status.as_ref()?.branch())
+            .map(str::to_string)
+    }
+
+    /// When the project directory was modified or, if later, its repository last committed to.
+    fn last_change(&self, project: &Project) -> SystemTime {
+        let committed = self
+            .git_status
+            .get(project.path())
+            .and_then(|(_, status)| status.as_ref()?.last_commit())
+            .map(|time| UNIX_EPOCH + Duration::from_secs(time));
+
+        committed.map_or(*project.modify(), |committed| {
+            committed.max(*project.modify())
+        })
+    }
+
+    fn has_unreleased_changes(&self, project: &Project) -> bool {
+        self.releases
+            .get(project.path())
+            .and_then(|(_, release)| release.as_ref())
+            .is_some_and(|release| release.has_unreleased_changes())
+    }
+
+    fn compare_manually(&self, a: &Project, b: &Project) -> Ordering {
+        self.config
+            .manual_rank(a.path())
+            .cmp(&self.config.manual_rank(b.path()))
+            .then_with(|| b.modify().cmp(a.modify()))
+    }
+
+    /// Moves the dragged project in front of `target` in the manual order.
+    fn drop_project(&mut self, dragged: &Path, target: &Path) {
+        let mut order: Vec<PathBuf> = self
+            .projects
+            .iter()
+            .sorted_by(|a, b| self.compare_manually(a, b))
+            .map(|project| project.path().clone())
+            .filter(|path| path != dragged)
+            .collect();
+
+        let index = order
+            .iter()
+            .position(|path| path == target)
+            .unwrap_or(order.len());
+        order.insert(index, dragged.to_path_buf());
+
+        // keep the place of projects that are currently missing, e.g. on unmounted media
+        for path in self.config.manual_order() {
+            if !order.contains(path) {
+                order.push(path.clone());
+            }
+        }
+
+        let _ = self
+            .config
+            .set_manual_order(self.config_handler.as_ref().unwrap(), order);
+    }
+
+    /// Pins and tags of the project, both shared and personal.
+    fn annotation(&self, project: &Project) -> Annotation {
+        if let Some(snapshot) = &self.snapshot {
+            return snapshot
+                .project(project.path())
+                .map(|project| project.annotation().clone())
+                .unwrap_or_default();
+        }
+
+        let mut annotation = Annotation::merge(
+            &self.shared_annotation(project),
+            &self.personal_annotation(project.path()),
+        );
+
+        for tag in project.rule_tags() {
+            annotation.add_tag(tag.clone());
+        }
+
+        annotation
+    }
+
+    fn shared_annotation(&self, project: &Project) -> Annotation {
+        self.shared_annotations
+            .get(project.name())
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn personal_annotation(&self, project_path: &Path) -> Annotation {
+        self.config
+            .annotation(project_path)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Labels the project by its annotation again after the name in it changed, else by a rule
+    /// or its folder name.
+    fn relabel(&mut self, project_path: &Path) {
+        let rules = RuleSet::new(self.config.rules());
+
+        let Some(index) = self
+            .projects
+            .iter()
+            .position(|project| project.path() == project_path)
+        else {
+            return;
+        };
+
+        let name = self
+            .annotation(&self.projects[index])
+            .name()
+            .map(str::to_string);
+
+        let project = &mut self.projects[index];
+        project.reset_name(&rules);
+
+        if let Some(name) = name {
+            project.rename(name);
+        }
+
+        Project::disambiguate(&mut self.projects);
+    }
+
+    /// Replaces the personal annotation of the project, removing it when empty.
+    fn save_annotation(&mut self, project_path: PathBuf, annotation: Annotation) {
+        let mut annotations: Vec<ProjectAnnotation> = self
+            .config
+            .annotations()
+            .iter()
+            .filter(|existing| existing.project() != project_path)
+            .cloned()
+            .collect();
+
+        if !annotation.is_empty() {
+            annotations.push(ProjectAnnotation::new(project_path, annotation));
+        }
+
+        let _ = self
+            .config
+            .set_annotations(self.config_handler.as_ref().unwrap(), annotations);
+        self.update_archive_suggestions();
+    }
+
+    /// Every tag of the projects, sorted.
+    fn tags(&self) -> Vec<String> {
+        self.projects
+            .iter()
+            .flat_map(|project| self.annotation(project).tags().to_vec())
+            .unique()
+            .sorted()
+            .collect()
+    }
+
+    /// Chips filtering the projects by tag, the picked one highlighted. `None` without tags.
+    fn tag_chips(&self, theme: &Theme) -> Option<Element<Message>> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme.cosmic().spacing;
+
+        let mut tags = self.tags();
+
+        // keep the picked tag, so it can be cleared after its last project lost it
+        if let Some(tag) = self.tag_filter.as_ref().filter(|tag| !tags.contains(tag)) {
+            tags.push(tag.clone());
+        }
+
+        let chips: Vec<Element<Message>> = tags
+            .into_iter()
+            .map(|tag| {
+                let chip = if self.tag_filter.as_ref() == Some(&tag) {
+                    widget::button::suggested(format!("#{tag}"))
+                } else {
+                    widget::button::standard(format!("#{tag}"))
+                };
+
+                chip.on_press(Message::TagFilterToggled(tag)).into()
+            })
+            .collect();
+
+        if chips.is_empty() {
+            return None;
+        }
+
+        Some(
+            widget::flex_row(chips)
+                .column_spacing(space_xxs)
+                .row_spacing(space_xxs)
+                .into(),
+        )
+    }
+
+    fn projects(&self, theme: &Theme) -> Element<Message> {
+        let cosmic_theme::Spacing { space_xs, .. } = theme.cosmic().spacing;
+
+        let projects = self.filter_projects();
+
+        let filtered = !self.search_text.is_empty() || self.tag_filter.is_some();
+
+        let visible =
+            projects
+                .iter()
+                .take(self.visible_projects)
+                .enumerate()
+                .map(|(index, project)| {
+                    let hint = HINT_KEYS.chars().nth(index).filter(|_| self.hints_visible);
+                    self.project(project, hint)
+                });
+
+        let mut column =
+            widget::Column::new().push_maybe(filtered.then(|| result_count(projects.len())));
+
+        column = match self.config.view_mode() {
+            ViewMode::List => column.extend(visible),
+            ViewMode::Grid => column.push(
+                widget::flex_row(visible.collect())
+                    .column_spacing(space_xs)
+                    .row_spacing(space_xs),
+            ),
+        };
+
+        let shown = projects.len().min(self.visible_projects);
+
+        column = column.push(widget::text::caption(fl!(
+            "projects-shown",
+            shown = shown,
+            total = projects.len()
+        )));
+
+        if shown < projects.len() {
+            column = column.push(
+                widget::button::text(fl!("projects-show-more", count = PROJECT_PAGE_SIZE))
+                    .on_press(Message::ShowMoreProjects),
+            );
+        }
+
+        widget::scrollable(column)
+            .spacing(space_xs)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+
+    fn project(&self, project: &Project, hint: Option<char>) -> Element<Message> {
+        let mut content = match self.config.view_mode() {
+            ViewMode::List => self.project_row(project),
+            ViewMode::Grid => self.project_card(project),
+        };
+
+        if let Some(hint) = hint {
+            content = widget::row()
+                .push(hint_label(hint))
+                .push(content)
+                .align_y(Alignment::Center)
+                .into();
+        }
+
+        content = widget::mouse_area(content)
+            .on_right_press(Message::OpenProjectMenu(
+                project.path().clone(),
+                ProjectMenu::Context,
+            ))
+            .into();
+        content = self.with_project_menu(content, project, ProjectMenu::Context);
+
+        if let Some((dragged, _)) = &self.drag {
+            content = widget::mouse_area(content)
+                .on_enter(Message::ProjectDragOver(project.path().clone()))
+                .into();
+
+            if dragged == project.path() {
+                return widget::container(content)
+                    .class(cosmic::theme::Container::custom(|_| {
+                        widget::container::Style {
+                            background: Some(Background::Color(Color {
+                                a: 0.5,
+                                ..Color::BLACK
+                            })),
+                            ..Default::default()
+                        }
+                    }))
+                    .into();
+            }
+        }
+
+        let drop_target = self
+            .drag
+            .as_ref()
+            .is_some_and(|(_, target)| target.as_ref() == Some(project.path()));
+        let selected = drop_target || self.selected_path.as_ref() == Some(project.path());
+        let fade = self
+            .row_highlights
+            .get(project.path())
+            .map(|start| start.elapsed().as_secs_f32() / ROW_HIGHLIGHT_DURATION.as_secs_f32());
+
+        if !selected && fade.is_none() {
+            return content;
+        }
+
+        let accent = Color::from(theme::active().cosmic().accent_color());
+
+        let background = fade.map(|progress| {
+            let mut color = accent;
+            color.a = 0.35 * (1.0 - progress.min(1.0));
+            Background::Color(color)
+        });
+
+        let border = if selected {
+            Border {
+                color: accent,
+                width: 2.0,
+                radius: 4.0.into(),
+            }
+        } else {
+            Border::default()
+        };
+
+        widget::container(content)
+            .class(cosmic::theme::Container::custom(move |_| {
+                widget::container::Style {
+                    background,
+                    border,
+                    ..Default::default()
+                }
+            }))
+            .into()
+    }
+
+    fn project_row(&self, project: &Project) -> Element<Message> {
+        let mut programs = widget::Row::new();
+
+        let selected = self.selected_path.as_ref() == Some(project.path());
+        // only the selected row shows which program Enter launches
+        let highlighted = match self.selected_program {
+            _ if !selected => None,
+            Some(index) => self.programs.get(index),
+            None => self.default_program(project),
+        };
+
+        for program in self.programs.iter().filter(|_| self.snapshot.is_none()) {
+            let broken = self.is_program_broken(program.name());
+
+            let is_highlighted = highlighted == Some(program);
+
+            let button = match program_icon(program) {
+                Some(icon) if is_highlighted => {
+                    widget::button::icon(icon).class(cosmic::theme::Button::Suggested)
+                }
+                Some(icon) => widget::button::icon(icon),
+                None if is_highlighted => widget::button::suggested(program.name()),
+                None => widget::button::text(program.name()),
+            };
+
+            let button = button.on_press_maybe(broken.not().then(|| Message::LaunchProject {
+                program_name: program.name().to_string(),
+                project_path: project.path().clone(),
+            }));
+
+            programs = if broken {
+                programs.push(widget::tooltip(
+                    button,
+                    widget::text::text(fl!("program-missing", command = program.command())),
+                    widget::tooltip::Position::Bottom,
+                ))
+            } else if program.icon().is_some() {
+                programs.push(widget::tooltip(
+                    button,
+                    widget::text::text(program.name()),
+                    widget::tooltip::Position::Bottom,
+                ))
+            } else {
+                programs.push(button)
+            };
+        }
+
+        let mut header = widget::Row::new();
+
+        if let Some(icon) = self.project_icon(project, 24) {
+            header = header.push(icon);
+        }
+
+        header = header
+            .push(widget::tooltip(
+                widget::button::text(project.label().to_string())
+                    .on_press(Message::ProjectClicked(project.path().clone())),
+                widget::text::text(self.project_location(project)),
+                widget::tooltip::Position::Bottom,
+            ))
+            .push_maybe(project_kind(project))
+            .push_maybe(self.git_status_label(project));
+
+        for badge in self.badges(project) {
+            header = header.push(badge.view(self.config.color_blind_palette()));
+        }
+
+        header = header
+            .push(self.pin_button(project))
+            .push(self.overflow_button(project))
+            .push(self.details_button(project))
+            .push_maybe(self.drag_handle(project));
+
+        widget::Column::new()
+            .push(header)
+            .push_maybe(self.last_launch_label(project))
+            .push(programs)
+            .into()
+    }
+
+    fn project_card(&self, project: &Project) -> Element<Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        let icon = self.project_icon(project, 48).unwrap_or_else(|| {
+            widget::icon::from_name("folder-symbolic")
+                .size(48)
+                .icon()
+                .into()
+        });
+
+        let badges = self
+            .badges(project)
+            .iter()
+            .map(|badge| badge.view(self.config.color_blind_palette()))
+            .collect();
+
+        let card = widget::column()
+            .push(icon)
+            .push(widget::text::heading(project.label().to_string()))
+            .push_maybe(project_kind(project))
+            .push_maybe(self.git_status_label(project))
+            .push_maybe(self.last_launch_label(project))
+            .push(
+                widget::flex_row(badges)
+                    .column_spacing(space_xxs)
+                    .row_spacing(space_xxs),
+            )
+            .push(
+                widget::row()
+                    .push(self.pin_button(project))
+                    .push(self.overflow_button(project))
+                    .push(self.details_button(project))
+                    .push_maybe(self.drag_handle(project)),
+            )
+            .align_x(Alignment::Center)
+            .spacing(space_xxs)
+            .width(Length::Fixed(180.0));
+
+        widget::tooltip(
+            widget::button::custom(card)
+                .on_press(Message::ProjectClicked(project.path().clone()))
+                .padding(space_xxs),
+            widget::text::text(self.project_location(project)),
+            widget::tooltip::Position::Bottom,
+        )
+        .into()
+    }
+
+    /// Label of the root, or its path if it was not given one.
+    fn root_name(&self, root: &Path) -> String {
+        self.config
+            .root_label(root)
+            .map(str::to_string)
+            .unwrap_or_else(|| root.display().to_string())
+    }
+
+    /// Path of the project, starting with the label of its root if it has one.
+    fn project_location(&self, project: &Project) -> String {
+        let root = self
+            .snapshot
+            .as_ref()
+            .map(|snapshot| snapshot.root().to_path_buf())
+            .or_else(|| self.config.project_root_path().cloned());
+
+        root.and_then(|root| {
+            let label = self.config.root_label(&root)?;
+            let relative = project.path().strip_prefix(&root).ok()?;
+
+            Some(Path::new(label).join(relative).display().to_string())
+        })
+        .unwrap_or_else(|| project.path().display().to_string())
+    }
+
+    /// Root to watch for new and removed projects, unless a snapshot is open or its media is missing.
+    fn watched_root(&self) -> Option<PathBuf> {
+        if self.snapshot.is_some()
+            || self
+                .root_mount
+                .as_ref()
+                .is_some_and(|root_mount| !root_mount.is_mounted())
+        {
+            return None;
+        }
+
+        self.config.project_root_path().cloned()
+    }
+
+    /// Checked out branch of the project, marked if the working tree has changes.
+    fn git_status_label(&self, project: &Project) -> Option<Element<Message>> {
+        let status = self.git_status.get(project.path())?.1.as_ref()?;
+        let branch = status
+            .branch()
+            .map_or_else(|| fl!("git-detached"), str::to_string);
+
+        let label = if status.is_dirty() {
+            fl!("git-status-dirty", branch = branch)
+        } else {
+            fl!("git-status-clean", branch = branch)
+        };
+
+        Some(widget::text::caption(label).into())
+    }
+
+    fn last_launch_label(&self, project: &Project) -> Option<Element<Message>> {
+        let record = history::last_launch(self.config.launch_history(), project.path())?;
+
+        Some(
+            widget::text::caption(fl!(
+                "last-launched",
+                program = record.program(),
+                days = digest::now().saturating_sub(record.time()) / (24 * 60 * 60)
+            ))
+            .into(),
+        )
+    }
+
+    fn is_program_broken(&self, name: &str) -> bool {
+        self.broken_programs.iter().any(|broken| broken == name)
+    }
+
+    /// Launches the project with the first configured program.
+    fn launch_default(&mut self, project_path: PathBuf) -> Task<Message> {
+        let Some(program) = self
+            .projects
+            .iter()
+            .find(|project| *project.path() == project_path)
+            .and_then(|project| self.default_program(project))
+        else {
+            return Task::none();
+        };
+
+        self.update(Message::LaunchProject {
+            program_name: program.name().to_string(),
+            project_path,
+        })
+    }
+
+    /// Launches the project once the compositor handed out a token for the program's window.
+    /// Asks for the prompts of the next launch waiting for them.
+    fn next_prompt(&mut self) -> Task<Message> {
+        match self.pending_prompts.pop_front() {
+            Some((project_path, program_name)) => self.update(Message::LaunchProject {
+                program_name,
+                project_path,
+            }),
+            None => Task::none(),
+        }
+    }
+
+    fn request_launch(
+        &mut self,
+        project_path: PathBuf,
+        program_name: String,
+        answers: Vec<(String, String)>,
+    ) -> Task<Message> {
+        // without a token the compositor keeps the focus on this window
+        if self.config.after_launch() == AfterLaunch::StayFocused {
+            return self.update(Message::LaunchProjectActivated {
+                project_path,
+                program_name,
+                token: None,
+                answers,
+            });
+        }
+
+        activation::request_token(Some(Self::APP_ID.to_string()), self.core.main_window_id()).map(
+            move |token| {
+                cosmic::app::Message::App(Message::LaunchProjectActivated {
+                    project_path: project_path.clone(),
+                    program_name: program_name.clone(),
+                    token,
+                    answers: answers.clone(),
+                })
+            },
+        )
+    }
+
+    /// Program the project is launched with by default: the one chosen for it, else the one it was
+    /// last launched with, else the global default. Without any of these, it is the configured
+    /// program for the default application of the project's kind or of directories, else the
+    /// first program, and only when there are no programs the default application itself.
+    fn default_program(&self, project: &Project) -> Option<&Program> {
+        let remembered = [
+            self.config.project_default_program(project.path()),
+            history::last_launch(self.config.launch_history(), project.path())
+                .map(|record| record.program()),
+            self.config.default_program(),
+        ];
+
+        if let Some(program) = remembered
+            .into_iter()
+            .flatten()
+            .find_map(|name| self.program(name))
+        {
+            return Some(program);
+        }
+
+        let handler = project
+            .kind()
+            .and_then(|kind| self.default_handlers.get(kind.mime_type()))
+            .or_else(|| {
+                self.default_handlers
+                    .get(desktop_entry::DIRECTORY_MIME_TYPE)
+            });
+
+        // a configured program for the same application keeps its launch overrides
+        let configured = handler.and_then(|handler| {
+            self.programs
+                .iter()
+                .find(|program| program.desktop_entry() == handler.desktop_entry())
+        });
+
+        // the default application only stands in when no programs are configured
+        configured.or_else(|| self.programs.first()).or(handler)
+    }
+
+    /// Configured program of that name, or the default application of a MIME type.
+    fn program(&self, name: &str) -> Option<&Program> {
+        self.programs
+            .iter()
+            .chain(self.default_handlers.values())
+            .find(|program| program.name() == name)
+    }
+
+    /// Moves the selection with the arrow keys or Ctrl+j and Ctrl+Shift+k, launches it with Enter
+    /// or the mnemonic of a program and picks the program with Tab or the number keys.
+    /// `None` if the key does none of this, or if a dialog or the context drawer takes the keys.
+    fn navigate(&mut self, modifiers: Modifiers, key: &Key) -> Option<Task<Message>> {
+        if self.dialog_page.is_some() || self.core.window.show_context {
+            return None;
+        }
+
+        let character = match key {
+            Key::Character(character) => Some(character.as_str()),
+            _ => None,
+        };
+
+        match key {
+            Key::Named(Named::ArrowDown) if modifiers.is_empty() => self.move_selection(1),
+            Key::Named(Named::ArrowUp) if modifiers.is_empty() => self.move_selection(-1),
+            _ if modifiers == Modifiers::CTRL && character == Some("j") => self.move_selection(1),
+            // Ctrl+k opens the command palette
+            _ if modifiers == Modifiers::CTRL | Modifiers::SHIFT && character == Some("K") => {
+                self.move_selection(-1)
+            }
+            Key::Named(Named::Enter) if modifiers.is_empty() => {
+                let project = self.selected_project()?;
+                let project_path = project.path().clone();
+                let program_name = match self.selected_program {
+                    Some(index) => self.programs.get(index),
+                    None => self.default_program(project),
+                }?
+                .name()
+                .to_string();
+
+                return Some(self.update(Message::LaunchProject {
+                    program_name,
+                    project_path,
+                }));
+            }
+            Key::Named(Named::Tab) if !self.programs.is_empty() => {
+                let count = self.programs.len();
+                let offset = if modifiers == Modifiers::SHIFT {
+                    count - 1
+                } else {
+                    1
+                };
+
+                let current = self
+                    .selected_program
+                    .or_else(|| {
+                        let project = self.selected_project()?;
+                        let default = self.default_program(project)?;
+
+                        self.programs
+                            .iter()
+                            .position(|program| program.name() == default.name())
+                    })
+                    .unwrap_or_default();
+
+                self.selected_program = Some((current.min(count - 1) + offset) % count);
+            }
+            _ if modifiers.is_empty() => {
+                let character = character?;
+
+                let mnemonic = self.programs.iter().find(|program| {
+                    program
+                        .mnemonic()
+                        .is_some_and(|mnemonic| character == mnemonic.to_string())
+                });
+
+                if let Some(program) = mnemonic {
+                    let program_name = program.name().to_string();
+                    let project_path = self.selected_project()?.path().clone();
+
+                    return Some(self.update(Message::LaunchProject {
+                        program_name,
+                        project_path,
+                    }));
+                }
+
+                let number: usize = character.parse().ok()?;
+                let index = number.checked_sub(1)?;
+
+                if index >= self.programs.len() {
+                    return None;
+                }
+
+                self.selected_program = Some(index);
+            }
+            _ => return None,
+        }
+
+        Some(Task::none())
+    }
+
+    /// Moves the selection through the listed projects, starting at the top one.
+    fn move_selection(&mut self, offset: isize) {
+        let projects = self.filter_projects();
+
+        let index = self
+            .selected_path
+            .as_ref()
+            .and_then(|selected| {
+                projects
+                    .iter()
+                    .position(|project| project.path() == selected)
+            })
+            .map_or(0, |index| {
+                index
+                    .saturating_add_signed(offset)
+                    .min(projects.len().saturating_sub(1))
+            });
+
+        let Some(project_path) = projects.get(index).map(|project| project.path().clone()) else {
+            return;
+        };
+
+        if index >= self.visible_projects {
+            self.visible_projects += PROJECT_PAGE_SIZE;
+        }
+
+        self.selected_path = Some(project_path);
+    }
+
+    /// Launches the rendered project whose hint is `key`.
+    fn launch_hinted(&mut self, key: &str) -> Task<Message> {
+        let Some(index) = HINT_KEYS.find(key).filter(|_| key.chars().count() == 1) else {
+            return Task::none();
+        };
+
+        let Some(project_path) = self
+            .filter_projects()
+            .into_iter()
+            .take(self.visible_projects)
+            .nth(index)
+            .map(|project| project.path().clone())
+        else {
+            return Task::none();
+        };
+
+        self.hints_visible = false;
+        self.launch_default(project_path)
+    }
+
+    /// Remembers the activity for the weekly digest, forgetting what is too old for it.
+    ///
+    /// Launches are not recorded, the digest counts them from the launch history.
+    fn record_activity(&mut self, kinds: impl IntoIterator<Item = ActivityKind>) {
+        let mut kinds = kinds.into_iter().peekable();
+
+        if kinds.peek().is_none() {
+            return;
+        }
+
+        let now = digest::now();
+
+        let mut activity: Vec<Activity> = self
+            .config
+            .activity()
+            .iter()
+            .filter(|activity| now.saturating_sub(activity.time()) < digest::RETENTION)
+            .cloned()
+            .collect();
+        activity.extend(kinds.map(Activity::new));
+
+        let _ = self
+            .config
+            .set_activity(self.config_handler.as_ref().unwrap(), activity);
+    }
+
+    fn record_usage(&mut self, project_path: &Path) {
+        let mut project_usage = self.config.project_usage().to_vec();
+
+        let index = match project_usage
+            .iter()
+            .position(|usage| usage.project() == project_path)
+        {
+            Some(index) => index,
+            None => {
+                project_usage.push(ProjectUsage::new(project_path.to_path_buf()));
+                project_usage.len() - 1
+            }
+        };
+
+        let now = digest::now();
+        project_usage[index].record_launch(now);
+        ranking::truncate(&mut project_usage, now);
+
+        let _ = self
+            .config
+            .set_project_usage(self.config_handler.as_ref().unwrap(), project_usage);
+    }
+
+    fn record_launch(&mut self, project_path: &Path, program_name: &str) {
+        let mut history = self.config.launch_history().to_vec();
+        history.push(LaunchRecord::new(
+            project_path.to_path_buf(),
+            program_name.to_string(),
+            digest::now(),
+        ));
+
+        let excess = history.len().saturating_sub(history::HISTORY_LIMIT);
+        history.drain(..excess);
+
+        let _ = self
+            .config
+            .set_launch_history(self.config_handler.as_ref().unwrap(), history);
+    }
+
+    /// The largest projects by the size `size` picks from their statistics, largest first.
+    fn largest_projects(&self, size: impl Fn(&ProjectStats) -> u64) -> Vec<(&Project, u64)> {
+        let mut projects: Vec<(&Project, u64)> = self
+            .projects
+            .iter()
+            .filter_map(|project| {
+                let (_, stats) = self.project_stats.get(project.path())?;
+                Some((project, size(stats)))
+            })
+            .filter(|(_, size)| *size > 0)
+            .collect();
+
+        projects.sort_by(|(_, a), (_, b)| b.cmp(a));
+        projects.truncate(LARGEST_PROJECTS);
+        projects
+    }
+
+    /// Projects and their disk usage by kind, for the statistics page.
+    fn kind_stats(&self) -> Vec<KindStats> {
+        KindStats::by_kind(self.projects.iter().map(|project| {
+            let stats = self
+                .project_stats
+                .get(project.path())
+                .map(|(_, stats)| stats);
+
+            (project.kind(), stats)
+        }))
+    }
+
+    /// Summarizes the week starting at `since`, in seconds since the unix epoch.
+    fn digest(&self, since: u64) -> Digest {
+        Digest::collect(
+            self.config.activity(),
+            self.config.launch_history(),
+            self.projects.iter().map(|project| *project.modify()),
+            since,
+        )
+    }
+
+    /// Whether the message leaves the open settings while they have edits that were not saved.
+    fn leaves_unsaved_settings(&self, message: &Message) -> bool {
+        let leaves = match message {
+            Message::CloseContextDrawer | Message::LaunchProject { .. } => true,
+            Message::OpenContextDrawer(context_page) => *context_page != ContextPage::Settings,
+            Message::OpenPage(page) => *page == Page::Statistics,
+            _ => false,
+        };
+
+        leaves
+            && self.core.window.show_context
+            && self.context_page == ContextPage::Settings
+            && self.has_unsaved_settings()
+    }
+
+    fn has_unsaved_settings(&self) -> bool {
+        let root_label = self
+            .config
+            .project_root_path()
+            .and_then(|root| self.config.root_label(root))
+            .unwrap_or_default();
+
+        self.root_path_input != path_input(self.config.project_root_path())
+            || self.templates_path_input != path_input(self.config.templates_path())
+            || self.root_label_input != root_label
+            || self.terminal_input.trim() != self.config.terminal().unwrap_or_default()
+            || !self.program_command_input.is_empty()
+            || !self.program_name_input.is_empty()
+            || !self.program_icon_input.is_empty()
+            || !self.program_env.is_empty()
+            || !self.program_env_input.is_empty()
+            || self.program_start_in_project.is_some()
+    }
+
+    /// Puts the config back into the settings inputs, dropping what was typed into them.
+    fn reset_settings_inputs(&mut self) {
+        self.root_path_input = path_input(self.config.project_root_path());
+        self.templates_path_input = path_input(self.config.templates_path());
+        self.root_label_input = self
+            .config
+            .project_root_path()
+            .and_then(|root| self.config.root_label(root))
+            .unwrap_or_default()
+            .to_string();
+        self.terminal_input = self.config.terminal().unwrap_or_default().to_string();
+        self.program_command_input.clear();
+        self.program_name_input.clear();
+        self.program_desktop_entry = None;
+        self.program_flatpak = None;
+        self.program_run_in_terminal = false;
+        self.program_start_in_project = None;
+        self.program_icon_input.clear();
+        self.program_env.clear();
+        self.program_env_input.clear();
+        self.program_edit = None;
+    }
+
+    /// Binds the keys to the action, or removes its shortcut, saving the change.
+    fn set_shortcut(&mut self, shortcut: Shortcut, key_bind: Option<menu::KeyBind>) {
+        let shortcuts = self
+            .key_binds
+            .assign(self.config.shortcuts(), shortcut, key_bind);
+
+        let _ = self
+            .config
+            .set_shortcuts(self.config_handler.as_ref().unwrap(), shortcuts);
+        self.key_binds = KeyBinds::new(self.config.shortcuts());
+    }
+
+    fn is_allowed(&self, message: &Message) -> bool {
+        (!self.kiosk || message.is_allowed_in_kiosk())
+            && (self.snapshot.is_none() || message.is_allowed_in_snapshot())
+    }
+
+    fn create_snapshot(&self) -> Snapshot {
+        let root = self
+            .snapshot
+            .as_ref()
+            .map(|snapshot| snapshot.root().to_path_buf())
+            .or_else(|| self.config.project_root_path().cloned())
+            .unwrap_or_default();
+
+        let projects = self
+            .projects
+            .iter()
+            .map(|project| {
+                SnapshotProject::new(
+                    project.clone(),
+                    self.annotation(project),
+                    self.project_stats
+                        .get(project.path())
+                        .map(|(_, stats)| *stats),
+                    self.ownership.get(project.path()).cloned(),
+                )
+            })
+            .collect();
+
+        Snapshot::new(root, projects)
+    }
+
+    /// Replaces the projects with the ones of the snapshot, dropping everything loaded for the root.
+    fn open_snapshot(&mut self, snapshot: Snapshot) {
+        self.scan_id += 1;
+        self.scanned = None;
+        self.projects = snapshot
+            .projects()
+            .iter()
+            .map(|project| project.project().clone())
+            .collect();
+        Project::disambiguate(&mut self.projects);
+
+        self.project_stats = snapshot
+            .projects()
+            .iter()
+            .filter_map(|project| {
+                let stats = project.stats()?;
+                Some((
+                    project.project().path().clone(),
+                    (*project.project().modify(), *stats),
+                ))
+            })
+            .collect();
+
+        self.ownership = snapshot
+            .projects()
+            .iter()
+            .filter_map(|project| {
+                Some((
+                    project.project().path().clone(),
+                    project.ownership()?.clone(),
+                ))
+            })
+            .collect();
+
+        self.project_forges.clear();
+        self.project_icons.clear();
+        self.git_status.clear();
+        self.releases.clear();
+        self.audits.clear();
+        self.check_results.clear();
+        self.row_highlights.clear();
+        self.selected_path = None;
+        self.root_mount = None;
+        self.last_scan = Some(snapshot.created());
+        self.snapshot = Some(snapshot);
+        self.update_archive_suggestions();
+    }
+
+    fn pin_button(&self, project: &Project) -> Element<Message> {
+        let pin_icon = if self.annotation(project).pinned() {
+            "starred-symbolic"
+        } else {
+            "non-starred-symbolic"
+        };
+
+        let mut pin = widget::button::icon(widget::icon::from_name(pin_icon));
+
+        let toggle = Message::PinToggled(project.path().clone());
+
+        if self.is_allowed(&toggle) {
+            pin = pin.on_press(toggle);
+        }
+
+        pin.into()
+    }
+
+    /// Handle for dragging the project, only offered while sorting manually.
+    fn drag_handle(&self, project: &Project) -> Option<Element<Message>> {
+        let drag_start = Message::ProjectDragStart(project.path().clone());
+
+        if self.config.sort_order() != SortOrder::Manual || !self.is_allowed(&drag_start) {
+            return None;
+        }
+
+        Some(
+            widget::mouse_area(widget::icon::from_name("list-drag-handle-symbolic").size(16))
+                .on_press(drag_start)
+                .into(),
+        )
+    }
+
+    /// Shows the menu of the project over `content` while it is open.
+    fn with_project_menu<'a>(
+        &'a self,
+        content: Element<'a, Message>,
+        project: &Project,
+        menu: ProjectMenu,
+    ) -> Element<'a, Message> {
+        let open = self
+            .project_menu
+            .as_ref()
+            .is_some_and(|(path, open_menu)| path == project.path() && *open_menu == menu);
+
+        if !open {
+            return content;
+        }
+
+        widget::popover(content)
+            .popup(self.project_menu(project, menu))
+            .on_close(Message::CloseProjectMenu)
+            .position(widget::popover::Position::Bottom)
+            .into()
+    }
+
+    /// Menu of the project, listing its actions allowed right now.
+    fn project_menu(&self, project: &Project, menu: ProjectMenu) -> Element<Message> {
+        let pinned = self.annotation(project).pinned();
+
+        let items = menu
+            .actions()
+            .iter()
+            .copied()
+            .filter(|action| self.is_allowed(&action.message(project.path())))
+            .map(|action| {
+                let key_bind = self.key_binds.get(Shortcut::Project(action));
+
+                shortcut_button(action.label(pinned), key_bind.map(ToString::to_string))
+                    .on_press(Message::ProjectActionRun(action, project.path().clone()))
+                    .into()
+            });
+
+        widget::container(widget::column().extend(items))
+            .padding(4)
+            .width(Length::Fixed(220.0))
+            .class(cosmic::theme::Container::Dropdown)
+            .into()
+    }
+
+    /// "…" button opening the overflow menu with the less used actions of the project.
+    fn overflow_button(&self, project: &Project) -> Element<Message> {
+        let button = widget::button::icon(widget::icon::from_name("view-more-symbolic")).on_press(
+            Message::OpenProjectMenu(project.path().clone(), ProjectMenu::Overflow),
+        );
+
+        self.with_project_menu(button.into(), project, ProjectMenu::Overflow)
+    }
+
+    fn details_button(&self, project: &Project) -> Element<Message> {
+        widget::button::icon(widget::icon::from_name("document-properties-symbolic"))
+            .on_press(Message::OpenProjectDetails(project.path().clone()))
+            .into()
+    }
+
+    fn project_icon(&self, project: &Project, size: u16) -> Option<Element<Message>> {
+        if !self.config.show_project_icons() {
+            return None;
+        }
+
+        let path = self.project_icons.get(project.path())?.as_ref()?;
+
+        let icon: Element<Message> = if path.extension().is_some_and(|ext| ext == "svg") {
+            widget::svg(widget::svg::Handle::from_path(path))
+                .width(size)
+                .height(size)
+                .into()
+        } else {
+            widget::image(widget::image::Handle::from_path(path))
+                .width(size)
+                .height(size)
+                .into()
+        };
+
+        Some(icon)
+    }
+
+    /// Looks for logos of projects that were not checked yet, in the background.
+    fn load_project_icons(&self) -> Task<Message> {
+        if !self.config.show_project_icons() {
+            return Task::none();
+        }
+
+        let projects: Vec<PathBuf> = self
+            .projects
+            .iter()
+            .map(|project| project.path().clone())
+            .filter(|path| !self.project_icons.contains_key(path))
+            .collect();
+
+        if projects.is_empty() {
+            return Task::none();
+        }
+
+        Task::perform(
+            tokio::task::spawn_blocking(move || {
+                projects
+                    .into_iter()
+                    .map(|project| {
+                        let icon = project_icon::find(&project);
+                        (project, icon)
+                    })
+                    .collect::<Vec<_>>()
+            }),
+            |icons| {
+                cosmic::app::Message::App(Message::ProjectIconsLoaded(icons.unwrap_or_default()))
+            },
+        )
+    }
+
+    /// Reads the `origin` remotes of projects that were not checked yet, in the background.
+    fn load_project_forges(&self) -> Task<Message> {
+        let projects: Vec<PathBuf> = self
+            .projects
+            .iter()
+            .map(|project| project.path().clone())
+            .filter(|path| !self.project_forges.contains_key(path))
+            .collect();
+
+        if projects.is_empty() {
+            return Task::none();
+        }
+
+        Task::perform(
+            tokio::task::spawn_blocking(move || {
+                projects
+                    .into_iter()
+                    .map(|project| {
+                        let forge = Forge::detect(&project);
+                        (project, forge)
+                    })
+                    .collect::<Vec<_>>()
+            }),
+            |forges| {
+                cosmic::app::Message::App(Message::ProjectForgesLoaded(forges.unwrap_or_default()))
+            },
+        )
+    }
+
+    fn load_ownership(&mut self) -> Task<Message> {
+        if !self.config.show_ownership() {
+            return Task::none();
+        }
+
+        let projects: Vec<PathBuf> = self
+            .projects
+            .iter()
+            .map(|project| project.path().clone())
+            .filter(|path| !self.ownership.contains_key(path))
+            .collect();
+
+        for project_path in projects {
+            self.jobs
+                .push(JobKind::Ownership, project_path.clone(), move |_| {
+                    let ownership = Ownership::detect(&project_path);
+                    Message::OwnershipLoaded {
+                        project_path,
+                        ownership,
+                    }
+                });
+        }
+
+        self.jobs.start(self.config.job_concurrency())
+    }
+
+    /// Queues reading the branch and working tree state of the projects changed since it was last read.
+    fn load_git_status(&mut self) -> Task<Message> {
+        if self.background_paused() {
+            return Task::none();
+        }
+
+        let projects: Vec<(PathBuf, SystemTime)> = self
+            .projects
+            .iter()
+            .filter(|project| {
+                self.git_status
+                    .get(project.path())
+                    .is_none_or(|(modify, _)| modify != project.modify())
+            })
+            .map(|project| (project.path().clone(), *project.modify()))
+            .collect();
+
+        for (project_path, modify) in projects {
+            self.jobs
+                .push(JobKind::GitStatus, project_path.clone(), move |_| {
+                    let status = git::status(&project_path);
+                    Message::GitStatusLoaded {
+                        project_path,
+                        modify,
+                        status,
+                    }
+                });
+        }
+
+        self.jobs.start(self.config.job_concurrency())
+    }
+
+    /// Queues release detection for projects that changed since they were last checked.
+    fn load_releases(&mut self) -> Task<Message> {
+        if self.background_paused() {
+            return Task::none();
+        }
+
+        let projects: Vec<(PathBuf, SystemTime)> = self
+            .projects
+            .iter()
+            .filter(|project| {
+                self.releases
+                    .get(project.path())
+                    .is_none_or(|(modify, _)| modify != project.modify())
+            })
+            .map(|project| (project.path().clone(), *project.modify()))
+            .collect();
+
+        for (project_path, modify) in projects {
+            self.jobs
+                .push(JobKind::Release, project_path.clone(), move |_| {
+                    let release = release::detect(&project_path);
+                    Message::ReleaseLoaded {
+                        project_path,
+                        modify,
+                        release,
+                    }
+                });
+        }
+
+        self.jobs.start(self.config.job_concurrency())
+    }
+
+    /// Queues statistics for projects that changed since they were last collected.
+    fn load_stats(&mut self) -> Task<Message> {
+        if !self.config.show_footer() || self.background_paused() {
+            return Task::none();
+        }
+
+        let projects: Vec<(PathBuf, SystemTime, Option<ProjectKind>)> = self
+            .projects
+            .iter()
+            .filter(|project| {
+                self.project_stats
+                    .get(project.path())
+                    .is_none_or(|(modify, _)| modify != project.modify())
+            })
+            .map(|project| (project.path().clone(), *project.modify(), project.kind()))
+            .collect();
+
+        for (project_path, modify, kind) in projects {
+            self.jobs
+                .push(JobKind::Stats, project_path.clone(), move |_| {
+                    let stats = ProjectStats::collect(&project_path, kind);
+                    Message::StatsLoaded {
+                        project_path,
+                        modify,
+                        stats,
+                    }
+                });
+        }
+
+        self.jobs.start(self.config.job_concurrency())
+    }
+
+    /// Whether expensive background work waits because of battery or a metered connection.
+    fn background_paused(&self) -> bool {
+        !self.config.ignore_power_state()
+            && self
+                .power_state
+                .is_none_or(|power_state| power_state.is_constrained())
+    }
+
+    /// Starts the background work that was skipped while it was paused.
+    fn resume_background_work(&mut self) -> Task<Message> {
+        // the projects of a snapshot are not reachable
+        if self.snapshot.is_some() {
+            return Task::none();
+        }
+
+        Task::batch([
+            self.run_audits(),
+            self.run_unchecked(CheckKind::Lint),
+            self.load_stats(),
+            self.load_git_status(),
+            self.load_releases(),
+        ])
+    }
+
+    /// Queues dependency audits for projects that were not audited yet.
+    fn run_audits(&mut self) -> Task<Message> {
+        if !self.config.security_audit() || self.background_paused() {
+            return Task::none();
+        }
+
+        let projects: Vec<PathBuf> = self
+            .projects
+            .iter()
+            .map(|project| project.path().clone())
+            .filter(|path| !self.audits.contains_key(path))
+            .collect();
+
+        for project_path in projects {
+            self.jobs
+                .push(JobKind::Audit, project_path.clone(), move |_| {
+                    let result = audit::run(&project_path);
+                    Message::AuditFinished {
+                        project_path,
+                        result,
+                    }
+                });
+        }
+
+        self.jobs.start(self.config.job_concurrency())
+    }
+
+    /// Replaces the checks of a kind, forgetting their previous results.
+    fn save_checks(&mut self, kind: CheckKind, checks: Vec<CheckCommand>) -> Task<Message> {
+        let handler = self.config_handler.as_ref().unwrap();
+
+        let _ = match kind {
+            CheckKind::Lint => self.config.set_lint_checks(handler, checks),
+            CheckKind::Health => self.config.set_health_checks(handler, checks),
+        };
+
+        self.check_results.remove(&kind);
+
+        match kind {
+            CheckKind::Lint => self.run_unchecked(kind),
+            CheckKind::Health => Task::none(),
+        }
+    }
+
+    /// Runs the checks of a kind for projects that were not checked yet.
+    fn run_unchecked(&mut self, kind: CheckKind) -> Task<Message> {
+        if self.background_paused() {
+            return Task::none();
+        }
+
+        let checked = self.check_results.get(&kind);
+
+        let projects = self
+            .projects
+            .iter()
+            .map(|project| project.path().clone())
+            .filter(|path| checked.is_none_or(|checked| !checked.contains_key(path)))
+            .collect();
+
+        self.run_checks(kind, projects)
+    }
+
+    /// Queues the checks of a kind for the given projects.
+    fn run_checks(&mut self, kind: CheckKind, projects: Vec<PathBuf>) -> Task<Message> {
+        let checks = self.config.checks(kind).to_vec();
+
+        if checks.is_empty() {
+            return Task::none();
+        }
+
+        for project_path in projects {
+            let checks = checks.clone();
+
+            self.jobs.push(
+                JobKind::Check(kind),
+                project_path.clone(),
+                move |cancelled| {
+                    let results = check::run(&project_path, &checks, cancelled);
+                    Message::ChecksFinished {
+                        kind,
+                        project_path,
+                        results,
+                    }
+                },
+            );
+        }
+
+        self.jobs.start(self.config.job_concurrency())
+    }
+
+    fn is_checking(&self, kind: CheckKind, project_path: &Path) -> bool {
+        self.jobs.contains(JobKind::Check(kind), project_path)
+    }
+
+    fn check_results(&self, kind: CheckKind, project: &Project) -> Option<&[CheckResult]> {
+        self.check_results
+            .get(&kind)?
+            .get(project.path())
+            .map(Vec::as_slice)
+    }
+
+    fn audit(&self, project: &Project) -> Option<&Result<Vec<Vulnerability>, String>> {
+        self.audits.get(project.path())?.as_ref()
+    }
+
+    fn badges(&self, project: &Project) -> Vec<Badge> {
+        let mut badges = vec![];
+
+        if project.is_read_only() {
+            badges.push(Badge::ReadOnly);
+        }
+
+        if project.is_locked() {
+            badges.push(Badge::Locked);
+        }
+
+        if let Some(Some(forge)) = self.project_forges.get(project.path()) {
+            badges.push(Badge::Forge(forge.clone()));
+        }
+
+        if let Some(results) = self.check_results(CheckKind::Lint, project) {
+            if !check::all_passed(results) {
+                badges.push(Badge::LintFailing);
+            }
+        }
+
+        if let Some(results) = self.check_results(CheckKind::Health, project) {
+            if !results.is_empty() {
+                badges.push(Badge::Health(check::all_passed(results)));
+            }
+        }
+
+        if let Some(Ok(vulnerabilities)) = self.audit(project) {
+            if !vulnerabilities.is_empty() {
+                badges.push(Badge::Vulnerabilities(vulnerabilities.len()));
+            }
+        }
+
+        if let Some(ownership) = self.ownership.get(project.path()) {
+            if let Some(owner) = ownership.owner() {
+                badges.push(Badge::Owner(owner.to_string()));
+            }
+
+            if let Some(last_committer) = ownership.last_committer() {
+                badges.push(Badge::LastCommitter(last_committer.to_string()));
+            }
+        }
+
+        for tag in self.annotation(project).tags() {
+            badges.push(Badge::Tag(tag.clone()));
+        }
+
+        badges
+    }
+
+    fn fire_automations(&self, event: AutomationEvent, data: &[(&str, String)]) {
+        for automation in self
+            .config
+            .automations()
+            .iter()
+            .filter(|automation| automation.event() == event)
+        {
+            if let Err(err) = automation::run(automation, data) {
+                error!("failed to run automation {:?}: {err}", automation.command());
+            }
+        }
+    }
+
+    /// Highlights rows of projects that are new or whose position in the list may have changed.
+    fn highlight_changed_rows(&mut self, projects: &[Project]) {
+        if self.config.reduce_motion() || self.projects.is_empty() {
+            return;
+        }
+
+        let previous: HashMap<&PathBuf, &SystemTime> = self
+            .projects
+            .iter()
+            .map(|project| (project.path(), project.modify()))
+            .collect();
+
+        let now = Instant::now();
+
+        for project in projects {
+            if previous.get(project.path()) != Some(&project.modify()) {
+                self.row_highlights.insert(project.path().clone(), now);
+            }
+        }
+    }
+
+    fn hook_run<'a>(&'a self, hook_run: &'a HookRun) -> Element<'a, Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        let Some(err) = hook_run.error() else {
+            return widget::text::caption(fl!(
+                "hook-running",
+                step = hook_run.finished() + 1,
+                total = hook_run.total(),
+                command = hook_run.current_step().unwrap_or_default()
+            ))
+            .into();
+        };
+
+        widget::Row::new()
+            .push(widget::text::caption(fl!("hook-failed", error = err)).width(Length::Fill))
+            .push(
+                widget::button::icon(widget::icon::from_name("window-close-symbolic"))
+                    .on_press(Message::DismissHookRun),
+            )
+            .align_y(Alignment::Center)
+            .spacing(space_xxs)
+            .into()
+    }
+
+    fn toasts(&self) -> Option<Element<Message>> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        if self.toasts.is_empty() {
+            return None;
+        }
+
+        let toasts = self.toasts.iter().enumerate().map(|(index, text)| {
+            widget::container(
+                widget::Row::new()
+                    .push(widget::text::body(text.as_str()).width(Length::Fill))
+                    .push(
+                        widget::button::icon(widget::icon::from_name("window-close-symbolic"))
+                            .on_press(Message::DismissToast(index)),
+                    )
+                    .align_y(Alignment::Center)
+                    .spacing(space_xxs),
+            )
+            .padding(space_xxs)
+            .class(cosmic::theme::Container::Dropdown)
+            .into()
+        });
+
+        Some(widget::column().extend(toasts).spacing(space_xxs).into())
+    }
+
+    fn scan_diff<'a>(&'a self, diff: &'a ScanDiff) -> Element<'a, Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        let changes = diff
+            .added()
+            .iter()
+            .map(|path| fl!("scan-diff-added", path = path.display().to_string()))
+            .chain(
+                diff.removed()
+                    .iter()
+                    .map(|path| fl!("scan-diff-removed", path = path.display().to_string())),
+            )
+            .chain(diff.renamed().iter().map(|(old, new)| {
+                fl!(
+                    "scan-diff-renamed",
+                    old = old.display().to_string(),
+                    new = new.display().to_string()
+                )
+            }))
+            .chain(diff.possibly_renamed().iter().map(|(old, new)| {
+                fl!(
+                    "scan-diff-possibly-renamed",
+                    old = old.display().to_string(),
+                    new = new.display().to_string()
+                )
+            }))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        widget::Row::new()
+            .push(widget::tooltip(
+                widget::text::caption(fl!(
+                    "scan-diff",
+                    added = diff.added().len(),
+                    removed = diff.removed().len(),
+                    renamed = diff.renamed().len(),
+                    possibly_renamed = diff.possibly_renamed().len()
+                ))
+                .width(Length::Fill),
+                widget::text::text(changes),
+                widget::tooltip::Position::Bottom,
+            ))
+            .push(
+                widget::button::icon(widget::icon::from_name("window-close-symbolic"))
+                    .on_press(Message::DismissScanDiff),
+            )
+            .align_y(Alignment::Center)
+            .spacing(space_xxs)
+            .into()
+    }
+
+    fn archive_banner(&self, count: usize) -> Element<Message> {
+        let cosmic_theme::Spacing { space_xxs, .. } = theme::active().cosmic().spacing;
+
+        widget::Row::new()
+            .push(
+                widget::text::caption(fl!(
+                    "archive-suggestions",
+                    count = count,
+                    months = self.config.archive_after()
+                ))
+                .width(Length::Fill),
+            )
+            .push(
+                widget::button::text(fl!("archive-suggestions-review"))
+                    .on_press(Message::OpenContextDrawer(ContextPage::Statistics)),
+            )
+            .push(
+                widget::button::text(fl!("archive-suggestions-hide"))
+                    .on_press(Message::ArchiveSuggestionsAccept),
+            )
+            .push(
+                widget::button::icon(widget::icon::from_name("window-close-symbolic"))
+                    .on_press(Message::ArchiveSuggestionsDismiss),
+            )
+            .align_y(Alignment::Center)
+            .spacing(space_xxs)
+            .into()
+    }
+
+    fn content_search_results(&self, theme: &Theme) -> Element<Message> {
+        let cosmic_theme::Spacing {
+            space_xs,
+            space_xxs,
+            ..
+        } = theme.cosmic().spacing;
+
+        if self.content_search_query.is_some() {
+            return widget::text::text(fl!("content-search-running")).into();
+        }
+
+        let mut column = widget::Column::new()
+            .push_maybe(
+                (!self.search_text.is_empty())
+                    .then(|| result_count(self.content_search_results.len())),
+            )
+            .spacing(space_xs);
+
+        for result in &self.content_search_results {
+            let name = result
+                .project()
+                .file_name()
+                .map(|name| name.to_string_lossy())
+                .unwrap_or_default();
+
+            let mut matches = widget::Column::new()
+                .push(widget::text::heading(fl!(
+                    "content-search-hits",
+                    project = name.to_string(),
+                    hits = result.hits()
+                )))
+                .spacing(space_xxs);
+
+            for content_match in result.matches() {
+                let file = content_match
+                    .path()
+                    .strip_prefix(result.project())
+                    .unwrap_or(content_match.path());
+
+                matches = matches.push(
+                    widget::button::link(format!(
+                        "{}:{} {}",
+                        file.display(),
+                        content_match.line(),
+                        content_match.text()
+                    ))
+                    .on_press(Message::OpenFile(content_match.path().clone()))
+                    .padding(0),
+                );
+            }
+
+            column = column.push(matches);
+        }
+
+        widget::scrollable(column)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .into()
+    }
+
+    fn run_hook_step(&self) -> Task<Message> {
+        let Some(hook_run) = &self.hook_run else {
+            return Task::none();
+        };
+
+        let Some(step) = hook_run.current_step() else {
+            return Task::none();
+        };
+
+        let step = step.to_string();
+        let project = hook_run.project().clone();
+        let name = project
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        info!("running post-create hook step {:?}", step);
+
+        Task::perform(
+            tokio::task::spawn_blocking(move || template::run_hook_step(&step, &project, &name)),
+            |result| {
+                let result = result.unwrap_or_else(|err| Err(err.to_string()));
+                cosmic::app::Message::App(Message::HookStepFinished(result))
+            },
+        )
+    }
+
+    /// The project keyboard actions apply to: the one whose details are shown, or the top result.
+    fn selected_project(&self) -> Option<&Project> {
+        if self.core.window.show_context && self.context_page == ContextPage::ProjectDetails {
+            if let Some(project) = self.details_project() {
+                return Some(project);
+            }
+        }
+
+        if let Some(selected_path) = &self.selected_path {
+            if let Some(project) = self
+                .projects
+                .iter()
+                .find(|project| project.path() == selected_path)
+            {
+                return Some(project);
+            }
+        }
+
+        self.filter_projects().into_iter().next()
+    }
+
+    fn open_palette(&mut self) -> Task<Message> {
+        self.palette_query = "".to_string();
+        self.dialog_page = Some(DialogPage::CommandPalette);
+
+        widget::text_input::focus(self.palette_input_id.clone())
+    }
+
+    /// Installed applications whose name contains the query, with their index.
+    fn installed_apps(&self) -> Vec<(usize, &DesktopEntry)> {
+        let query = self.installed_apps_query.trim().to_lowercase();
+
+        self.desktop_entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| entry.name().to_lowercase().contains(&query))
+            .collect()
+    }
+
+    fn palette_project(&self) -> Option<&Project> {
+        let path = self.palette_project.as_ref()?;
+        self.projects.iter().find(|project| project.path() == path)
+    }
+
+    fn palette_entries(&self) -> Vec<PaletteEntry> {
+        let mut entries = vec![];
+
+        if let Some(project) = self.palette_project() {
+            entries.push(
+                PaletteEntry::new(
+                    fl!("palette-show-details"),
+                    Message::OpenProjectDetails(project.path().clone()),
+                )
+                .with_shortcut(
+                    self.key_binds
+                        .get(Shortcut::Project(ProjectAction::Details)),
+                ),
+            );
+
+            for program in &self.programs {
+                entries.push(PaletteEntry::new(
+                    fl!("palette-launch", program = program.name()),
+                    Message::LaunchProject {
+                        project_path: project.path().clone(),
+                        program_name: program.name().to_string(),
+                    },
+                ));
+            }
+
+            entries.push(PaletteEntry::new(
+                fl!("open-with"),
+                Message::OpenWith(project.path().clone()),
+            ));
+
+            let pinned = self.annotation(project).pinned();
+
+            for action in [
+                ProjectAction::OpenFolder,
+                ProjectAction::CopyPath,
+                ProjectAction::Pin,
+                ProjectAction::Tags,
+                ProjectAction::Rename,
+                ProjectAction::Hide,
+            ] {
+                entries.push(
+                    PaletteEntry::new(
+                        action.label(pinned),
+                        Message::ProjectActionRun(action, project.path().clone()),
+                    )
+                    .with_shortcut(self.key_binds.get(Shortcut::Project(action))),
+                );
+            }
+
+            for program in &self.programs {
+                entries.push(PaletteEntry::new(
+                    fl!("dock-pin", program = program.name()),
+                    Message::DockPinAdd(DockPin::new(
+                        project.path().clone(),
+                        program.name().to_string(),
+                    )),
+                ));
+            }
+
+            for task in &self.palette_tasks {
+                entries.push(PaletteEntry::new(
+                    fl!("palette-run", command = task.command()),
+                    Message::RunRepoTask {
+                        project_path: project.path().clone(),
+                        command: task.command().to_string(),
+                    },
+                ));
+            }
+        } else {
+            for action in MenuAction::ALL
+                .into_iter()
+                .filter(|&action| action.in_palette() && self.is_action_available(action))
+            {
+                entries.push(
+                    PaletteEntry::new(action.label(), action.message())
+                        .with_shortcut(self.key_binds.get(Shortcut::Menu(action))),
+                );
+            }
+
+            entries.push(PaletteEntry::new(
+                fl!("content-search"),
+                Message::ContentSearchToggled(!self.content_search),
+            ));
+
+            for tag in self.tags() {
+                entries.push(PaletteEntry::new(
+                    fl!("bulk-launch-tag", tag = tag.as_str()),
+                    Message::BulkLaunchTag(tag),
+                ));
+            }
+
+            for search in self.config.saved_searches() {
+                entries.push(PaletteEntry::new(
+                    fl!("bulk-launch-matching", search = search.as_str()),
+                    Message::BulkLaunchSavedSearch(search.clone()),
+                ));
+            }
+
+            let (search, _) = quick_launch(&self.search_text);
+
+            if !search.trim().is_empty() {
+                entries.push(PaletteEntry::new(
+                    fl!("saved-search-add", search = search.trim()),
+                    Message::SavedSearchAdd,
+                ));
+            }
+
+            for session in self.config.sessions() {
+                entries.push(PaletteEntry::new(
+                    fl!("palette-launch-session", session = session.name()),
+                    Message::SessionLaunch(session.name().to_string()),
+                ));
+            }
+
+            for project in self.filter_projects() {
+                entries.push(PaletteEntry::new(
+                    fl!("palette-open-project", project = project.label()),
+                    Message::OpenProjectDetails(project.path().clone()),
+                ));
+            }
+        }
+
+        entries.retain(|entry| self.is_allowed(entry.message()));
+
+        palette::filter(entries, &self.palette_query)
+    }
+
+    fn is_valid_env_profile_name(&self) -> bool {
+        let name = self.env_profile_name_input.trim();
+
+        name.is_empty().not()
+            && self
+                .config
+                .env_profiles()
+                .iter()
+                .any(|profile| profile.name() == name)
+                .not()
+    }
+
+    fn is_valid_session_name(&self) -> bool {
+        let name = self.session_name_input.trim();
+
+        name.is_empty().not()
+            && self
+                .config
+                .sessions()
+                .iter()
+                .any(|session| session.name() == name)
+                .not()
+    }
+
+    /// Path of the project to create, if the entered name is usable.
+    fn new_project_target(&self) -> Option<PathBuf> {
+        let name = self.new_project_name_input.trim();
+
+        if name.is_empty() || name.contains('/') || self.root_read_only {
+            return None;
         }
-        Task::none()
+
+        let target = self.config.project_root_path()?.join(name);
+        target.exists().not().then_some(target)
     }
 
-    fn view(&self) -> Element<Self::Message> {
-        let theme = theme::active();
-        let cosmic_theme::Spacing { space_xs, .. } = theme.cosmic().spacing;
+    fn details_project(&self) -> Option<&Project> {
+        let path = self.details_path.as_ref()?;
+        self.projects.iter().find(|project| project.path() == path)
+    }
 
-        let input = widget::search_input(fl!("search-input"), &self.search_text)
-            .on_input(Message::SearchTextInputChanged)
-            .id(self.search_input_id.clone());
+    pub fn update_title(&mut self) -> Task<Message> {
+        let window_title = fl!("app-title");
 
-        widget::Column::new()
-            .push(input)
-            .push(self.projects(&theme))
-            .spacing(space_xs)
-            .width(Length::Fill)
-            .height(Length::Fill)
-            .into()
+        if let Some(id) = self.core.main_window_id() {
+            self.set_window_title(window_title, id)
+        } else {
+            Task::none()
+        }
     }
-}
 
-impl AppModel {
-    fn filter_projects(&self) -> Vec<&Project> {
-        self.projects
+    /// Lists the projects whose config still references the given program.
+    fn projects_depending_on(&self, program_name: &str) -> Vec<String> {
+        let overridden = self
+            .config
+            .launch_overrides()
             .iter()
-            .filter(|project| {
-                self.search_text.is_empty() || project.name().contains(&self.search_text)
-            })
-            .sorted_by(|a, b| b.modify().cmp(a.modify()))
+            .filter(|launch_override| launch_override.program() == program_name)
+            .map(|launch_override| launch_override.project());
+
+        let defaulting = self
+            .config
+            .default_programs()
+            .iter()
+            .filter(|default| default.program() == program_name)
+            .map(|default| default.project());
+
+        let in_sessions = self
+            .config
+            .sessions()
+            .iter()
+            .flat_map(|session| session.entries())
+            .filter(|entry| entry.program() == program_name)
+            .map(|entry| entry.project());
+
+        let pinned = self
+            .config
+            .dock_pins()
+            .iter()
+            .filter(|pin| pin.program() == program_name)
+            .map(|pin| pin.project());
+
+        overridden
+            .chain(defaulting)
+            .chain(in_sessions)
+            .chain(pinned)
+            .map(|project| project.display().to_string())
+            .unique()
             .collect()
     }
 
-    fn projects(&self, theme: &Theme) -> Element<Message> {
-        let cosmic_theme::Spacing { space_xs, .. } = theme.cosmic().spacing;
+    fn remove_program_references(&mut self, program_name: &str) {
+        let launch_overrides = self
+            .config
+            .launch_overrides()
+            .iter()
+            .filter(|launch_override| launch_override.program() != program_name)
+            .cloned()
+            .collect();
 
-        let mut column = widget::Column::new();
+        let _ = self
+            .config
+            .set_launch_overrides(self.config_handler.as_ref().unwrap(), launch_overrides);
 
-        for project in &self.filter_projects() {
-            column = column.push(self.project(project));
+        let default_programs = self
+            .config
+            .default_programs()
+            .iter()
+            .filter(|default| default.program() != program_name)
+            .cloned()
+            .collect();
+
+        let _ = self
+            .config
+            .set_default_programs(self.config_handler.as_ref().unwrap(), default_programs);
+
+        if self.config.default_program() == Some(program_name) {
+            let _ = self
+                .config
+                .set_default_program(self.config_handler.as_ref().unwrap(), None);
         }
 
-        widget::scrollable(column)
-            .spacing(space_xs)
-            .width(Length::Fill)
-            .height(Length::Fill)
-            .into()
+        let mut sessions = self.config.sessions().to_vec();
+        for session in &mut sessions {
+            session.remove_program(program_name);
+        }
+        self.save_sessions(sessions);
+
+        // pins would launch a program that no longer exists
+        let pins: Vec<DockPin> = self
+            .config
+            .dock_pins()
+            .iter()
+            .filter(|pin| pin.program() == program_name)
+            .cloned()
+            .collect();
+
+        for pin in pins {
+            let _ = self.update(Message::DockPinRemove(pin));
+        }
+
+        let prompt_answers = self
+            .config
+            .prompt_answers()
+            .iter()
+            .filter(|answers| answers.program() != program_name)
+            .cloned()
+            .collect();
+
+        let _ = self
+            .config
+            .set_prompt_answers(self.config_handler.as_ref().unwrap(), prompt_answers);
     }
-    fn project(&self, project: &Project) -> Element<Message> {
-        let mut programs = widget::Row::new();
 
-        for program in &self.programs {
-            programs = programs.push(widget::button::text(program.name()).on_press(
-                Message::LaunchProject {
-                    program_name: program.name().to_string(),
-                    project_name: project.name().to_string(),
-                },
-            ));
+    /// Points everything referring to the program by its name at its new name.
+    fn rename_program_references(&mut self, program_name: &str, name: &str) {
+        let launch_overrides = self
+            .config
+            .launch_overrides()
+            .iter()
+            .map(|launch_override| {
+                if launch_override.program() != program_name {
+                    return launch_override.clone();
+                }
+
+                LaunchOverride::new(
+                    launch_override.project().to_path_buf(),
+                    name.to_string(),
+                    launch_override.working_directory().cloned(),
+                    launch_override.path().cloned(),
+                )
+            })
+            .collect();
+
+        let _ = self
+            .config
+            .set_launch_overrides(self.config_handler.as_ref().unwrap(), launch_overrides);
+
+        let default_programs = self
+            .config
+            .default_programs()
+            .iter()
+            .map(|default| {
+                if default.program() != program_name {
+                    return default.clone();
+                }
+
+                DefaultProgram::new(default.project().to_path_buf(), name.to_string())
+            })
+            .collect();
+
+        let _ = self
+            .config
+            .set_default_programs(self.config_handler.as_ref().unwrap(), default_programs);
+
+        if self.config.default_program() == Some(program_name) {
+            let _ = self.config.set_default_program(
+                self.config_handler.as_ref().unwrap(),
+                Some(name.to_string()),
+            );
         }
 
-        widget::Column::new()
-            .push(widget::text::text(project.name().to_string()))
-            .push(programs)
-            .into()
+        let history = self
+            .config
+            .launch_history()
+            .iter()
+            .map(|record| {
+                if record.program() != program_name {
+                    return record.clone();
+                }
+
+                LaunchRecord::new(
+                    record.project().to_path_buf(),
+                    name.to_string(),
+                    record.time(),
+                )
+            })
+            .collect();
+
+        let _ = self
+            .config
+            .set_launch_history(self.config_handler.as_ref().unwrap(), history);
+
+        let mut sessions = self.config.sessions().to_vec();
+        for session in &mut sessions {
+            session.rename_program(program_name, name);
+        }
+        self.save_sessions(sessions);
+
+        // pins launch the program by its name, so their desktop entries are written anew
+        let pins: Vec<DockPin> = self
+            .config
+            .dock_pins()
+            .iter()
+            .filter(|pin| pin.program() == program_name)
+            .cloned()
+            .collect();
+
+        for pin in pins {
+            let renamed = DockPin::new(pin.project().to_path_buf(), name.to_string());
+            let _ = self.update(Message::DockPinRemove(pin));
+            let _ = self.update(Message::DockPinAdd(renamed));
+        }
+
+        let mut prompt_answers = self.config.prompt_answers().to_vec();
+        for answers in &mut prompt_answers {
+            if answers.program() == program_name {
+                answers.set_program(name.to_string());
+            }
+        }
+
+        let _ = self
+            .config
+            .set_prompt_answers(self.config_handler.as_ref().unwrap(), prompt_answers);
     }
 
-    pub fn update_title(&mut self) -> Task<Message> {
-        let window_title = fl!("app-title");
+    /// Carries everything kept about the projects over to the paths they were moved to,
+    /// writing each setting once for all of them.
+    fn move_project_references(&mut self, moves: &[(PathBuf, PathBuf)]) -> Task<Message> {
+        if moves.is_empty() {
+            return Task::none();
+        }
 
-        if let Some(id) = self.core.main_window_id() {
-            self.set_window_title(window_title, id)
-        } else {
-            Task::none()
+        let moved: HashMap<&Path, &Path> = moves
+            .iter()
+            .map(|(old, new)| (old.as_path(), new.as_path()))
+            .collect();
+        let moved_path = |path: &Path| moved.get(path).map(|new| new.to_path_buf());
+        let handler = self.config_handler.as_ref().unwrap();
+
+        let mut annotations = self.config.annotations().to_vec();
+        for annotation in &mut annotations {
+            if let Some(path) = moved_path(annotation.project()) {
+                annotation.set_project(path);
+            }
+        }
+        let _ = self.config.set_annotations(handler, annotations);
+
+        let mut history = self.config.launch_history().to_vec();
+        for record in &mut history {
+            if let Some(path) = moved_path(record.project()) {
+                record.set_project(path);
+            }
+        }
+        let _ = self.config.set_launch_history(handler, history);
+
+        let mut usage = self.config.project_usage().to_vec();
+        for project_usage in &mut usage {
+            if let Some(path) = moved_path(project_usage.project()) {
+                project_usage.set_project(path);
+            }
+        }
+        let _ = self.config.set_project_usage(handler, usage);
+
+        let mut launch_overrides = self.config.launch_overrides().to_vec();
+        for launch_override in &mut launch_overrides {
+            if let Some(path) = moved_path(launch_override.project()) {
+                launch_override.set_project(path);
+            }
+        }
+        let _ = self.config.set_launch_overrides(handler, launch_overrides);
+
+        let mut default_programs = self.config.default_programs().to_vec();
+        for default in &mut default_programs {
+            if let Some(path) = moved_path(default.project()) {
+                default.set_project(path);
+            }
+        }
+        let _ = self.config.set_default_programs(handler, default_programs);
+
+        let mut project_profiles = self.config.project_env_profiles().to_vec();
+        for chosen in &mut project_profiles {
+            if let Some(path) = moved_path(chosen.project()) {
+                chosen.set_project(path);
+            }
+        }
+        let _ = self
+            .config
+            .set_project_env_profiles(handler, project_profiles);
+
+        let moved_paths = |paths: &[PathBuf]| -> Vec<PathBuf> {
+            paths
+                .iter()
+                .map(|path| moved_path(path).unwrap_or_else(|| path.clone()))
+                .collect()
+        };
+
+        let manual_order = moved_paths(self.config.manual_order());
+        let _ = self.config.set_manual_order(handler, manual_order);
+
+        let hidden_projects = moved_paths(self.config.hidden_projects());
+        let _ = self.config.set_hidden_projects(handler, hidden_projects);
+
+        let dismissed = moved_paths(self.config.dismissed_archive_suggestions());
+        let _ = self
+            .config
+            .set_dismissed_archive_suggestions(handler, dismissed);
+
+        let mut sessions = self.config.sessions().to_vec();
+        for session in &mut sessions {
+            for (old, new) in moves {
+                session.move_project(old, new);
+            }
+        }
+        let _ = self.config.set_sessions(handler, sessions);
+
+        // pins launch the project by its path, so their desktop entries are written anew
+        let mut pins = self.config.dock_pins().to_vec();
+        for pin in &mut pins {
+            let Some(path) = moved_path(pin.project()) else {
+                continue;
+            };
+
+            let moved = DockPin::new(path, pin.program().to_string());
+            let name = self
+                .projects
+                .iter()
+                .find(|project| project.path() == moved.project())
+                .map(|project| project.label().to_string())
+                .unwrap_or_else(|| moved.project().to_string_lossy().to_string());
+
+            if let Err(err) = dock::unpin(pin).and_then(|_| dock::pin(&moved, &name)) {
+                error!("failed to move the dock pin {pin:?}: {err}");
+            }
+
+            *pin = moved;
+        }
+        let _ = self.config.set_dock_pins(handler, pins);
+
+        // shared annotations are keyed by folder name, which only a rename changes
+        let names: Vec<(String, String)> = moves
+            .iter()
+            .filter_map(|(old, new)| {
+                let old = old.file_name()?.to_str()?.to_string();
+                let new = new.file_name()?.to_str()?.to_string();
+                (old != new).then_some((old, new))
+            })
+            .filter(|(old, _)| self.shared_annotations.contains_key(old))
+            .collect();
+
+        let Some(root) = self.config.project_root_path().cloned() else {
+            return Task::none();
+        };
+
+        if names.is_empty() || self.root_read_only {
+            return Task::none();
+        }
+
+        for (old, new) in &names {
+            if let Some(annotation) = self.shared_annotations.remove(old) {
+                self.shared_annotations.insert(new.clone(), annotation);
+            }
+        }
+
+        Task::perform(
+            tokio::task::spawn_blocking(move || annotation::move_shared(&root, &names)),
+            |result| {
+                let result = result.unwrap_or_else(|err| Err(err.to_string()));
+                cosmic::app::Message::App(Message::SharedAnnotationsMoved(result))
+            },
+        )
+    }
+
+    /// Checks the config in the background, filling in the open config report.
+    fn validate_config(&self) -> Task<Message> {
+        let config = self.config.clone();
+
+        Task::perform(
+            tokio::task::spawn_blocking(move || config_repair::validate(&config)),
+            |issues| {
+                cosmic::app::Message::App(Message::ConfigValidated(issues.unwrap_or_default()))
+            },
+        )
+    }
+
+    /// Removes the config entry the issue is about.
+    fn remove_config_issue(&mut self, issue: &ConfigIssue) {
+        match issue {
+            ConfigIssue::MissingProjectRoot(_) => {
+                let _ = self
+                    .config
+                    .set_project_root_path(self.config_handler.as_ref().unwrap(), None);
+            }
+            ConfigIssue::MissingTemplatesPath(_) => {
+                let _ = self
+                    .config
+                    .set_templates_path(self.config_handler.as_ref().unwrap(), None);
+            }
+            ConfigIssue::DuplicateProgram(name) => {
+                let mut seen = false;
+                self.programs.retain(|program| {
+                    program.name() != name || !std::mem::replace(&mut seen, true)
+                });
+                self.save_programs();
+            }
+            ConfigIssue::InvalidProgram { name, .. } => {
+                self.programs.retain(|program| program.name() != name);
+                self.save_programs();
+                self.remove_program_references(name);
+            }
+            ConfigIssue::DanglingDefaultProgram { project: None, .. } => {
+                let _ = self
+                    .config
+                    .set_default_program(self.config_handler.as_ref().unwrap(), None);
+            }
+            ConfigIssue::DanglingDefaultProgram {
+                project: Some(project),
+                program,
+            } => {
+                let default_programs = self
+                    .config
+                    .default_programs()
+                    .iter()
+                    .filter(|default| default.project() != project || default.program() != program)
+                    .cloned()
+                    .collect();
+
+                let _ = self
+                    .config
+                    .set_default_programs(self.config_handler.as_ref().unwrap(), default_programs);
+            }
+            ConfigIssue::DanglingEnvProfile { project, profile } => {
+                let project_profiles = self
+                    .config
+                    .project_env_profiles()
+                    .iter()
+                    .filter(|chosen| chosen.project() != project || chosen.profile() != profile)
+                    .cloned()
+                    .collect();
+
+                let _ = self.config.set_project_env_profiles(
+                    self.config_handler.as_ref().unwrap(),
+                    project_profiles,
+                );
+            }
+            ConfigIssue::DanglingDockPin { project, program } => {
+                let _ = self.update(Message::DockPinRemove(DockPin::new(
+                    project.clone(),
+                    program.clone(),
+                )));
+            }
+            ConfigIssue::DuplicateMnemonic { program, .. } => {
+                for duplicate in self
+                    .programs
+                    .iter_mut()
+                    .filter(|duplicate| duplicate.name() == program)
+                {
+                    duplicate.set_mnemonic(None);
+                }
+                self.save_programs();
+            }
+            ConfigIssue::DanglingAnnotation(project) => {
+                let annotations = self
+                    .config
+                    .annotations()
+                    .iter()
+                    .filter(|annotation| annotation.project() != project)
+                    .cloned()
+                    .collect();
+
+                let _ = self
+                    .config
+                    .set_annotations(self.config_handler.as_ref().unwrap(), annotations);
+            }
+            ConfigIssue::DanglingOverride { project, program } => {
+                let launch_overrides = self
+                    .config
+                    .launch_overrides()
+                    .iter()
+                    .filter(|launch_override| {
+                        launch_override.project() != project || launch_override.program() != program
+                    })
+                    .cloned()
+                    .collect();
+
+                let _ = self
+                    .config
+                    .set_launch_overrides(self.config_handler.as_ref().unwrap(), launch_overrides);
+            }
+            ConfigIssue::DanglingSessionEntry {
+                session: session_name,
+                project,
+                program,
+            } => {
+                let mut sessions = self.config.sessions().to_vec();
+                for session in sessions
+                    .iter_mut()
+                    .filter(|session| session.name() == session_name)
+                {
+                    session.remove(project, program);
+                }
+                self.save_sessions(sessions);
+            }
+            ConfigIssue::DuplicateSession(name) => {
+                let mut seen = false;
+                let mut sessions = self.config.sessions().to_vec();
+                sessions.retain(|session| {
+                    session.name() != name || !std::mem::replace(&mut seen, true)
+                });
+                self.save_sessions(sessions);
+            }
+        }
+    }
+
+    fn save_sessions(&mut self, sessions: Vec<Session>) {
+        let _ = self
+            .config
+            .set_sessions(self.config_handler.as_ref().unwrap(), sessions);
+    }
+
+    /// Replaces the override for the same project and program, removing it when empty.
+    fn save_launch_override(&mut self, launch_override: LaunchOverride) {
+        let mut launch_overrides: Vec<LaunchOverride> = self
+            .config
+            .launch_overrides()
+            .iter()
+            .filter(|existing| {
+                existing.project() != launch_override.project()
+                    || existing.program() != launch_override.program()
+            })
+            .cloned()
+            .collect();
+
+        if !launch_override.is_empty() {
+            launch_overrides.push(launch_override);
         }
+
+        let _ = self
+            .config
+            .set_launch_overrides(self.config_handler.as_ref().unwrap(), launch_overrides);
     }
 
     fn save_programs(&mut self) {
-        let _ = self.config.set_programs(
+        let saved = self.config.set_programs(
             self.config_handler.as_ref().unwrap(),
             self.programs.to_vec(),
         );
+
+        if saved.is_ok() {
+            self.programs_on_disk = self.programs.clone();
+        }
     }
 
     pub fn is_valid_program(&self) -> bool {
@@ -380,7 +7012,130 @@ impl AppModel {
         let command = &self.program_command_input;
 
         name.is_empty().not()
-            && Program::is_valid_command(command)
-            && self.programs.iter().any(|p| p.name() == name).not()
+            && (self.program_desktop_entry.is_some() || Program::is_valid_command(command))
+            && self
+                .programs
+                .iter()
+                .any(|p| p.name() == name && self.program_edit.as_ref() != Some(name))
+                .not()
+    }
+}
+
+/// Number of matches of the search and tag filter, shown above the results so screen readers
+/// reach it before the list.
+fn result_count(count: usize) -> Element<'static, Message> {
+    widget::text::caption(fl!("search-results", count = count)).into()
+}
+
+/// Splits `myproj > code` or `myproj !code` into the search and the start of the name of
+/// the program Enter launches the top match with, `None` without one.
+fn quick_launch(search: &str) -> (&str, Option<&str>) {
+    let split = search.split_once('>').or_else(|| search.rsplit_once(" !"));
+
+    match split {
+        Some((search, program)) => (search.trim_end(), Some(program.trim())),
+        None => (search, None),
+    }
+}
+
+/// Icon shown on the launch buttons of the program, a path if it contains a `/` and a
+/// freedesktop icon name otherwise.
+fn program_icon(program: &Program) -> Option<widget::icon::Handle> {
+    program.icon().map(icon_handle)
+}
+
+/// Icon of an icon name or, containing a `/`, an image path.
+fn icon_handle(icon: &str) -> widget::icon::Handle {
+    if icon.contains('/') {
+        widget::icon::from_path(PathBuf::from(icon))
+    } else {
+        widget::icon::from_name(icon).handle()
+    }
+}
+
+/// Text of a settings input holding the path, empty if it is not set.
+fn path_input(path: Option<&PathBuf>) -> String {
+    path.map(|path| path.to_str().unwrap_or_default())
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Icon and label of the language or build system of the project, if it was detected.
+fn project_kind<'a>(project: &Project) -> Option<Element<'a, Message>> {
+    let kind = project.kind()?;
+
+    let cosmic_theme::Spacing { space_xxxs, .. } = theme::active().cosmic().spacing;
+
+    Some(
+        widget::row()
+            .push(widget::icon::from_name(kind.icon_name()).size(16))
+            .push(widget::text::caption(kind.label()))
+            .align_y(Alignment::Center)
+            .spacing(space_xxxs)
+            .into(),
+    )
+}
+
+fn hint_label<'a>(hint: char) -> Element<'a, Message> {
+    let theme = theme::active();
+    let accent = Color::from(theme.cosmic().accent_color());
+    let on_accent = Color::from(theme.cosmic().on_accent_color());
+
+    widget::container(widget::text::monotext(hint.to_uppercase().to_string()))
+        .padding([0, 6])
+        .class(cosmic::theme::Container::custom(move |_| {
+            widget::container::Style {
+                background: Some(Background::Color(accent)),
+                text_color: Some(on_accent),
+                border: Border {
+                    radius: 4.0.into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        }))
+        .into()
+}
+
+/// Full-width menu entry with the keys running its action, if any, at the end.
+fn shortcut_button<'a>(label: String, keys: Option<String>) -> widget::Button<'a, Message> {
+    let mut row = widget::row()
+        .push(widget::text::text(label).width(Length::Fill))
+        .align_y(Alignment::Center);
+
+    if let Some(keys) = keys {
+        row = row.push(widget::text::caption(keys));
+    }
+
+    widget::button::custom(row)
+        .class(cosmic::theme::Button::Text)
+        .width(Length::Fill)
+}
+
+fn digest_summary(digest: &Digest) -> String {
+    fl!(
+        "digest-summary",
+        touched = digest.touched(),
+        launches = digest.launches(),
+        new = digest.new_projects(),
+        cleaned = format_size(digest.cleaned())
+    )
+}
+
+/// Formats a byte count with binary units, e.g. `1.5 GiB`.
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    match unit {
+        0 => format!("{bytes} B"),
+        unit => format!("{size:.1} {}", UNITS[unit]),
     }
 }
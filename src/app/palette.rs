@@ -0,0 +1,55 @@
+use crate::app::Message;
+use cosmic::widget::menu::KeyBind;
+
+/// Maximum number of entries shown at once.
+pub const MAX_ENTRIES: usize = 15;
+
+/// A command listed in the command palette.
+#[derive(Debug, Clone)]
+pub struct PaletteEntry {
+    label: String,
+    message: Message,
+    /// Keys running the command outside the palette.
+    shortcut: Option<String>,
+}
+
+impl PaletteEntry {
+    pub fn new(label: String, message: Message) -> PaletteEntry {
+        PaletteEntry {
+            label,
+            message,
+            shortcut: None,
+        }
+    }
+
+    pub fn with_shortcut(mut self, key_bind: Option<&KeyBind>) -> PaletteEntry {
+        self.shortcut = key_bind.map(ToString::to_string);
+        self
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn shortcut(&self) -> Option<&str> {
+        self.shortcut.as_deref()
+    }
+
+    pub fn message(&self) -> &Message {
+        &self.message
+    }
+}
+
+/// Keeps the entries whose label contains every word of `query`, ignoring case.
+pub fn filter(entries: Vec<PaletteEntry>, query: &str) -> Vec<PaletteEntry> {
+    let query = query.to_lowercase();
+
+    entries
+        .into_iter()
+        .filter(|entry| {
+            let label = entry.label.to_lowercase();
+            query.split_whitespace().all(|word| label.contains(word))
+        })
+        .take(MAX_ENTRIES)
+        .collect()
+}
@@ -0,0 +1,109 @@
+use crate::app::Message;
+use crate::fl;
+use cosmic::iced::keyboard::key::Named;
+use cosmic::iced::keyboard::Key;
+use cosmic::widget::menu::key_bind::Modifier;
+use cosmic::widget::menu::KeyBind;
+use std::path::Path;
+
+/// Secondary actions on a single project, kept out of its row.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ProjectAction {
+    LaunchWith,
+    OpenFolder,
+    CopyPath,
+    Pin,
+    Tags,
+    Rename,
+    Hide,
+    Details,
+}
+
+impl ProjectAction {
+    pub const ALL: [ProjectAction; 8] = [
+        ProjectAction::LaunchWith,
+        ProjectAction::OpenFolder,
+        ProjectAction::CopyPath,
+        ProjectAction::Pin,
+        ProjectAction::Tags,
+        ProjectAction::Rename,
+        ProjectAction::Hide,
+        ProjectAction::Details,
+    ];
+
+    pub fn label(&self, pinned: bool) -> String {
+        match self {
+            ProjectAction::LaunchWith => fl!("palette-project"),
+            ProjectAction::OpenFolder => fl!("open-folder"),
+            ProjectAction::CopyPath => fl!("copy-path"),
+            ProjectAction::Pin if pinned => fl!("project-unpin"),
+            ProjectAction::Pin => fl!("project-pin"),
+            ProjectAction::Tags => fl!("project-tags-edit"),
+            ProjectAction::Rename => fl!("project-rename"),
+            ProjectAction::Hide => fl!("project-hide"),
+            ProjectAction::Details => fl!("project-details"),
+        }
+    }
+
+    /// Shortcut running the action on the selected project unless changed in the settings,
+    /// if it has one.
+    pub fn key_bind(&self) -> Option<KeyBind> {
+        let (modifiers, key) = match self {
+            ProjectAction::CopyPath => (
+                vec![Modifier::Ctrl, Modifier::Shift],
+                Key::Character("C".into()),
+            ),
+            ProjectAction::Rename => (vec![], Key::Named(Named::F2)),
+            ProjectAction::Details => (vec![Modifier::Ctrl], Key::Character("i".into())),
+            _ => return None,
+        };
+
+        Some(KeyBind { modifiers, key })
+    }
+
+    pub fn message(&self, project: &Path) -> Message {
+        let project = project.to_path_buf();
+
+        match self {
+            ProjectAction::LaunchWith => Message::OpenProjectPaletteFor(project),
+            ProjectAction::OpenFolder => Message::OpenFolder(project),
+            ProjectAction::CopyPath => Message::CopyPath(project),
+            ProjectAction::Pin => Message::PinToggled(project),
+            ProjectAction::Tags => Message::OpenProjectTags(project),
+            ProjectAction::Rename => Message::OpenRenameProject(project),
+            ProjectAction::Hide => Message::ProjectHide(project),
+            ProjectAction::Details => Message::OpenProjectDetails(project),
+        }
+    }
+}
+
+/// Menus listing project actions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProjectMenu {
+    /// Opened by right-clicking the project.
+    Context,
+    /// Opened with the "…" button of the project.
+    Overflow,
+}
+
+impl ProjectMenu {
+    pub fn actions(&self) -> &'static [ProjectAction] {
+        match self {
+            ProjectMenu::Context => &[
+                ProjectAction::LaunchWith,
+                ProjectAction::OpenFolder,
+                ProjectAction::CopyPath,
+                ProjectAction::Pin,
+                ProjectAction::Tags,
+                ProjectAction::Hide,
+            ],
+            ProjectMenu::Overflow => &[
+                ProjectAction::OpenFolder,
+                ProjectAction::CopyPath,
+                ProjectAction::Rename,
+                ProjectAction::Hide,
+                ProjectAction::Details,
+            ],
+        }
+    }
+}
@@ -0,0 +1,322 @@
+use crate::app::menu_action::MenuAction;
+use crate::app::project_action::ProjectAction;
+use cosmic::iced::keyboard::key::Named;
+use cosmic::iced::keyboard::{Key, Modifiers};
+use cosmic::widget::menu::key_bind::Modifier;
+use cosmic::widget::menu::KeyBind;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Keys other than characters a shortcut can be bound to.
+const NAMED_KEYS: [Named; 20] = [
+    Named::F1,
+    Named::F2,
+    Named::F3,
+    Named::F4,
+    Named::F5,
+    Named::F6,
+    Named::F7,
+    Named::F8,
+    Named::F9,
+    Named::F10,
+    Named::F11,
+    Named::F12,
+    Named::Delete,
+    Named::Insert,
+    Named::Home,
+    Named::End,
+    Named::PageUp,
+    Named::PageDown,
+    Named::Space,
+    Named::Backspace,
+];
+
+/// An action a shortcut can run, project actions running on the selected project.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Shortcut {
+    Menu(MenuAction),
+    Project(ProjectAction),
+}
+
+impl Shortcut {
+    /// Every action, in the order the keyboard shortcut settings list them.
+    pub fn all() -> impl Iterator<Item = Shortcut> {
+        MenuAction::ALL
+            .into_iter()
+            .map(Shortcut::Menu)
+            .chain(ProjectAction::ALL.into_iter().map(Shortcut::Project))
+    }
+
+    /// Name the action is saved under in the settings.
+    fn id(&self) -> String {
+        match self {
+            Shortcut::Menu(action) => format!("{action:?}"),
+            Shortcut::Project(action) => format!("Project{action:?}"),
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Shortcut::Menu(action) => action.label(),
+            Shortcut::Project(action) => action.label(false),
+        }
+    }
+
+    pub fn default_key_bind(&self) -> Option<KeyBind> {
+        match self {
+            Shortcut::Menu(action) => action.key_bind(),
+            Shortcut::Project(action) => action.key_bind(),
+        }
+    }
+}
+
+/// Shortcut of an action changed in the settings, `key_bind` being `None` when it was removed.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ShortcutOverride {
+    action: String,
+    key_bind: Option<String>,
+}
+
+/// Shortcuts of every action, the defaults with the changes of the settings applied.
+#[derive(Debug, Clone, Default)]
+pub struct KeyBinds {
+    binds: HashMap<Shortcut, KeyBind>,
+    /// Shortcuts of the menu actions, as the header menus take them.
+    menu: HashMap<KeyBind, MenuAction>,
+}
+
+impl KeyBinds {
+    /// Leaves out changes of actions that no longer exist or keys that cannot be read.
+    pub fn new(overrides: &[ShortcutOverride]) -> KeyBinds {
+        let mut binds = HashMap::new();
+
+        for shortcut in Shortcut::all() {
+            let key_bind = match overrides
+                .iter()
+                .find(|change| change.action == shortcut.id())
+            {
+                Some(ShortcutOverride { key_bind, .. }) => {
+                    key_bind.as_deref().and_then(from_setting)
+                }
+                None => shortcut.default_key_bind(),
+            };
+
+            if let Some(key_bind) = key_bind {
+                binds.insert(shortcut, key_bind);
+            }
+        }
+
+        let menu = binds
+            .iter()
+            .filter_map(|(shortcut, key_bind)| match shortcut {
+                Shortcut::Menu(action) => Some((key_bind.clone(), *action)),
+                Shortcut::Project(_) => None,
+            })
+            .collect();
+
+        KeyBinds { binds, menu }
+    }
+
+    pub fn get(&self, shortcut: Shortcut) -> Option<&KeyBind> {
+        self.binds.get(&shortcut)
+    }
+
+    /// Action bound to the pressed keys, if any.
+    pub fn find(&self, modifiers: Modifiers, key: &Key) -> Option<Shortcut> {
+        self.binds
+            .iter()
+            .find(|(_, key_bind)| key_bind.matches(modifiers, key))
+            .map(|(shortcut, _)| *shortcut)
+    }
+
+    pub fn menu(&self) -> &HashMap<KeyBind, MenuAction> {
+        &self.menu
+    }
+
+    /// Changes of the settings binding `key_bind` to `shortcut`, `None` removing its shortcut.
+    ///
+    /// Another action bound to the same keys loses its shortcut, so every key runs one action.
+    pub fn assign(
+        &self,
+        overrides: &[ShortcutOverride],
+        shortcut: Shortcut,
+        key_bind: Option<KeyBind>,
+    ) -> Vec<ShortcutOverride> {
+        let mut overrides = overrides.to_vec();
+        let mut set = |shortcut: Shortcut, key_bind: Option<&KeyBind>| {
+            overrides.retain(|change| change.action != shortcut.id());
+
+            if key_bind != shortcut.default_key_bind().as_ref() {
+                overrides.push(ShortcutOverride {
+                    action: shortcut.id(),
+                    key_bind: key_bind.map(to_setting),
+                });
+            }
+        };
+
+        if let Some(key_bind) = &key_bind {
+            for (other, _) in self
+                .binds
+                .iter()
+                .filter(|(other, bound)| **other != shortcut && *bound == key_bind)
+            {
+                set(*other, None);
+            }
+        }
+
+        set(shortcut, key_bind.as_ref());
+
+        overrides
+    }
+}
+
+/// Shortcut of the pressed keys, `None` for keys that cannot be bound, like modifiers alone.
+pub fn key_bind(modifiers: Modifiers, key: &Key) -> Option<KeyBind> {
+    match key {
+        Key::Character(_) => {}
+        Key::Named(named) if NAMED_KEYS.contains(named) => {}
+        _ => return None,
+    }
+
+    let modifiers = [
+        (Modifiers::LOGO, Modifier::Super),
+        (Modifiers::CTRL, Modifier::Ctrl),
+        (Modifiers::ALT, Modifier::Alt),
+        (Modifiers::SHIFT, Modifier::Shift),
+    ]
+    .into_iter()
+    .filter(|(flag, _)| modifiers.contains(*flag))
+    .map(|(_, modifier)| modifier)
+    .collect();
+
+    Some(KeyBind {
+        modifiers,
+        key: key.clone(),
+    })
+}
+
+/// Writes the shortcut like `Ctrl+Shift+P`, as the settings store it.
+fn to_setting(key_bind: &KeyBind) -> String {
+    let mut text = String::new();
+
+    for modifier in &key_bind.modifiers {
+        text.push_str(&format!("{modifier:?}+"));
+    }
+
+    match &key_bind.key {
+        Key::Character(character) => text.push_str(character),
+        Key::Named(named) => text.push_str(&format!("{named:?}")),
+        Key::Unidentified => {}
+    }
+
+    text
+}
+
+fn from_setting(text: &str) -> Option<KeyBind> {
+    let (modifiers, key) = match text.rsplit_once('+') {
+        // the plus key itself, like `Ctrl++`
+        Some((modifiers, "")) => (modifiers.strip_suffix('+').unwrap_or(modifiers), "+"),
+        Some((modifiers, key)) => (modifiers, key),
+        None => ("", text),
+    };
+
+    let modifiers = modifiers
+        .split('+')
+        .filter(|modifier| !modifier.is_empty())
+        .map(|modifier| match modifier {
+            "Super" => Some(Modifier::Super),
+            "Ctrl" => Some(Modifier::Ctrl),
+            "Alt" => Some(Modifier::Alt),
+            "Shift" => Some(Modifier::Shift),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let key = match NAMED_KEYS
+        .into_iter()
+        .find(|named| format!("{named:?}") == key)
+    {
+        Some(named) => Key::Named(named),
+        None if key.chars().count() == 1 => Key::Character(key.into()),
+        None => return None,
+    };
+
+    Some(KeyBind { modifiers, key })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bind(modifiers: Vec<Modifier>, key: &str) -> KeyBind {
+        KeyBind {
+            modifiers,
+            key: Key::Character(key.into()),
+        }
+    }
+
+    #[test]
+    fn stores_shortcuts_as_text() {
+        for key_bind in [
+            bind(vec![Modifier::Ctrl, Modifier::Shift], "P"),
+            bind(vec![Modifier::Ctrl], "+"),
+            bind(vec![], "f"),
+            KeyBind {
+                modifiers: vec![Modifier::Alt],
+                key: Key::Named(Named::F2),
+            },
+        ] {
+            assert_eq!(from_setting(&to_setting(&key_bind)), Some(key_bind));
+        }
+
+        assert_eq!(from_setting("Hyper+x"), None);
+        assert_eq!(from_setting("Ctrl+Escape"), None);
+    }
+
+    #[test]
+    fn overrides_replace_defaults() {
+        let rename = Shortcut::Project(ProjectAction::Rename);
+        let palette = Shortcut::Menu(MenuAction::CommandPalette);
+        let key_binds = KeyBinds::new(&[]);
+
+        let overrides = key_binds.assign(&[], rename, Some(bind(vec![Modifier::Ctrl], "r")));
+        let key_binds = KeyBinds::new(&overrides);
+        assert_eq!(
+            key_binds.get(rename),
+            Some(&bind(vec![Modifier::Ctrl], "r"))
+        );
+        assert_eq!(
+            key_binds.find(Modifiers::CTRL, &Key::Character("k".into())),
+            Some(palette)
+        );
+
+        let overrides = key_binds.assign(&overrides, palette, None);
+        let key_binds = KeyBinds::new(&overrides);
+        assert_eq!(key_binds.get(palette), None);
+        assert!(!key_binds
+            .menu()
+            .values()
+            .any(|action| *action == MenuAction::CommandPalette));
+
+        let default = rename.default_key_bind();
+        let overrides = key_binds.assign(&overrides, rename, default.clone());
+        assert_eq!(KeyBinds::new(&overrides).get(rename), default.as_ref());
+        assert_eq!(overrides.len(), 1);
+    }
+
+    #[test]
+    fn binding_taken_keys_unbinds_the_other_action() {
+        let key_binds = KeyBinds::new(&[]);
+        let details = Shortcut::Project(ProjectAction::Details);
+        let new_project = Shortcut::Menu(MenuAction::NewProject);
+
+        let overrides = key_binds.assign(&[], details, Some(bind(vec![Modifier::Ctrl], "n")));
+        let key_binds = KeyBinds::new(&overrides);
+
+        assert_eq!(key_binds.get(new_project), None);
+        assert_eq!(
+            key_binds.find(Modifiers::CTRL, &Key::Character("n".into())),
+            Some(details)
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use crate::fl;
+use serde::{Deserialize, Serialize};
+
+/// How the project list is laid out.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ViewMode {
+    /// Dense rows with every program's launch button.
+    #[default]
+    List,
+    /// Spacious cards showing logos and badges.
+    Grid,
+}
+
+/// What clicking a project does.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ClickAction {
+    #[default]
+    OpenDetails,
+    /// Launches the project with the first configured program.
+    LaunchDefault,
+    Select,
+}
+
+impl ClickAction {
+    pub const ALL: [ClickAction; 3] = [
+        ClickAction::OpenDetails,
+        ClickAction::LaunchDefault,
+        ClickAction::Select,
+    ];
+
+    pub fn label(&self) -> String {
+        match self {
+            ClickAction::OpenDetails => fl!("click-action-open-details"),
+            ClickAction::LaunchDefault => fl!("click-action-launch-default"),
+            ClickAction::Select => fl!("click-action-select"),
+        }
+    }
+}
+
+/// What happens to the window after launching a project.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum AfterLaunch {
+    /// Keeps the focus, e.g. to launch several projects in a row.
+    StayFocused,
+    /// Hands the focus to the window of the launched program.
+    #[default]
+    YieldFocus,
+    Minimize,
+}
+
+impl AfterLaunch {
+    pub const ALL: [AfterLaunch; 3] = [
+        AfterLaunch::StayFocused,
+        AfterLaunch::YieldFocus,
+        AfterLaunch::Minimize,
+    ];
+
+    pub fn label(&self) -> String {
+        match self {
+            AfterLaunch::StayFocused => fl!("after-launch-stay-focused"),
+            AfterLaunch::YieldFocus => fl!("after-launch-yield-focus"),
+            AfterLaunch::Minimize => fl!("after-launch-minimize"),
+        }
+    }
+}
+
+/// Order of the projects after the pinned ones.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum SortOrder {
+    /// Most recently modified first.
+    #[default]
+    Modified,
+    Name,
+    /// Most recently created first, projects without a creation time coming last.
+    Created,
+    /// Most recently launched first, projects never launched coming last.
+    Launched,
+    /// Order the projects were dragged into.
+    Manual,
+}
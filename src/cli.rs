@@ -0,0 +1,313 @@
+use crate::config::Config;
+use crate::domain::command;
+use crate::domain::digest;
+use crate::domain::git;
+use crate::domain::launch;
+use crate::domain::program::Program;
+use crate::fl;
+use cosmic::app::CosmicFlags;
+use log::{error, info, warn};
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Command line arguments of the application.
+#[derive(Debug, Clone, Default)]
+pub struct Args {
+    launch_session: Option<String>,
+    launch_project: Option<(String, PathBuf)>,
+    kiosk: bool,
+    action: Action,
+}
+
+/// Arguments forwarded to an already running instance instead of opening a second window.
+///
+/// Travels as the activated action's name, one argument and its value per line.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Action {
+    search: Option<String>,
+    page: Option<Page>,
+}
+
+impl Action {
+    pub fn search(&self) -> Option<&str> {
+        self.search.as_deref()
+    }
+
+    pub fn page(&self) -> Option<Page> {
+        self.page
+    }
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut lines = vec![];
+
+        if let Some(search) = &self.search {
+            lines.push(format!("--search\n{}", search.replace('\n', " ")));
+        }
+        if let Some(page) = self.page {
+            lines.push(format!("--page\n{page}"));
+        }
+
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+impl FromStr for Action {
+    type Err = String;
+
+    fn from_str(action: &str) -> Result<Self, Self::Err> {
+        let mut parsed = Action::default();
+        let mut lines = action.lines();
+
+        while let Some(arg) = lines.next() {
+            match (arg, lines.next()) {
+                ("--search", Some(search)) => parsed.search = Some(search.to_string()),
+                ("--page", Some(page)) => parsed.page = Some(page.parse()?),
+                (arg, _) => return Err(format!("unknown activation {arg:?}")),
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// Page the window can be opened on directly, from the command line or other features.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Page {
+    Settings,
+    Statistics,
+    Programs,
+}
+
+impl FromStr for Page {
+    type Err = String;
+
+    fn from_str(page: &str) -> Result<Self, Self::Err> {
+        match page {
+            "settings" => Ok(Page::Settings),
+            "stats" => Ok(Page::Statistics),
+            "programs" => Ok(Page::Programs),
+            page => Err(format!(
+                "unknown page {page:?}, expected settings, stats or programs"
+            )),
+        }
+    }
+}
+
+impl Display for Page {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Page::Settings => write!(f, "settings"),
+            Page::Statistics => write!(f, "stats"),
+            Page::Programs => write!(f, "programs"),
+        }
+    }
+}
+
+impl Args {
+    pub fn parse() -> Args {
+        let mut args = Args::default();
+        let mut iter = std::env::args().skip(1);
+
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--launch-session" => args.launch_session = iter.next(),
+                "--launch" => match (iter.next(), iter.next()) {
+                    (Some(program), Some(project)) => {
+                        args.launch_project = Some((program, PathBuf::from(project)))
+                    }
+                    _ => warn!("--launch expects a program and a project"),
+                },
+                "--kiosk" => args.kiosk = true,
+                "--search" => args.action.search = iter.next(),
+                "--page" => match iter.next().map(|page| page.parse()) {
+                    Some(Ok(page)) => args.action.page = Some(page),
+                    Some(Err(err)) => warn!("{err}"),
+                    None => warn!("--page is missing the page"),
+                },
+                _ => warn!("unknown argument {arg:?}"),
+            }
+        }
+
+        args
+    }
+
+    pub fn launch_session(&self) -> Option<&str> {
+        self.launch_session.as_deref()
+    }
+
+    /// Program and project to launch without opening a window.
+    pub fn launch_project(&self) -> Option<(&str, &Path)> {
+        self.launch_project
+            .as_ref()
+            .map(|(program, project)| (program.as_str(), project.as_path()))
+    }
+
+    /// Only allow browsing and launching projects, e.g. on shared machines.
+    pub fn kiosk(&self) -> bool {
+        self.kiosk
+    }
+
+    /// Term the search starts out filtered to.
+    pub fn search(&self) -> Option<&str> {
+        self.action.search()
+    }
+
+    /// Page the window opens on.
+    pub fn page(&self) -> Option<Page> {
+        self.action.page()
+    }
+}
+
+impl CosmicFlags for Args {
+    type SubCommand = Action;
+    type Args = Vec<String>;
+
+    fn action(&self) -> Option<&Action> {
+        (self.action != Action::default()).then_some(&self.action)
+    }
+}
+
+/// Launches every entry of the named session without opening a window.
+pub fn launch_session(name: &str) {
+    let (_, config) = Config::load();
+
+    let Some(session) = config
+        .sessions()
+        .iter()
+        .find(|session| session.name() == name)
+    else {
+        error!("no session named {name:?}");
+        return;
+    };
+
+    info!("launching session - {:?}", session);
+
+    for entry in session.entries() {
+        let Some(program) = config
+            .programs()
+            .iter()
+            .find(|program| program.name() == entry.program())
+        else {
+            error!(
+                "session {name:?} refers to missing program {:?}",
+                entry.program()
+            );
+            continue;
+        };
+
+        let launch_override = config.launch_override(entry.project(), entry.program());
+
+        let Some(values) = values(&config, program, entry.project()) else {
+            continue;
+        };
+
+        if let Err(err) = launch::launch(
+            program,
+            entry.project(),
+            launch_override,
+            config.launch_settings(),
+            &values,
+            env_vars(&config, entry.project()),
+        ) {
+            error!(
+                "failed to launch {} for {:?}: {err}",
+                entry.program(),
+                entry.project()
+            );
+        }
+    }
+}
+
+/// Launches the project with the named program without opening a window, e.g. from a dock pin.
+pub fn launch_project(program_name: &str, project: &Path) {
+    let (_, config) = Config::load();
+
+    let Some(program) = config
+        .programs()
+        .iter()
+        .find(|program| program.name() == program_name)
+    else {
+        error!("no program named {program_name:?}");
+        return;
+    };
+
+    let Some(values) = values(&config, program, project) else {
+        return;
+    };
+
+    let mut env = env_vars(&config, project).to_vec();
+
+    // handed over by the dock, so the program's window gets the focus
+    if let Ok(token) = std::env::var("XDG_ACTIVATION_TOKEN") {
+        env.extend(launch::activation_env(&token));
+    }
+
+    if let Err(err) = launch::launch(
+        program,
+        project,
+        config.launch_override(project, program_name),
+        config.launch_settings(),
+        &values,
+        &env,
+    ) {
+        error!("failed to launch {program_name} for {project:?}: {err}");
+    }
+}
+
+/// Placeholder values of the program, answering its prompts like its last launch from the window.
+///
+/// `None` if a prompt was never answered, as there is no window to ask in, telling the user
+/// with a notification since sessions launched at login have nobody watching their log.
+fn values(config: &Config, program: &Program, project: &Path) -> Option<command::Values> {
+    let answers = config.program_prompt_answers(program.name());
+
+    let unanswered: Vec<String> = program
+        .prompts()
+        .into_iter()
+        .filter(|label| !answers.iter().any(|(answered, _)| answered == label))
+        .collect();
+
+    if !unanswered.is_empty() {
+        error!(
+            "{} asks for {unanswered:?}, launch it from the window once to answer",
+            program.name()
+        );
+
+        let body = fl!(
+            "launch-unanswered-prompts",
+            program = program.name(),
+            project = project.to_string_lossy().to_string(),
+            prompts = unanswered.join(", ")
+        );
+
+        if let Err(err) = digest::notify(&fl!("launch-skipped"), &body) {
+            error!("failed to notify about the skipped launch: {err}");
+        }
+
+        return None;
+    }
+
+    // asking git costs a process, so only commands using the branch do
+    let git_branch = program
+        .command()
+        .contains(command::GIT_BRANCH_PLACEHOLDER)
+        .then(|| git::status(project)?.branch().map(str::to_string))
+        .flatten();
+
+    Some(command::Values::new(
+        config.project_root_path().cloned(),
+        git_branch,
+        answers.to_vec(),
+    ))
+}
+
+/// Variables of the environment profile the project is launched with.
+fn env_vars<'a>(config: &'a Config, project: &Path) -> &'a [(String, String)] {
+    config
+        .project_env_profile(project)
+        .map(|profile| profile.vars())
+        .unwrap_or_default()
+}
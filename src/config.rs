@@ -1,7 +1,24 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+use crate::app::shortcut::ShortcutOverride;
+use crate::app::view_mode::{AfterLaunch, ClickAction, SortOrder, ViewMode};
 use crate::app::AppModel;
-use crate::domain::program::Program;
+use crate::domain::annotation::{Annotation, ProjectAnnotation};
+use crate::domain::automation::Automation;
+use crate::domain::check::{CheckCommand, CheckKind};
+use crate::domain::digest::Activity;
+use crate::domain::dock::DockPin;
+use crate::domain::env_profile::{EnvProfile, ProjectEnvProfile};
+use crate::domain::history::LaunchRecord;
+use crate::domain::launch::{LaunchMode, LaunchSettings};
+use crate::domain::launch_override::LaunchOverride;
+use crate::domain::program::{DefaultProgram, Program, PromptAnswers};
+use crate::domain::project::DEFAULT_IGNORE_PATTERNS;
+use crate::domain::ranking::ProjectUsage;
+use crate::domain::root_label::RootLabel;
+use crate::domain::rule::Rule;
+use crate::domain::session::Session;
+use crate::domain::trash::TrashedProject;
 use cosmic::{
     cosmic_config::{self, cosmic_config_derive::CosmicConfigEntry, CosmicConfigEntry},
     Application,
@@ -12,7 +29,89 @@ use log::error;
 #[version = 1]
 pub struct Config {
     project_root_path: Option<PathBuf>,
+    /// Names shown instead of the paths of roots, kept for every root that was given one.
+    root_labels: Vec<RootLabel>,
+    /// Directory levels below the root searched for projects, `1` reading only the root itself.
+    discovery_depth: usize,
+    /// Globs of directory names the scan skips, like `node_modules` or `.*`,
+    /// the built-in ones if the list was never edited.
+    ignore_patterns: Option<Vec<String>>,
+    templates_path: Option<PathBuf>,
     programs: Vec<Program>,
+    /// Program projects are launched with by Enter and the launch click action
+    /// unless they were launched before, the first one if unset.
+    default_program: Option<String>,
+    /// Per-project defaults, overriding `default_program`.
+    default_programs: Vec<DefaultProgram>,
+    launch_overrides: Vec<LaunchOverride>,
+    /// Last answers to the prompts of each program, offered again on its next launch and used
+    /// by launches without a window.
+    prompt_answers: Vec<PromptAnswers>,
+    /// Sets of environment variables launches of the projects using them get.
+    env_profiles: Vec<EnvProfile>,
+    /// Profiles chosen per project, by their name.
+    project_env_profiles: Vec<ProjectEnvProfile>,
+    sessions: Vec<Session>,
+    /// Searches kept to bulk launch the projects matching them.
+    saved_searches: Vec<String>,
+    dock_pins: Vec<DockPin>,
+    launch_mode: LaunchMode,
+    after_launch: AfterLaunch,
+    /// Terminal emulator programs and repository tasks run in, detected if unset.
+    terminal: Option<String>,
+    /// Keep the app's working directory for launched programs instead of starting them
+    /// inside the project.
+    start_outside_project: bool,
+    view_mode: ViewMode,
+    list_click_action: ClickAction,
+    grid_click_action: ClickAction,
+    sort_order: SortOrder,
+    /// Share in percent of launch usage in the search ranking, `0` ranking by the match alone.
+    usage_weight: u8,
+    project_usage: Vec<ProjectUsage>,
+    /// Months without changes after which projects are hidden unless all are shown, `0` hiding none.
+    max_project_age: u32,
+    /// Every launch from the app, oldest first.
+    launch_history: Vec<LaunchRecord>,
+    /// Projects in the order they were dragged into, for the manual sort order.
+    manual_order: Vec<PathBuf>,
+    /// Leave the view toggle and sort menu out of the header bar.
+    hide_header_controls: bool,
+    /// Keyboard shortcuts changed from their defaults.
+    shortcuts: Vec<ShortcutOverride>,
+    reduce_motion: bool,
+    color_blind_palette: bool,
+    show_project_icons: bool,
+    /// Show who owns and last committed to each project, useful on shared roots.
+    show_ownership: bool,
+    show_footer: bool,
+    security_audit: bool,
+    /// Keep running background work on battery and metered connections.
+    ignore_power_state: bool,
+    /// Background jobs allowed to run at once, at least one.
+    job_concurrency: usize,
+    lint_checks: Vec<CheckCommand>,
+    health_checks: Vec<CheckCommand>,
+    /// Minutes between scheduled health check runs, `0` to only run them manually.
+    health_check_interval: u64,
+    automations: Vec<Automation>,
+    /// Tags, kinds and display names assigned to matching projects while scanning.
+    rules: Vec<Rule>,
+    /// Personal pins and tags, merged with the shared ones of the root.
+    annotations: Vec<ProjectAnnotation>,
+    hidden_projects: Vec<PathBuf>,
+    /// Months without changes or launches after which projects are suggested for hiding,
+    /// `0` suggesting none.
+    archive_after: u32,
+    /// Projects whose suggestion to hide them was dismissed.
+    dismissed_archive_suggestions: Vec<PathBuf>,
+    /// Projects the app moved to the trash, so they can be restored.
+    trashed_projects: Vec<TrashedProject>,
+    weekly_digest: bool,
+    /// Seconds since the unix epoch the last weekly digest was shown at.
+    last_digest: u64,
+    /// Launches, discoveries and cleanups of the last weeks, summarized by the weekly digest.
+    activity: Vec<Activity>,
 }
 
 impl Config {
@@ -36,7 +135,277 @@ impl Config {
         self.project_root_path.as_ref()
     }
 
+    pub fn root_labels(&self) -> &[RootLabel] {
+        self.root_labels.as_slice()
+    }
+
+    pub fn root_label(&self, root: &Path) -> Option<&str> {
+        self.root_labels
+            .iter()
+            .find(|root_label| root_label.root() == root)
+            .map(|root_label| root_label.label())
+    }
+
+    pub fn discovery_depth(&self) -> usize {
+        self.discovery_depth.max(1)
+    }
+
+    pub fn ignore_patterns(&self) -> Vec<String> {
+        self.ignore_patterns.clone().unwrap_or_else(|| {
+            DEFAULT_IGNORE_PATTERNS
+                .iter()
+                .map(|pattern| pattern.to_string())
+                .collect()
+        })
+    }
+
+    /// Whether the ignore patterns are the built-in ones, not edited in the settings.
+    pub fn has_default_ignore_patterns(&self) -> bool {
+        self.ignore_patterns.is_none()
+    }
+
+    pub fn templates_path(&self) -> Option<&PathBuf> {
+        self.templates_path.as_ref()
+    }
+
     pub fn programs(&self) -> &[Program] {
         self.programs.as_slice()
     }
+
+    pub fn default_program(&self) -> Option<&str> {
+        self.default_program.as_deref()
+    }
+
+    pub fn default_programs(&self) -> &[DefaultProgram] {
+        self.default_programs.as_slice()
+    }
+
+    /// Name of the program chosen as the default of this project alone.
+    pub fn project_default_program(&self, project: &Path) -> Option<&str> {
+        self.default_programs
+            .iter()
+            .find(|default| default.project() == project)
+            .map(|default| default.program())
+    }
+
+    pub fn launch_overrides(&self) -> &[LaunchOverride] {
+        self.launch_overrides.as_slice()
+    }
+
+    pub fn launch_override(&self, project: &Path, program: &str) -> Option<&LaunchOverride> {
+        self.launch_overrides.iter().find(|launch_override| {
+            launch_override.project() == project && launch_override.program() == program
+        })
+    }
+
+    pub fn prompt_answers(&self) -> &[PromptAnswers] {
+        self.prompt_answers.as_slice()
+    }
+
+    /// Last answers to the prompts of the program, empty if it was never launched with them.
+    pub fn program_prompt_answers(&self, program: &str) -> &[(String, String)] {
+        self.prompt_answers
+            .iter()
+            .find(|answers| answers.program() == program)
+            .map(|answers| answers.answers())
+            .unwrap_or_default()
+    }
+
+    pub fn env_profiles(&self) -> &[EnvProfile] {
+        self.env_profiles.as_slice()
+    }
+
+    pub fn project_env_profiles(&self) -> &[ProjectEnvProfile] {
+        self.project_env_profiles.as_slice()
+    }
+
+    /// Profile the project is launched with, `None` if none was chosen or it no longer exists.
+    pub fn project_env_profile(&self, project: &Path) -> Option<&EnvProfile> {
+        let name = self
+            .project_env_profiles
+            .iter()
+            .find(|chosen| chosen.project() == project)?
+            .profile();
+
+        self.env_profiles
+            .iter()
+            .find(|profile| profile.name() == name)
+    }
+
+    pub fn sessions(&self) -> &[Session] {
+        self.sessions.as_slice()
+    }
+
+    pub fn saved_searches(&self) -> &[String] {
+        &self.saved_searches
+    }
+
+    pub fn dock_pins(&self) -> &[DockPin] {
+        self.dock_pins.as_slice()
+    }
+
+    pub fn launch_mode(&self) -> LaunchMode {
+        self.launch_mode
+    }
+
+    pub fn after_launch(&self) -> AfterLaunch {
+        self.after_launch
+    }
+
+    pub fn terminal(&self) -> Option<&str> {
+        self.terminal.as_deref()
+    }
+
+    pub fn start_in_project(&self) -> bool {
+        !self.start_outside_project
+    }
+
+    pub fn launch_settings(&self) -> LaunchSettings {
+        LaunchSettings::new(self.launch_mode, self.terminal(), self.start_in_project())
+    }
+
+    pub fn view_mode(&self) -> ViewMode {
+        self.view_mode
+    }
+
+    pub fn click_action(&self, view_mode: ViewMode) -> ClickAction {
+        match view_mode {
+            ViewMode::List => self.list_click_action,
+            ViewMode::Grid => self.grid_click_action,
+        }
+    }
+
+    pub fn sort_order(&self) -> SortOrder {
+        self.sort_order
+    }
+
+    pub fn usage_weight(&self) -> u8 {
+        self.usage_weight
+    }
+
+    pub fn max_project_age(&self) -> u32 {
+        self.max_project_age
+    }
+
+    pub fn project_usage(&self) -> &[ProjectUsage] {
+        self.project_usage.as_slice()
+    }
+
+    pub fn launch_history(&self) -> &[LaunchRecord] {
+        self.launch_history.as_slice()
+    }
+
+    pub fn manual_order(&self) -> &[PathBuf] {
+        self.manual_order.as_slice()
+    }
+
+    /// Position of the project in the manual order, projects never dragged coming last.
+    pub fn manual_rank(&self, project: &Path) -> usize {
+        self.manual_order
+            .iter()
+            .position(|path| path == project)
+            .unwrap_or(usize::MAX)
+    }
+
+    pub fn hide_header_controls(&self) -> bool {
+        self.hide_header_controls
+    }
+
+    pub fn shortcuts(&self) -> &[ShortcutOverride] {
+        &self.shortcuts
+    }
+
+    pub fn reduce_motion(&self) -> bool {
+        self.reduce_motion
+    }
+
+    pub fn color_blind_palette(&self) -> bool {
+        self.color_blind_palette
+    }
+
+    pub fn show_project_icons(&self) -> bool {
+        self.show_project_icons
+    }
+
+    pub fn show_ownership(&self) -> bool {
+        self.show_ownership
+    }
+
+    pub fn show_footer(&self) -> bool {
+        self.show_footer
+    }
+
+    pub fn security_audit(&self) -> bool {
+        self.security_audit
+    }
+
+    pub fn ignore_power_state(&self) -> bool {
+        self.ignore_power_state
+    }
+
+    pub fn job_concurrency(&self) -> usize {
+        self.job_concurrency.max(1)
+    }
+
+    pub fn health_check_interval(&self) -> u64 {
+        self.health_check_interval
+    }
+
+    pub fn automations(&self) -> &[Automation] {
+        self.automations.as_slice()
+    }
+
+    pub fn rules(&self) -> &[Rule] {
+        self.rules.as_slice()
+    }
+
+    pub fn annotations(&self) -> &[ProjectAnnotation] {
+        self.annotations.as_slice()
+    }
+
+    pub fn annotation(&self, project: &Path) -> Option<&Annotation> {
+        self.annotations
+            .iter()
+            .find(|annotation| annotation.project() == project)
+            .map(|annotation| annotation.annotation())
+    }
+
+    pub fn hidden_projects(&self) -> &[PathBuf] {
+        self.hidden_projects.as_slice()
+    }
+
+    pub fn is_hidden(&self, project: &Path) -> bool {
+        self.hidden_projects.iter().any(|hidden| hidden == project)
+    }
+
+    pub fn archive_after(&self) -> u32 {
+        self.archive_after
+    }
+
+    pub fn dismissed_archive_suggestions(&self) -> &[PathBuf] {
+        self.dismissed_archive_suggestions.as_slice()
+    }
+
+    pub fn trashed_projects(&self) -> &[TrashedProject] {
+        self.trashed_projects.as_slice()
+    }
+
+    pub fn weekly_digest(&self) -> bool {
+        self.weekly_digest
+    }
+
+    pub fn last_digest(&self) -> u64 {
+        self.last_digest
+    }
+
+    pub fn activity(&self) -> &[Activity] {
+        self.activity.as_slice()
+    }
+
+    pub fn checks(&self, kind: CheckKind) -> &[CheckCommand] {
+        match kind {
+            CheckKind::Lint => self.lint_checks.as_slice(),
+            CheckKind::Health => self.health_checks.as_slice(),
+        }
+    }
 }
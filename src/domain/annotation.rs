@@ -0,0 +1,163 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// File in the project root holding the annotations shared by everyone using that root.
+pub const SHARED_FILE: &str = ".project-overview.json";
+
+/// Pins and tags of a project.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Annotation {
+    #[serde(default)]
+    pinned: bool,
+    #[serde(default)]
+    tags: Vec<String>,
+    /// Name shown instead of the folder name.
+    #[serde(default)]
+    name: Option<String>,
+}
+
+impl Annotation {
+    pub fn pinned(&self) -> bool {
+        self.pinned
+    }
+
+    pub fn set_pinned(&mut self, pinned: bool) {
+        self.pinned = pinned;
+    }
+
+    pub fn tags(&self) -> &[String] {
+        self.tags.as_slice()
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|existing| existing == tag)
+    }
+
+    pub fn add_tag(&mut self, tag: String) {
+        if !self.has_tag(&tag) {
+            self.tags.push(tag);
+        }
+    }
+
+    pub fn remove_tag(&mut self, tag: &str) {
+        self.tags.retain(|existing| existing != tag);
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn set_name(&mut self, name: Option<String>) {
+        self.name = name;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.pinned && self.tags.is_empty() && self.name.is_none()
+    }
+
+    /// Combines shared and personal annotations, pinning if either does, keeping the shared tags first
+    /// and preferring the personal name.
+    pub fn merge(shared: &Annotation, personal: &Annotation) -> Annotation {
+        let mut merged = shared.clone();
+        merged.pinned |= personal.pinned;
+
+        if personal.name.is_some() {
+            merged.name = personal.name.clone();
+        }
+
+        for tag in &personal.tags {
+            merged.add_tag(tag.clone());
+        }
+
+        merged
+    }
+}
+
+/// Personal annotation of a project, stored in the config.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ProjectAnnotation {
+    project: PathBuf,
+    annotation: Annotation,
+}
+
+impl ProjectAnnotation {
+    pub fn new(project: PathBuf, annotation: Annotation) -> ProjectAnnotation {
+        ProjectAnnotation {
+            project,
+            annotation,
+        }
+    }
+
+    pub fn project(&self) -> &Path {
+        &self.project
+    }
+
+    pub fn set_project(&mut self, project: PathBuf) {
+        self.project = project;
+    }
+
+    pub fn annotation(&self) -> &Annotation {
+        &self.annotation
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct SharedAnnotations {
+    #[serde(default)]
+    projects: HashMap<String, Annotation>,
+}
+
+/// Reads the shared annotations of a root, keyed by project folder name,
+/// e.g. `{"projects": {"website": {"pinned": true, "tags": ["client"]}}}`.
+///
+/// A root without a shared file has no shared annotations.
+pub fn load_shared(root: &Path) -> Result<HashMap<String, Annotation>, String> {
+    let path = root.join(SHARED_FILE);
+
+    if !path.exists() {
+        return Ok(HashMap::new());
+    }
+
+    let content = std::fs::read_to_string(&path).map_err(|err| err.to_string())?;
+    let shared: SharedAnnotations = serde_json::from_str(&content)
+        .map_err(|err| format!("Failed to parse {:?}: {}", path, err))?;
+
+    Ok(shared.projects)
+}
+
+/// Renames the entries of the shared annotations from the old to the new folder names,
+/// leaving everything else in the file as it is.
+pub fn move_shared(root: &Path, names: &[(String, String)]) -> Result<(), String> {
+    let path = root.join(SHARED_FILE);
+
+    if !path.exists() {
+        return Ok(());
+    }
+
+    let content = std::fs::read_to_string(&path).map_err(|err| err.to_string())?;
+    let mut shared: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|err| format!("Failed to parse {:?}: {}", path, err))?;
+
+    let Some(projects) = shared
+        .get_mut("projects")
+        .and_then(|projects| projects.as_object_mut())
+    else {
+        return Ok(());
+    };
+
+    let mut moved = false;
+    for (old, new) in names {
+        if let Some(annotation) = projects.remove(old) {
+            projects.insert(new.clone(), annotation);
+            moved = true;
+        }
+    }
+
+    if !moved {
+        return Ok(());
+    }
+
+    let content = serde_json::to_string_pretty(&shared).map_err(|err| err.to_string())?;
+    std::fs::write(&path, content).map_err(|err| format!("Failed to write {:?}: {}", path, err))
+}
@@ -0,0 +1,96 @@
+use serde_json::Value;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Vulnerability {
+    package: String,
+    title: String,
+}
+
+impl Vulnerability {
+    pub fn package(&self) -> &str {
+        &self.package
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+}
+
+/// Runs the dependency audit tool matching the project's lock file.
+///
+/// Returns `None` if the project has no supported lock file.
+pub fn run(project: &Path) -> Option<Result<Vec<Vulnerability>, String>> {
+    if project.join("Cargo.lock").is_file() {
+        return Some(cargo_audit(project));
+    }
+
+    if project.join("package-lock.json").is_file() {
+        return Some(npm_audit(project));
+    }
+
+    None
+}
+
+fn cargo_audit(project: &Path) -> Result<Vec<Vulnerability>, String> {
+    let report = json_output(project, "cargo", &["audit", "--json"])?;
+
+    let list = report
+        .pointer("/vulnerabilities/list")
+        .and_then(Value::as_array)
+        .ok_or("unexpected cargo audit output")?;
+
+    Ok(list
+        .iter()
+        .map(|entry| Vulnerability {
+            package: string_at(entry, "/package/name"),
+            title: format!(
+                "{}: {}",
+                string_at(entry, "/advisory/id"),
+                string_at(entry, "/advisory/title")
+            ),
+        })
+        .collect())
+}
+
+fn npm_audit(project: &Path) -> Result<Vec<Vulnerability>, String> {
+    let report = json_output(project, "npm", &["audit", "--json"])?;
+
+    let vulnerabilities = report
+        .get("vulnerabilities")
+        .and_then(Value::as_object)
+        .ok_or("unexpected npm audit output")?;
+
+    Ok(vulnerabilities
+        .iter()
+        .map(|(package, entry)| Vulnerability {
+            package: package.clone(),
+            title: string_at(entry, "/severity"),
+        })
+        .collect())
+}
+
+/// Both tools exit with a failure status when vulnerabilities are found,
+/// so only unparsable output is treated as an error.
+fn json_output(project: &Path, program: &str, args: &[&str]) -> Result<Value, String> {
+    let output = Command::new(program)
+        .args(args)
+        .current_dir(project)
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|err| format!("failed to run {program}: {err}"))?;
+
+    serde_json::from_slice(&output.stdout).map_err(|_| {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        format!("{program} {}: {}", args.join(" "), stderr.trim())
+    })
+}
+
+fn string_at(value: &Value, pointer: &str) -> String {
+    value
+        .pointer(pointer)
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
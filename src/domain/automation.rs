@@ -0,0 +1,69 @@
+use crate::domain::launch;
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::process::{Command, Stdio};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum AutomationEvent {
+    ProjectLaunched,
+    ProjectDiscovered,
+    ScanFinished,
+}
+
+impl AutomationEvent {
+    pub const ALL: [AutomationEvent; 3] = [
+        AutomationEvent::ProjectLaunched,
+        AutomationEvent::ProjectDiscovered,
+        AutomationEvent::ScanFinished,
+    ];
+
+    /// Name passed to scripts in `PROJECT_OVERVIEW_EVENT`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            AutomationEvent::ProjectLaunched => "project-launched",
+            AutomationEvent::ProjectDiscovered => "project-discovered",
+            AutomationEvent::ScanFinished => "scan-finished",
+        }
+    }
+}
+
+/// A shell command run whenever its event happens.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Automation {
+    event: AutomationEvent,
+    command: String,
+}
+
+impl Automation {
+    pub fn new(event: AutomationEvent, command: String) -> Automation {
+        Automation { event, command }
+    }
+
+    pub fn event(&self) -> AutomationEvent {
+        self.event
+    }
+
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+}
+
+/// Spawns the automation without waiting for it, passing the event data as
+/// `PROJECT_OVERVIEW_*` environment variables, e.g. `PROJECT_OVERVIEW_PROJECT_PATH`.
+pub fn run(automation: &Automation, data: &[(&str, String)]) -> io::Result<()> {
+    let mut command = Command::new("sh");
+    command
+        .arg("-c")
+        .arg(&automation.command)
+        .env("PROJECT_OVERVIEW_EVENT", automation.event.name())
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+
+    for (key, value) in data {
+        command.env(format!("PROJECT_OVERVIEW_{key}"), value);
+    }
+
+    launch::reap(command.spawn()?, automation.command.clone(), false);
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use crate::domain::command;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Adds or removes an XDG autostart entry launching the given session on login.
+pub fn set_session_autostart(session: &str, enabled: bool) -> io::Result<()> {
+    let path = entry_path(session)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no config directory available"))?;
+
+    if !enabled {
+        return match fs::remove_file(&path) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        };
+    }
+
+    let exec = std::env::current_exe()?;
+
+    let entry = format!(
+        "[Desktop Entry]\n\
+         Type=Application\n\
+         Name=Project Overview: {}\n\
+         Exec={}\n\
+         NoDisplay=true\n\
+         X-GNOME-Autostart-enabled=true\n",
+        escape(session),
+        escape(&format!(
+            "{} --launch-session {}",
+            quote(&exec.to_string_lossy()),
+            quote(session)
+        )),
+    );
+
+    fs::create_dir_all(path.parent().unwrap_or(&path))?;
+    fs::write(path, entry)
+}
+
+fn entry_path(session: &str) -> Option<PathBuf> {
+    let file_name: String = session
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+
+    Some(
+        dirs::config_dir()?
+            .join("autostart")
+            .join(format!("at.tobinio.ProjectOverview.{file_name}.desktop")),
+    )
+}
+
+/// Escapes a string value of a desktop entry, so line breaks cannot start keys of their own.
+///
+/// `Exec` values are escaped after quoting their arguments.
+pub fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Quotes an argument according to the desktop entry `Exec` key rules, where `%` starts a field code
+/// unless doubled.
+pub fn quote(arg: &str) -> String {
+    command::quote(arg).replace('%', "%%")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_line_breaks() {
+        assert_eq!(escape("api\nExec=rm -rf ~"), "api\\nExec=rm -rf ~");
+        assert_eq!(escape(r"C:\code\tab"), r"C:\\code\\tab");
+    }
+
+    #[test]
+    fn escapes_quoted_backslashes_twice() {
+        assert_eq!(escape(&quote(r"a\b")), r#""a\\\\b""#);
+        assert_eq!(quote("100%"), r#""100%%""#);
+    }
+}
@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Output kept per check, counted from the end where errors usually are.
+const MAX_OUTPUT_LEN: usize = 4096;
+
+/// The purpose a list of checks is configured for.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum CheckKind {
+    /// Run automatically in the background, flagging projects that would fail CI.
+    Lint,
+    /// Run manually or on a schedule to tell whether a project still builds and passes its tests.
+    Health,
+}
+
+/// A shell command run in every project containing the marker file, e.g. `Cargo.toml`.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CheckCommand {
+    marker: String,
+    command: String,
+}
+
+impl CheckCommand {
+    pub fn new(marker: String, command: String) -> CheckCommand {
+        CheckCommand { marker, command }
+    }
+
+    pub fn marker(&self) -> &str {
+        &self.marker
+    }
+
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+
+    pub fn applies_to(&self, project: &Path) -> bool {
+        project.join(&self.marker).exists()
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CheckResult {
+    command: String,
+    success: bool,
+    output: String,
+}
+
+impl CheckResult {
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+
+    pub fn success(&self) -> bool {
+        self.success
+    }
+
+    pub fn output(&self) -> &str {
+        &self.output
+    }
+}
+
+/// Runs every applicable check inside the project, returning an empty list if none applies.
+/// Once `cancelled` is set, the remaining checks are skipped.
+pub fn run(project: &Path, checks: &[CheckCommand], cancelled: &AtomicBool) -> Vec<CheckResult> {
+    checks
+        .iter()
+        .filter(|check| check.applies_to(project))
+        .take_while(|_| !cancelled.load(Ordering::Relaxed))
+        .map(|check| run_check(project, check))
+        .collect()
+}
+
+pub fn all_passed(results: &[CheckResult]) -> bool {
+    results.iter().all(|result| result.success)
+}
+
+fn run_check(project: &Path, check: &CheckCommand) -> CheckResult {
+    let output = Command::new("sh")
+        .arg("-c")
+        .arg(&check.command)
+        .current_dir(project)
+        .stdin(Stdio::null())
+        .output();
+
+    let (success, output) = match output {
+        Ok(output) => {
+            let mut text = String::from_utf8_lossy(&output.stdout).to_string();
+            text.push_str(&String::from_utf8_lossy(&output.stderr));
+            (output.status.success(), text)
+        }
+        Err(err) => (false, err.to_string()),
+    };
+
+    let start = output
+        .char_indices()
+        .rev()
+        .nth(MAX_OUTPUT_LEN)
+        .map(|(index, _)| index)
+        .unwrap_or_default();
+
+    CheckResult {
+        command: check.command.clone(),
+        success,
+        output: output[start..].trim().to_string(),
+    }
+}
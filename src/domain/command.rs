@@ -0,0 +1,484 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Placeholder of program commands replaced by the project path.
+pub const PATH_PLACEHOLDER: &str = "%path%";
+/// Placeholder replaced by the folder name of the project.
+pub const NAME_PLACEHOLDER: &str = "%name%";
+/// Placeholder replaced by the directory containing the project.
+pub const PARENT_PLACEHOLDER: &str = "%parent%";
+/// Placeholder replaced by the checked out branch of the project's repository.
+pub const GIT_BRANCH_PLACEHOLDER: &str = "%git_branch%";
+/// Placeholder replaced by the root the projects are found in.
+pub const ROOT_PLACEHOLDER: &str = "%root%";
+
+/// Placeholders referring to the launched project, one of which every command needs.
+pub const PROJECT_PLACEHOLDERS: [&str; 4] = [
+    PATH_PLACEHOLDER,
+    NAME_PLACEHOLDER,
+    PARENT_PLACEHOLDER,
+    GIT_BRANCH_PLACEHOLDER,
+];
+
+/// Start of the `%prompt:Label%` placeholders, whose values are asked for at launch.
+const PROMPT_PREFIX: &str = "%prompt:";
+
+/// Splits a command into arguments the way a POSIX shell does.
+///
+/// Single quotes keep everything literal, double quotes only let `\` escape `"`, `\`, `$` and `` ` ``,
+/// and outside of quotes `\` escapes any character.
+pub fn split(command: &str) -> io::Result<Vec<String>> {
+    let mut args = vec![];
+    let mut current = String::new();
+    let mut in_arg = false;
+    let mut chars = command.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                in_arg = true;
+
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => current.push(c),
+                        None => return Err(invalid("unclosed single quote")),
+                    }
+                }
+            }
+            '"' => {
+                in_arg = true;
+
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(c @ ('"' | '\\' | '$' | '`')) => current.push(c),
+                            Some('\n') => {}
+                            Some(c) => {
+                                current.push('\\');
+                                current.push(c);
+                            }
+                            None => return Err(invalid("unclosed double quote")),
+                        },
+                        Some(c) => current.push(c),
+                        None => return Err(invalid("unclosed double quote")),
+                    }
+                }
+            }
+            '\\' => match chars.next() {
+                Some('\n') => {}
+                Some(c) => {
+                    current.push(c);
+                    in_arg = true;
+                }
+                None => return Err(invalid("trailing backslash")),
+            },
+            c if c.is_whitespace() => {
+                if in_arg {
+                    args.push(std::mem::take(&mut current));
+                    in_arg = false;
+                }
+            }
+            c => {
+                current.push(c);
+                in_arg = true;
+            }
+        }
+    }
+
+    if in_arg {
+        args.push(current);
+    }
+
+    Ok(args)
+}
+
+/// Double quotes an argument so [`split`] reads it back unchanged.
+pub fn quote(arg: &str) -> String {
+    let mut quoted = String::from("\"");
+
+    for c in arg.chars() {
+        if matches!(c, '"' | '`' | '$' | '\\') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+
+    quoted.push('"');
+    quoted
+}
+
+/// Labels of the `%prompt:Label%` placeholders of the command, in order and without repeats.
+pub fn prompts(command: &str) -> Vec<String> {
+    let mut labels: Vec<String> = vec![];
+    let mut rest = command;
+
+    while let Some(start) = rest.find(PROMPT_PREFIX) {
+        let after = &rest[start + PROMPT_PREFIX.len()..];
+
+        let Some(end) = after.find('%') else {
+            break;
+        };
+
+        let label = &after[..end];
+
+        if !labels.iter().any(|existing| existing == label) {
+            labels.push(label.to_string());
+        }
+
+        rest = &after[end + 1..];
+    }
+
+    labels
+}
+
+/// Placeholders of the command, without their `%`, that are neither known nor prompts.
+pub fn unknown_placeholders(command: &str) -> Vec<String> {
+    let mut unknown = vec![];
+    let mut rest = command;
+
+    while let Some(start) = rest.find('%') {
+        let after = &rest[start + 1..];
+
+        let Some(end) = after.find('%') else {
+            break;
+        };
+
+        let name = &after[..end];
+
+        if name.starts_with(&PROMPT_PREFIX[1..]) {
+            rest = &after[end + 1..];
+        } else if !name.is_empty() && name.chars().all(|c| c.is_ascii_lowercase() || c == '_') {
+            let placeholder = format!("%{name}%");
+
+            if !PROJECT_PLACEHOLDERS.contains(&placeholder.as_str())
+                && placeholder != ROOT_PLACEHOLDER
+            {
+                unknown.push(name.to_string());
+            }
+
+            rest = &after[end + 1..];
+        } else {
+            // a literal `%`, the next one may still open a placeholder
+            rest = after;
+        }
+    }
+
+    unknown
+}
+
+/// Values of the placeholders that cannot be told from the project path alone.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Values {
+    root: Option<PathBuf>,
+    git_branch: Option<String>,
+    answers: Vec<(String, String)>,
+}
+
+impl Values {
+    /// `git_branch` is the checked out branch of the project, looked up by the caller as asking
+    /// git costs a process. `answers` hold the values of the prompts by their label.
+    pub fn new(
+        root: Option<PathBuf>,
+        git_branch: Option<String>,
+        answers: Vec<(String, String)>,
+    ) -> Values {
+        Values {
+            root,
+            git_branch,
+            answers,
+        }
+    }
+
+    pub fn root(&self) -> Option<&Path> {
+        self.root.as_deref()
+    }
+
+    pub fn git_branch(&self) -> Option<&str> {
+        self.git_branch.as_deref()
+    }
+
+    pub fn answers(&self) -> &[(String, String)] {
+        self.answers.as_slice()
+    }
+}
+
+/// Arguments of `command` with every `%path%` replaced by `target`, the other project
+/// placeholders by what they stand for in `project` and every `%prompt:Label%` by the answer
+/// given for its label.
+///
+/// The command is split before substituting, so the path and answers are quoted automatically
+/// and never split into several arguments, whatever characters they contain. Each argument is
+/// substituted in a single pass, so placeholders within the substituted values stay as they are,
+/// just like unknown placeholders.
+pub fn expand(
+    command: &str,
+    project: &Path,
+    target: &Path,
+    values: &Values,
+) -> io::Result<Vec<String>> {
+    let target = target.to_string_lossy();
+    let name = project
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+    let parent = project
+        .parent()
+        .map(|parent| parent.to_string_lossy())
+        .unwrap_or_default();
+
+    let value = |placeholder: &str| -> io::Result<Option<String>> {
+        if let Some(label) = placeholder
+            .strip_prefix(PROMPT_PREFIX)
+            .and_then(|rest| rest.strip_suffix('%'))
+        {
+            return values
+                .answers()
+                .iter()
+                .find(|(answered, _)| answered == label)
+                .map(|(_, answer)| Some(answer.clone()))
+                .ok_or_else(|| invalid(&format!("no value given for {label:?}")));
+        }
+
+        Ok(Some(match placeholder {
+            PATH_PLACEHOLDER => target.to_string(),
+            NAME_PLACEHOLDER => name.to_string(),
+            PARENT_PLACEHOLDER => parent.to_string(),
+            GIT_BRANCH_PLACEHOLDER => values
+                .git_branch()
+                .ok_or_else(|| invalid("the project has no checked out git branch"))?
+                .to_string(),
+            ROOT_PLACEHOLDER => values
+                .root()
+                .ok_or_else(|| invalid("no project root is set"))?
+                .to_string_lossy()
+                .into_owned(),
+            _ => return Ok(None),
+        }))
+    };
+
+    split(command)?
+        .into_iter()
+        .map(|arg| substitute(&arg, &value))
+        .collect()
+}
+
+/// Replaces every `%…%` of `arg` that `value` knows, leaving the others as written.
+fn substitute(
+    arg: &str,
+    value: &impl Fn(&str) -> io::Result<Option<String>>,
+) -> io::Result<String> {
+    let mut substituted = String::new();
+    let mut rest = arg;
+
+    while let Some(start) = rest.find('%') {
+        substituted.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+
+        let Some(end) = after.find('%') else {
+            rest = &rest[start..];
+            break;
+        };
+
+        let placeholder = &rest[start..start + end + 2];
+
+        match value(placeholder)? {
+            Some(value) => {
+                substituted.push_str(&value);
+                rest = &after[end + 1..];
+            }
+            None => {
+                // a literal `%`, the next one may still open a placeholder
+                substituted.push('%');
+                rest = after;
+            }
+        }
+    }
+
+    substituted.push_str(rest);
+    Ok(substituted)
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(command: &str) -> Vec<String> {
+        split(command).unwrap()
+    }
+
+    #[test]
+    fn splits_on_whitespace() {
+        assert_eq!(
+            args("code  --new-window\t%path%"),
+            ["code", "--new-window", "%path%"]
+        );
+        assert!(args("   ").is_empty());
+    }
+
+    #[test]
+    fn keeps_quoted_arguments_together() {
+        assert_eq!(args(r#"sh -c "echo 'a b'""#), ["sh", "-c", "echo 'a b'"]);
+        assert_eq!(args(r#"echo 'say "hi"'"#), ["echo", r#"say "hi""#]);
+        assert_eq!(args(r#"--name="a b"c"#), ["--name=a bc"]);
+        assert_eq!(args("echo '' \"\""), ["echo", "", ""]);
+    }
+
+    #[test]
+    fn handles_escapes() {
+        assert_eq!(args(r"open a\ b"), ["open", "a b"]);
+        assert_eq!(args(r#"echo "\"\$HOME\" \n""#), ["echo", r#""$HOME" \n"#]);
+        assert_eq!(args(r"echo 'a\b'"), ["echo", r"a\b"]);
+    }
+
+    #[test]
+    fn rejects_unbalanced_commands() {
+        assert!(split("echo 'a").is_err());
+        assert!(split(r#"echo "a"#).is_err());
+        assert!(split(r"echo a\").is_err());
+    }
+
+    #[test]
+    fn substitutes_path_as_single_argument() {
+        let path = Path::new("/home/me/my \"odd\" project's");
+
+        assert_eq!(
+            expand("code %path%", path, path, &Values::default()).unwrap(),
+            ["code", "/home/me/my \"odd\" project's"]
+        );
+        assert_eq!(
+            expand(
+                r#"kitty --directory="%path%" nvim"#,
+                path,
+                path,
+                &Values::default()
+            )
+            .unwrap(),
+            ["kitty", "--directory=/home/me/my \"odd\" project's", "nvim"]
+        );
+    }
+
+    #[test]
+    fn lists_prompts_once() {
+        assert_eq!(
+            prompts("serve %path% --port %prompt:Port% --env=%prompt:Environment% %prompt:Port%"),
+            ["Port", "Environment"]
+        );
+        assert!(prompts("code %path% 100%").is_empty());
+    }
+
+    #[test]
+    fn substitutes_prompt_answers() {
+        let values = Values::new(
+            None,
+            None,
+            vec![
+                ("Port".to_string(), "8080".to_string()),
+                ("Environment".to_string(), "staging area".to_string()),
+            ],
+        );
+        let path = Path::new("/p");
+
+        assert_eq!(
+            expand(
+                "serve %path% --port=%prompt:Port% %prompt:Environment%",
+                path,
+                path,
+                &values
+            )
+            .unwrap(),
+            ["serve", "/p", "--port=8080", "staging area"]
+        );
+        assert!(expand("serve %prompt:Port%", path, path, &Values::default()).is_err());
+    }
+
+    #[test]
+    fn substitutes_project_placeholders() {
+        let values = Values::new(
+            Some(PathBuf::from("/home/me/code")),
+            Some("main".to_string()),
+            vec![],
+        );
+
+        assert_eq!(
+            expand(
+                "tmux new -s %name%-%git_branch% -c %path% --root=%root% %parent%",
+                Path::new("/home/me/code/app"),
+                Path::new("/home/me/code/app/web"),
+                &values
+            )
+            .unwrap(),
+            [
+                "tmux",
+                "new",
+                "-s",
+                "app-main",
+                "-c",
+                "/home/me/code/app/web",
+                "--root=/home/me/code",
+                "/home/me/code"
+            ]
+        );
+        assert!(expand(
+            "ls %root%",
+            Path::new("/p"),
+            Path::new("/p"),
+            &Values::default()
+        )
+        .is_err());
+        assert!(expand(
+            "git log %git_branch% %path%",
+            Path::new("/p"),
+            Path::new("/p"),
+            &Values::default()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn substitutes_in_a_single_pass() {
+        let values = Values::new(
+            None,
+            None,
+            vec![("Query".to_string(), "%path%".to_string())],
+        );
+        let path = Path::new("/code/%name%");
+
+        assert_eq!(
+            expand(
+                "grep %prompt:Query% %path% --label=%name% 100%done%",
+                path,
+                path,
+                &values
+            )
+            .unwrap(),
+            [
+                "grep",
+                "%path%",
+                "/code/%name%",
+                "--label=%name%",
+                "100%done%"
+            ]
+        );
+    }
+
+    #[test]
+    fn quotes_for_split() {
+        let arg = r#"say "hi" to $USER \ `me`"#;
+        assert_eq!(args(&quote(arg)), [arg]);
+    }
+
+    #[test]
+    fn finds_unknown_placeholders() {
+        assert_eq!(
+            unknown_placeholders("code %path% %nmae% %prompt:Port% 100%"),
+            ["nmae"]
+        );
+        assert!(unknown_placeholders("open %parent% %git_branch% %root% 100%").is_empty());
+    }
+}
@@ -0,0 +1,113 @@
+use ignore::WalkBuilder;
+use std::fs;
+use std::path::PathBuf;
+
+/// Files larger than this are assumed to be generated or binary and are skipped.
+const MAX_FILE_SIZE: u64 = 1024 * 1024;
+/// Number of matches kept per project, further hits are only counted.
+const MAX_MATCHES_PER_PROJECT: usize = 20;
+
+#[derive(Debug, Clone)]
+pub struct ContentMatch {
+    path: PathBuf,
+    line: usize,
+    text: String,
+}
+
+impl ContentMatch {
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ProjectMatches {
+    project: PathBuf,
+    hits: usize,
+    matches: Vec<ContentMatch>,
+}
+
+impl ProjectMatches {
+    pub fn project(&self) -> &PathBuf {
+        &self.project
+    }
+
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    pub fn matches(&self) -> &[ContentMatch] {
+        self.matches.as_slice()
+    }
+}
+
+/// Searches the contents of every file in the given projects, respecting `.gitignore` files.
+///
+/// Matching is case-insensitive. Projects without any hit are omitted and the
+/// result is sorted by hit count.
+pub fn search(projects: Vec<PathBuf>, query: &str) -> Vec<ProjectMatches> {
+    let query = query.to_lowercase();
+
+    let mut results: Vec<ProjectMatches> = projects
+        .into_iter()
+        .map(|project| search_project(project, &query))
+        .filter(|result| result.hits > 0)
+        .collect();
+
+    results.sort_by(|a, b| b.hits.cmp(&a.hits));
+    results
+}
+
+fn search_project(project: PathBuf, query: &str) -> ProjectMatches {
+    let mut result = ProjectMatches {
+        project: project.clone(),
+        hits: 0,
+        matches: vec![],
+    };
+
+    let files = WalkBuilder::new(&project)
+        .build()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            entry
+                .file_type()
+                .is_some_and(|file_type| file_type.is_file())
+        })
+        .filter(|entry| {
+            entry
+                .metadata()
+                .is_ok_and(|metadata| metadata.len() <= MAX_FILE_SIZE)
+        });
+
+    for file in files {
+        let Ok(content) = fs::read_to_string(file.path()) else {
+            continue;
+        };
+
+        for (index, line) in content.lines().enumerate() {
+            if !line.to_lowercase().contains(query) {
+                continue;
+            }
+
+            result.hits += 1;
+
+            if result.matches.len() < MAX_MATCHES_PER_PROJECT {
+                result.matches.push(ContentMatch {
+                    path: file.path().to_path_buf(),
+                    line: index + 1,
+                    text: line.trim().to_string(),
+                });
+            }
+        }
+    }
+
+    result
+}
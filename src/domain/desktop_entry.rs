@@ -0,0 +1,518 @@
+use crate::domain::{command, trash};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// How long [`find`] reuses the entries read last, so checking every program reads them once.
+const CACHE_DURATION: Duration = Duration::from_secs(30);
+
+/// Entries by id, with the time they were read.
+static CACHE: Mutex<Option<(Instant, HashMap<String, DesktopEntry>)>> = Mutex::new(None);
+
+/// An installed application, read from its `.desktop` file.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DesktopEntry {
+    id: String,
+    name: String,
+    exec: String,
+    icon: Option<String>,
+    path: PathBuf,
+}
+
+impl DesktopEntry {
+    /// Desktop file id, e.g. `org.gnome.gedit.desktop`.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Icon name or path of the `Icon` key.
+    pub fn icon(&self) -> Option<&str> {
+        self.icon.as_deref()
+    }
+
+    /// Arguments launching the application for `path`, expanding the field codes of `Exec`.
+    ///
+    /// Applications without a file or URL field code get the path appended.
+    pub fn command(&self, path: &Path) -> Vec<String> {
+        let url = format!("file://{}", trash::encode_path(path));
+        let mut takes_file = false;
+        let mut args = vec![];
+
+        for arg in split_exec(&self.exec) {
+            if arg == "%i" {
+                if let Some(icon) = &self.icon {
+                    args.push("--icon".to_string());
+                    args.push(icon.to_string());
+                }
+                continue;
+            }
+
+            let mut expanded = String::new();
+            let mut chars = arg.chars();
+
+            while let Some(c) = chars.next() {
+                if c != '%' {
+                    expanded.push(c);
+                    continue;
+                }
+
+                match chars.next() {
+                    Some('f' | 'F') => {
+                        takes_file = true;
+                        expanded.push_str(&path.to_string_lossy());
+                    }
+                    Some('u' | 'U') => {
+                        takes_file = true;
+                        expanded.push_str(&url);
+                    }
+                    Some('c') => expanded.push_str(&self.name),
+                    Some('k') => expanded.push_str(&self.path.to_string_lossy()),
+                    Some('%') => expanded.push('%'),
+                    // deprecated and unknown field codes expand to nothing
+                    _ => {}
+                }
+            }
+
+            if !expanded.is_empty() || !arg.starts_with('%') {
+                args.push(expanded);
+            }
+        }
+
+        if !takes_file {
+            args.push(path.to_string_lossy().to_string());
+        }
+
+        args
+    }
+
+    /// `Exec` as a program command, with the file and URL field codes replaced by
+    /// [`command::PATH_PLACEHOLDER`].
+    ///
+    /// Like [`DesktopEntry::command`], the placeholder is appended without such a field code.
+    pub fn path_command(&self) -> String {
+        let mut takes_file = false;
+        let mut args = vec![];
+
+        for arg in split_exec(&self.exec) {
+            let mut converted = String::new();
+            let mut chars = arg.chars();
+
+            while let Some(c) = chars.next() {
+                if c != '%' {
+                    converted.push(c);
+                    continue;
+                }
+
+                match chars.next() {
+                    Some('f' | 'F' | 'u' | 'U') => {
+                        takes_file = true;
+                        converted.push_str(command::PATH_PLACEHOLDER);
+                    }
+                    Some('c') => converted.push_str(&self.name),
+                    Some('%') => converted.push('%'),
+                    // icon and location codes only make sense when launching through the entry
+                    _ => {}
+                }
+            }
+
+            if converted.is_empty() && arg.starts_with('%') {
+                continue;
+            }
+
+            let plain = !converted.is_empty()
+                && converted
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "-_./:=%+,@".contains(c));
+
+            args.push(if plain {
+                converted
+            } else {
+                command::quote(&converted)
+            });
+        }
+
+        if !takes_file {
+            args.push(command::PATH_PLACEHOLDER.to_string());
+        }
+
+        args.join(" ")
+    }
+}
+
+/// Applications listed in the XDG data directories, sorted by name.
+///
+/// Entries hidden from menus are skipped, as are later duplicates of an id.
+/// Always reads the directories, refreshing the entries [`find`] looks up.
+pub fn installed() -> Vec<DesktopEntry> {
+    let entries = read_installed();
+    let by_id = entries
+        .iter()
+        .map(|entry| (entry.id.clone(), entry.clone()))
+        .collect();
+
+    *CACHE.lock().unwrap_or_else(PoisonError::into_inner) = Some((Instant::now(), by_id));
+
+    entries
+}
+
+/// Installed application with the desktop file id, reading the directories again only
+/// once the entries read last are older than [`CACHE_DURATION`].
+pub fn find(id: &str) -> Option<DesktopEntry> {
+    let mut cache = CACHE.lock().unwrap_or_else(PoisonError::into_inner);
+
+    if !cache
+        .as_ref()
+        .is_some_and(|(read, _)| read.elapsed() < CACHE_DURATION)
+    {
+        let by_id = read_installed()
+            .into_iter()
+            .map(|entry| (entry.id.clone(), entry))
+            .collect();
+
+        *cache = Some((Instant::now(), by_id));
+    }
+
+    cache.as_ref()?.1.get(id).cloned()
+}
+
+fn read_installed() -> Vec<DesktopEntry> {
+    let mut ids = HashSet::new();
+    let mut entries = vec![];
+
+    for dir in application_dirs() {
+        for entry in read_dir(&dir, &dir) {
+            if ids.insert(entry.id.clone()) {
+                entries.push(entry);
+            }
+        }
+    }
+
+    entries.retain(|entry| !entry.exec.is_empty());
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    entries
+}
+
+/// MIME type of directories, whose default application is the fallback for projects.
+pub const DIRECTORY_MIME_TYPE: &str = "inode/directory";
+
+/// Applications `mimeapps.list` makes the default for each of the MIME types,
+/// leaving out types without an installed default.
+pub fn default_handlers(
+    mime_types: &[&str],
+    entries: &[DesktopEntry],
+) -> HashMap<String, DesktopEntry> {
+    let mut handlers = HashMap::new();
+
+    // earlier files take precedence, like the user's config over the system's
+    for path in mimeapps_lists() {
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+
+        let mut in_group = false;
+
+        for line in content.lines().map(str::trim) {
+            if line.starts_with('[') {
+                in_group = line == "[Default Applications]";
+                continue;
+            }
+
+            let Some((mime_type, ids)) = line.split_once('=').filter(|_| in_group) else {
+                continue;
+            };
+
+            let mime_type = mime_type.trim();
+
+            if !mime_types.contains(&mime_type) || handlers.contains_key(mime_type) {
+                continue;
+            }
+
+            let entry = ids
+                .split(';')
+                .map(str::trim)
+                .find_map(|id| entries.iter().find(|entry| entry.id == id));
+
+            if let Some(entry) = entry {
+                handlers.insert(mime_type.to_string(), entry.clone());
+            }
+        }
+    }
+
+    handlers
+}
+
+fn mimeapps_lists() -> Vec<PathBuf> {
+    let mut dirs = vec![];
+
+    if let Some(config_dir) = dirs::config_dir() {
+        dirs.push(config_dir);
+    }
+
+    let config_dirs = std::env::var("XDG_CONFIG_DIRS")
+        .ok()
+        .filter(|config_dirs| !config_dirs.is_empty())
+        .unwrap_or_else(|| "/etc/xdg".to_string());
+
+    dirs.extend(std::env::split_paths(&config_dirs));
+    dirs.extend(application_dirs());
+
+    // within each directory, the lists of the current desktops come before the generic one
+    let file_names = mimeapps_file_names(
+        std::env::var("XDG_CURRENT_DESKTOP")
+            .unwrap_or_default()
+            .as_str(),
+    );
+
+    dirs.into_iter()
+        .flat_map(|dir| file_names.iter().map(move |name| dir.join(name)))
+        .collect()
+}
+
+fn mimeapps_file_names(current_desktop: &str) -> Vec<String> {
+    current_desktop
+        .split(':')
+        .filter(|desktop| !desktop.is_empty())
+        .map(|desktop| format!("{}-mimeapps.list", desktop.to_lowercase()))
+        .chain(std::iter::once("mimeapps.list".to_string()))
+        .collect()
+}
+
+fn application_dirs() -> Vec<PathBuf> {
+    let mut dirs = vec![];
+
+    if let Some(data_dir) = dirs::data_dir() {
+        dirs.push(data_dir);
+    }
+
+    let data_dirs = std::env::var("XDG_DATA_DIRS")
+        .ok()
+        .filter(|data_dirs| !data_dirs.is_empty())
+        .unwrap_or_else(|| "/usr/local/share:/usr/share".to_string());
+
+    dirs.extend(std::env::split_paths(&data_dirs));
+
+    dirs.into_iter()
+        .map(|dir| dir.join("applications"))
+        .collect()
+}
+
+fn read_dir(base: &Path, dir: &Path) -> Vec<DesktopEntry> {
+    let Ok(read_dir) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut entries = vec![];
+
+    for path in read_dir.flatten().map(|entry| entry.path()) {
+        if path.is_dir() {
+            entries.extend(read_dir(base, &path));
+        } else if path
+            .extension()
+            .is_some_and(|extension| extension == "desktop")
+        {
+            entries.extend(parse(base, &path));
+        }
+    }
+
+    entries
+}
+
+/// Reads the `[Desktop Entry]` group, ignoring non-applications and hidden entries.
+fn parse(base: &Path, path: &Path) -> Option<DesktopEntry> {
+    let content = fs::read_to_string(path).ok()?;
+
+    let mut in_group = false;
+    let mut name = None;
+    let mut exec = None;
+    let mut icon = None;
+    let mut application = false;
+
+    for line in content.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_group = line == "[Desktop Entry]";
+            continue;
+        }
+
+        if !in_group {
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+
+        let value = unescape(value.trim());
+
+        match key.trim() {
+            "Type" => application = value == "Application",
+            "Name" => name = Some(value),
+            "Exec" => exec = Some(value),
+            "Icon" => icon = Some(value),
+            "NoDisplay" | "Hidden" if value == "true" => return None,
+            _ => {}
+        }
+    }
+
+    if !application {
+        return None;
+    }
+
+    // subdirectories become part of the id, e.g. `kde/konsole.desktop` is `kde-konsole.desktop`
+    let id = path
+        .strip_prefix(base)
+        .ok()?
+        .to_string_lossy()
+        .replace('/', "-");
+
+    Some(DesktopEntry {
+        id,
+        name: name?,
+        exec: exec?,
+        icon,
+        path: path.to_path_buf(),
+    })
+}
+
+/// Resolves the escapes of desktop entry string values, e.g. `\s` for a space.
+fn unescape(value: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('s') => unescaped.push(' '),
+            Some('n') => unescaped.push('\n'),
+            Some('t') => unescaped.push('\t'),
+            Some('r') => unescaped.push('\r'),
+            Some('\\') => unescaped.push('\\'),
+            Some(c) => {
+                unescaped.push('\\');
+                unescaped.push(c);
+            }
+            None => unescaped.push('\\'),
+        }
+    }
+
+    unescaped
+}
+
+/// Splits `Exec` into arguments, honouring the double quoted ones and their escapes.
+fn split_exec(exec: &str) -> Vec<String> {
+    let mut args = vec![];
+    let mut current = String::new();
+    let mut in_arg = false;
+    let mut quoted = false;
+    let mut chars = exec.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                quoted = !quoted;
+                in_arg = true;
+            }
+            '\\' if quoted => {
+                if let Some(escaped) = chars.next() {
+                    current.push(escaped);
+                }
+            }
+            c if c.is_whitespace() && !quoted => {
+                if in_arg {
+                    args.push(std::mem::take(&mut current));
+                    in_arg = false;
+                }
+            }
+            c => {
+                current.push(c);
+                in_arg = true;
+            }
+        }
+    }
+
+    if in_arg {
+        args.push(current);
+    }
+
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(exec: &str) -> DesktopEntry {
+        DesktopEntry {
+            id: "org.example.Editor.desktop".to_string(),
+            name: "Text Editor".to_string(),
+            exec: exec.to_string(),
+            icon: Some("accessories-text-editor".to_string()),
+            path: PathBuf::from("/usr/share/applications/org.example.Editor.desktop"),
+        }
+    }
+
+    #[test]
+    fn splits_quoted_exec_arguments() {
+        assert_eq!(
+            split_exec(r#""/opt/my editor/bin" --title "say \"hi\"" %F"#),
+            ["/opt/my editor/bin", "--title", r#"say "hi""#, "%F"]
+        );
+        assert_eq!(split_exec(r#"app "" x"#), ["app", "", "x"]);
+    }
+
+    #[test]
+    fn expands_field_codes() {
+        let path = Path::new("/code/my api");
+
+        assert_eq!(
+            entry("editor --new-window %F").command(path),
+            ["editor", "--new-window", "/code/my api"]
+        );
+        assert_eq!(
+            entry("browser %u").command(path),
+            ["browser", "file:///code/my%20api"]
+        );
+        assert_eq!(
+            entry("editor %i --name=%c %d 100%%").command(path),
+            [
+                "editor",
+                "--icon",
+                "accessories-text-editor",
+                "--name=Text Editor",
+                "100%",
+                "/code/my api"
+            ]
+        );
+    }
+
+    #[test]
+    fn converts_exec_to_path_commands() {
+        assert_eq!(entry("editor %F").path_command(), "editor %path%");
+        assert_eq!(
+            entry("editor --reuse").path_command(),
+            "editor --reuse %path%"
+        );
+        assert_eq!(
+            entry(r#""/opt/my editor/bin" --title %c %i %U"#).path_command(),
+            r#""/opt/my editor/bin" --title "Text Editor" %path%"#
+        );
+        assert_eq!(
+            entry("editor --open=%f").path_command(),
+            "editor --open=%path%"
+        );
+    }
+
+    #[test]
+    fn unescapes_string_values() {
+        assert_eq!(unescape(r"My\sApp\\x\q"), r"My App\x\q");
+    }
+}
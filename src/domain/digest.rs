@@ -0,0 +1,156 @@
+use crate::domain::history::LaunchRecord;
+use crate::domain::launch;
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const WEEK: u64 = 7 * 24 * 60 * 60;
+/// How long activity is remembered, enough for this and last week's digest.
+pub const RETENTION: u64 = 2 * WEEK;
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ActivityKind {
+    /// Only found in activity recorded before launches were counted from the launch history.
+    Launched(PathBuf),
+    Discovered(PathBuf),
+    /// Bytes freed in the artifact folders of a project.
+    Cleaned(PathBuf, u64),
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Activity {
+    /// Seconds since the unix epoch.
+    time: u64,
+    kind: ActivityKind,
+}
+
+impl Activity {
+    pub fn new(kind: ActivityKind) -> Activity {
+        Activity { time: now(), kind }
+    }
+
+    pub fn time(&self) -> u64 {
+        self.time
+    }
+
+    pub fn kind(&self) -> &ActivityKind {
+        &self.kind
+    }
+}
+
+/// What happened to the projects in a week.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct Digest {
+    touched: usize,
+    launches: usize,
+    new_projects: usize,
+    cleaned: u64,
+}
+
+impl Digest {
+    /// Summarizes the week starting at `since`, counting projects modified in it as touched.
+    pub fn collect(
+        activity: &[Activity],
+        launches: &[LaunchRecord],
+        modified: impl IntoIterator<Item = SystemTime>,
+        since: u64,
+    ) -> Digest {
+        let until = since + WEEK;
+        let in_week = |time: u64| (since..until).contains(&time);
+
+        let mut digest = Digest {
+            touched: modified
+                .into_iter()
+                .filter(|modify| in_week(seconds(*modify)))
+                .count(),
+            launches: launches
+                .iter()
+                .filter(|launch| in_week(launch.time()))
+                .count(),
+            ..Digest::default()
+        };
+
+        for activity in activity.iter().filter(|activity| in_week(activity.time)) {
+            match activity.kind {
+                ActivityKind::Launched(_) => {}
+                ActivityKind::Discovered(_) => digest.new_projects += 1,
+                ActivityKind::Cleaned(_, bytes) => digest.cleaned += bytes,
+            }
+        }
+
+        digest
+    }
+
+    pub fn touched(&self) -> usize {
+        self.touched
+    }
+
+    pub fn launches(&self) -> usize {
+        self.launches
+    }
+
+    pub fn new_projects(&self) -> usize {
+        self.new_projects
+    }
+
+    pub fn cleaned(&self) -> u64 {
+        self.cleaned
+    }
+}
+
+/// Seconds since the unix epoch.
+pub fn now() -> u64 {
+    seconds(SystemTime::now())
+}
+
+fn seconds(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+/// Shows a desktop notification through `notify-send`.
+pub fn notify(summary: &str, body: &str) -> io::Result<()> {
+    let child = Command::new("notify-send")
+        .arg("--app-name=Project Overview")
+        .arg(summary)
+        .arg(body)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    launch::reap(child, "notify-send".to_string(), false);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_launches_from_the_history() {
+        let since = 10 * WEEK;
+        let activity = [
+            Activity {
+                time: since + 1,
+                kind: ActivityKind::Launched(PathBuf::from("/code/api")),
+            },
+            Activity {
+                time: since + 2,
+                kind: ActivityKind::Cleaned(PathBuf::from("/code/api"), 1024),
+            },
+        ];
+        let launches = [
+            LaunchRecord::new(PathBuf::from("/code/api"), "code".to_string(), since + 1),
+            LaunchRecord::new(PathBuf::from("/code/web"), "code".to_string(), since + 3),
+            LaunchRecord::new(PathBuf::from("/code/web"), "code".to_string(), since - 1),
+        ];
+
+        let digest = Digest::collect(&activity, &launches, [], since);
+
+        assert_eq!(digest.launches(), 2);
+        assert_eq!(digest.cleaned(), 1024);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::domain::autostart;
+use cosmic::cosmic_config::{self, ConfigGet, ConfigSet};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Config of the COSMIC dock, listing its favorites by desktop file id.
+const APP_LIST: &str = "com.system76.CosmicAppList";
+
+/// A project opened with a program, pinned to the dock as an application of its own.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DockPin {
+    project: PathBuf,
+    program: String,
+}
+
+impl DockPin {
+    pub fn new(project: PathBuf, program: String) -> DockPin {
+        DockPin { project, program }
+    }
+
+    pub fn project(&self) -> &Path {
+        &self.project
+    }
+
+    pub fn program(&self) -> &str {
+        &self.program
+    }
+
+    /// Desktop file id, unique for the project and program.
+    pub fn id(&self) -> String {
+        // FNV-1a, to stay the same across builds unlike the std hasher
+        let hash = self
+            .project
+            .to_string_lossy()
+            .bytes()
+            .chain([0])
+            .chain(self.program.bytes())
+            .fold(0xcbf29ce484222325_u64, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+            });
+
+        format!("at.tobinio.ProjectOverview.pin-{hash:016x}")
+    }
+}
+
+/// Writes a desktop entry launching the pin and adds it to the dock favorites.
+pub fn pin(pin: &DockPin, name: &str) -> io::Result<()> {
+    let path = entry_path(pin)?;
+    let exec = std::env::current_exe()?;
+
+    // the windows of the launched program keep the class of the program, so the dock shows
+    // them under the program's own entry rather than the pin
+    let entry = format!(
+        "[Desktop Entry]\n\
+         Type=Application\n\
+         Name={}\n\
+         Exec={}\n\
+         Icon=at.tobinio.ProjectOverview\n\
+         Categories=Development;\n",
+        autostart::escape(&format!("{name} ({})", pin.program)),
+        autostart::escape(&format!(
+            "{} --launch {} {}",
+            autostart::quote(&exec.to_string_lossy()),
+            autostart::quote(&pin.program),
+            autostart::quote(&pin.project.to_string_lossy()),
+        )),
+    );
+
+    fs::create_dir_all(path.parent().unwrap_or(&path))?;
+    fs::write(path, entry)?;
+
+    set_favorite(&pin.id(), true)
+}
+
+/// Removes the pin from the dock favorites and deletes its desktop entry.
+pub fn unpin(pin: &DockPin) -> io::Result<()> {
+    set_favorite(&pin.id(), false)?;
+
+    match fs::remove_file(entry_path(pin)?) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+fn entry_path(pin: &DockPin) -> io::Result<PathBuf> {
+    let data_dir = dirs::data_dir()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no data directory available"))?;
+
+    Ok(data_dir
+        .join("applications")
+        .join(format!("{}.desktop", pin.id())))
+}
+
+fn set_favorite(id: &str, favorite: bool) -> io::Result<()> {
+    let config =
+        cosmic_config::Config::new(APP_LIST, 1).map_err(|err| io::Error::other(err.to_string()))?;
+    let mut favorites: Vec<String> = config.get("favorites").unwrap_or_default();
+
+    favorites.retain(|favorite| favorite != id);
+
+    if favorite {
+        favorites.push(id.to_string());
+    }
+
+    config
+        .set("favorites", favorites)
+        .map_err(|err| io::Error::other(err.to_string()))
+}
@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// A named set of environment variables, e.g. staging or production credentials,
+/// set for every launch of the projects using it.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EnvProfile {
+    name: String,
+    vars: Vec<(String, String)>,
+}
+
+impl EnvProfile {
+    pub fn new(name: String) -> EnvProfile {
+        EnvProfile { name, vars: vec![] }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn vars(&self) -> &[(String, String)] {
+        self.vars.as_slice()
+    }
+
+    /// Sets the variable, replacing its previous value.
+    pub fn set_var(&mut self, key: String, value: String) {
+        match self.vars.iter_mut().find(|(existing, _)| *existing == key) {
+            Some((_, existing)) => *existing = value,
+            None => self.vars.push((key, value)),
+        }
+    }
+
+    pub fn remove_var(&mut self, key: &str) {
+        self.vars.retain(|(existing, _)| existing != key);
+    }
+}
+
+/// Profile a single project is launched with.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ProjectEnvProfile {
+    project: PathBuf,
+    profile: String,
+}
+
+impl ProjectEnvProfile {
+    pub fn new(project: PathBuf, profile: String) -> ProjectEnvProfile {
+        ProjectEnvProfile { project, profile }
+    }
+
+    pub fn project(&self) -> &Path {
+        &self.project
+    }
+
+    pub fn set_project(&mut self, project: PathBuf) {
+        self.project = project;
+    }
+
+    pub fn profile(&self) -> &str {
+        &self.profile
+    }
+}
+
+/// Splits `KEY=value` into the name and value of a variable, `None` without a valid name.
+pub fn parse_var(input: &str) -> Option<(String, String)> {
+    let (key, value) = input.split_once('=')?;
+    let key = key.trim();
+
+    let valid = key
+        .chars()
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && key
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || char == '_');
+
+    valid.then(|| (key.to_string(), value.to_string()))
+}
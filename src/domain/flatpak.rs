@@ -0,0 +1,62 @@
+use std::process::{Command, Stdio};
+
+/// An application installed with Flatpak.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FlatpakApp {
+    id: String,
+    name: String,
+}
+
+impl FlatpakApp {
+    /// Application id, e.g. `com.visualstudio.code`.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Program command running the application for the project.
+pub fn command(id: &str) -> String {
+    format!("flatpak run {id} %path%")
+}
+
+/// Applications of the user and system installations, sorted by name.
+///
+/// Empty if Flatpak is not installed.
+pub fn installed() -> Vec<FlatpakApp> {
+    let Ok(output) = Command::new("flatpak")
+        .args(["list", "--app", "--columns=application,name"])
+        .stderr(Stdio::null())
+        .output()
+    else {
+        return vec![];
+    };
+
+    let mut apps: Vec<FlatpakApp> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let (id, name) = line.split_once('\t')?;
+
+            Some(FlatpakApp {
+                id: id.trim().to_string(),
+                name: name.trim().to_string(),
+            })
+        })
+        .collect();
+
+    apps.sort_by(|a, b| a.name.cmp(&b.name));
+    apps.dedup_by(|a, b| a.id == b.id);
+    apps
+}
+
+pub fn is_installed(id: &str) -> bool {
+    Command::new("flatpak")
+        .args(["info", id])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
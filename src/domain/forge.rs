@@ -0,0 +1,78 @@
+use std::fs;
+use std::path::Path;
+
+/// The service hosting a repository, derived from its `origin` remote.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Forge {
+    GitHub,
+    GitLab,
+    Codeberg,
+    Bitbucket,
+    SelfHosted(String),
+}
+
+impl Forge {
+    pub fn detect(project: &Path) -> Option<Forge> {
+        Forge::from_url(&origin_url(project)?)
+    }
+
+    /// Supports `scheme://[user@]host[:port]/path` and scp-like `user@host:path` urls.
+    pub fn from_url(url: &str) -> Option<Forge> {
+        let host = match url.split_once("://") {
+            Some((_, rest)) => rest.split('/').next()?,
+            None => url.split(':').next()?,
+        };
+
+        let host = host.rsplit('@').next()?;
+        let host = host.split(':').next()?.to_lowercase();
+
+        if host.is_empty() || Path::new(url).exists() {
+            return None;
+        }
+
+        Some(match host.as_str() {
+            "github.com" => Forge::GitHub,
+            "gitlab.com" => Forge::GitLab,
+            "codeberg.org" => Forge::Codeberg,
+            "bitbucket.org" => Forge::Bitbucket,
+            _ => Forge::SelfHosted(host),
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Forge::GitHub => "GitHub",
+            Forge::GitLab => "GitLab",
+            Forge::Codeberg => "Codeberg",
+            Forge::Bitbucket => "Bitbucket",
+            Forge::SelfHosted(host) => host,
+        }
+    }
+}
+
+/// Reads the `origin` remote from the project's `.git/config`, without spawning git.
+pub fn origin_url(project: &Path) -> Option<String> {
+    let config = fs::read_to_string(project.join(".git").join("config")).ok()?;
+
+    let mut in_origin = false;
+
+    for line in config.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_origin = line == "[remote \"origin\"]";
+            continue;
+        }
+
+        if !in_origin {
+            continue;
+        }
+
+        if let Some(url) = line
+            .strip_prefix("url")
+            .and_then(|rest| rest.trim_start().strip_prefix('='))
+        {
+            return Some(url.trim().to_string());
+        }
+    }
+
+    None
+}
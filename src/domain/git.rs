@@ -0,0 +1,159 @@
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+#[derive(Debug, Clone, Default)]
+pub struct GitChanges {
+    modified: Vec<PathBuf>,
+    last_commit: Vec<PathBuf>,
+}
+
+impl GitChanges {
+    /// Files with uncommitted changes, including untracked ones.
+    pub fn modified(&self) -> &[PathBuf] {
+        self.modified.as_slice()
+    }
+
+    /// Files touched by the commit `HEAD` points to.
+    pub fn last_commit(&self) -> &[PathBuf] {
+        self.last_commit.as_slice()
+    }
+}
+
+/// Collects the changed files of the repository containing `project`.
+///
+/// Returns `None` if `project` is not inside a git repository.
+pub fn changes(project: &Path) -> Option<GitChanges> {
+    let top_level = PathBuf::from(run(project, &["rev-parse", "--show-toplevel"])?.trim());
+
+    let modified = run(project, &["status", "--porcelain"])
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| line.get(3..))
+        .map(|path| path.rsplit(" -> ").next().unwrap_or(path))
+        .map(|path| top_level.join(path.trim_matches('"')))
+        .collect();
+
+    let last_commit = run(
+        project,
+        &["diff-tree", "--no-commit-id", "--name-only", "-r", "HEAD"],
+    )
+    .unwrap_or_default()
+    .lines()
+    .map(|path| top_level.join(path))
+    .collect();
+
+    Some(GitChanges {
+        modified,
+        last_commit,
+    })
+}
+
+/// Checked out branch, working tree state and last commit time of a repository.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GitStatus {
+    branch: Option<String>,
+    dirty: bool,
+    last_commit: Option<u64>,
+}
+
+impl GitStatus {
+    /// Name of the checked out branch, `None` with a detached `HEAD`.
+    pub fn branch(&self) -> Option<&str> {
+        self.branch.as_deref()
+    }
+
+    /// Whether there are uncommitted changes, including untracked files.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Commit time of `HEAD` in seconds since the unix epoch, `None` without commits.
+    pub fn last_commit(&self) -> Option<u64> {
+        self.last_commit
+    }
+}
+
+/// Branch, working tree state and last commit time of the repository at `project`, `None` if it has no `.git`.
+pub fn status(project: &Path) -> Option<GitStatus> {
+    if !project.join(".git").exists() {
+        return None;
+    }
+
+    let status = run(project, &["status", "--porcelain", "--branch"])?;
+    let mut lines = status.lines();
+
+    let branch = lines
+        .next()
+        .and_then(|line| line.strip_prefix("## "))
+        .map(|line| line.strip_prefix("No commits yet on ").unwrap_or(line))
+        .filter(|line| !line.starts_with("HEAD (no branch)"))
+        .and_then(|line| line.split("...").next())
+        .and_then(|line| line.split_whitespace().next())
+        .map(str::to_string);
+
+    let last_commit =
+        run(project, &["log", "-1", "--format=%ct"]).and_then(|time| time.trim().parse().ok());
+
+    Some(GitStatus {
+        branch,
+        dirty: lines.next().is_some(),
+        last_commit,
+    })
+}
+
+/// Whether the repository containing `project` has uncommitted changes, `None` outside of repositories.
+pub fn is_dirty(project: &Path) -> Option<bool> {
+    run(project, &["status", "--porcelain"]).map(|status| !status.trim().is_empty())
+}
+
+/// Whether git tracks any file below `path` relative to `project`, `None` outside of repositories.
+pub fn is_tracked(project: &Path, path: &str) -> Option<bool> {
+    run(project, &["ls-files", "--", path]).map(|files| !files.trim().is_empty())
+}
+
+/// Author name of the commit `HEAD` points to, `None` outside of repositories without commits.
+pub fn last_committer(project: &Path) -> Option<String> {
+    let name = run(project, &["log", "-1", "--format=%an"])?
+        .trim()
+        .to_string();
+
+    (!name.is_empty()).then_some(name)
+}
+
+/// Most recent tag reachable from `HEAD` with its commit time in seconds since the unix epoch.
+pub fn latest_tag(project: &Path) -> Option<(String, u64)> {
+    let tag = run(project, &["describe", "--tags", "--abbrev=0"])?
+        .trim()
+        .to_string();
+
+    let time = run(project, &["log", "-1", "--format=%ct", &tag])?
+        .trim()
+        .parse()
+        .ok()?;
+
+    Some((tag, time))
+}
+
+/// Number of commits on `HEAD` after `tag`.
+pub fn commits_since(project: &Path, tag: &str) -> Option<usize> {
+    run(project, &["rev-list", "--count", &format!("{tag}..HEAD")])?
+        .trim()
+        .parse()
+        .ok()
+}
+
+fn run(project: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(project)
+        .args(args)
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    String::from_utf8(output.stdout).ok()
+}
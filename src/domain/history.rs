@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Launches kept in the history, the oldest being dropped first.
+pub const HISTORY_LIMIT: usize = 500;
+
+/// A single launch of a project with a program.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LaunchRecord {
+    project: PathBuf,
+    program: String,
+    /// Seconds since the unix epoch.
+    time: u64,
+}
+
+impl LaunchRecord {
+    pub fn new(project: PathBuf, program: String, time: u64) -> LaunchRecord {
+        LaunchRecord {
+            project,
+            program,
+            time,
+        }
+    }
+
+    pub fn project(&self) -> &Path {
+        &self.project
+    }
+
+    pub fn set_project(&mut self, project: PathBuf) {
+        self.project = project;
+    }
+
+    pub fn program(&self) -> &str {
+        &self.program
+    }
+
+    pub fn time(&self) -> u64 {
+        self.time
+    }
+}
+
+/// Most recent launch of `project`, `None` if it was never launched.
+pub fn last_launch<'a>(history: &'a [LaunchRecord], project: &Path) -> Option<&'a LaunchRecord> {
+    history
+        .iter()
+        .rev()
+        .find(|record| record.project == project)
+}
+
+/// Time of the most recent launch of every project in the history.
+pub fn last_launches(history: &[LaunchRecord]) -> HashMap<&Path, u64> {
+    let mut last_launches = HashMap::new();
+
+    for record in history {
+        let time = last_launches.entry(record.project()).or_insert(record.time);
+        *time = (*time).max(record.time);
+    }
+
+    last_launches
+}
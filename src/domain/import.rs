@@ -0,0 +1,145 @@
+use std::ffi::OsString;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ImportMode {
+    Move,
+    Copy,
+}
+
+/// Moves or copies the folder `source` to `target`.
+///
+/// `progress` is called with the number of copied files and the total number of files.
+/// Moving within the same filesystem is a plain rename and does not report progress.
+/// Copies are made next to `target` under a hidden name and renamed into place once complete,
+/// so a failed or interrupted import never leaves a partial project behind.
+pub fn import(
+    source: &Path,
+    target: &Path,
+    mode: ImportMode,
+    mut progress: impl FnMut(usize, usize),
+) -> io::Result<()> {
+    if target.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", target.display()),
+        ));
+    }
+
+    if mode == ImportMode::Move && fs::rename(source, target).is_ok() {
+        return Ok(());
+    }
+
+    let total = count_files(source)?;
+    let mut done = 0;
+    let partial = partial_path(target)?;
+
+    // left behind by an import that was killed
+    if partial.exists() {
+        fs::remove_dir_all(&partial)?;
+    }
+
+    progress(done, total);
+    let copied = copy_dir(source, &partial, &mut || {
+        done += 1;
+        progress(done, total);
+    })
+    .and_then(|()| fs::set_permissions(&partial, fs::metadata(source)?.permissions()))
+    .and_then(|()| {
+        // renaming onto an empty folder would replace it
+        if target.exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists", target.display()),
+            ));
+        }
+
+        fs::rename(&partial, target)
+    });
+
+    if let Err(err) = copied {
+        let _ = fs::remove_dir_all(&partial);
+        return Err(err);
+    }
+
+    if mode == ImportMode::Move {
+        fs::remove_dir_all(source)?;
+    }
+
+    Ok(())
+}
+
+/// Hidden sibling of `target` the copy is made in, skipped by scans ignoring dot folders.
+fn partial_path(target: &Path) -> io::Result<PathBuf> {
+    let name = target.file_name().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{} has no folder name", target.display()),
+        )
+    })?;
+
+    let mut partial = OsString::from(".");
+    partial.push(name);
+    partial.push(".importing");
+
+    Ok(target.with_file_name(partial))
+}
+
+fn count_files(path: &Path) -> io::Result<usize> {
+    let mut count = 0;
+
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+
+        if entry.file_type()?.is_dir() {
+            count += count_files(&entry.path())?;
+        } else {
+            count += 1;
+        }
+    }
+
+    Ok(count)
+}
+
+fn copy_dir(source: &Path, target: &Path, copied: &mut impl FnMut()) -> io::Result<()> {
+    fs::create_dir_all(target)?;
+
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        let target = target.join(entry.file_name());
+        let file_type = entry.file_type()?;
+
+        if file_type.is_dir() {
+            copy_dir(&entry.path(), &target, copied)?;
+            // set last, as a read-only folder would not let its contents be copied
+            fs::set_permissions(&target, entry.metadata()?.permissions())?;
+            continue;
+        }
+
+        if file_type.is_symlink() {
+            std::os::unix::fs::symlink(fs::read_link(entry.path())?, &target)?;
+        } else {
+            fs::copy(entry.path(), &target)?;
+        }
+
+        copied();
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn copies_into_a_hidden_sibling() {
+        assert_eq!(
+            partial_path(Path::new("/code/api")).unwrap(),
+            Path::new("/code/.api.importing")
+        );
+        assert!(partial_path(Path::new("/")).is_err());
+    }
+}
@@ -0,0 +1,166 @@
+use crate::domain::command;
+use crate::domain::desktop_entry;
+use crate::domain::launch_override::LaunchOverride;
+use crate::domain::program::Program;
+use crate::domain::terminal;
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::os::unix::process::CommandExt;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum LaunchMode {
+    /// Runs the program in a session of its own, so it outlives the app and its terminal.
+    #[default]
+    Detached,
+    /// Keeps the program in the app's session, ending with it, and logs when it exits.
+    Tracked,
+}
+
+/// How programs are started, as chosen in the settings.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct LaunchSettings<'a> {
+    mode: LaunchMode,
+    /// Terminal programs running in a terminal open in, the detected one if unset.
+    terminal: Option<&'a str>,
+    /// Start programs inside the project unless they or the launch override say otherwise.
+    start_in_project: bool,
+}
+
+impl<'a> LaunchSettings<'a> {
+    pub fn new(
+        mode: LaunchMode,
+        terminal: Option<&'a str>,
+        start_in_project: bool,
+    ) -> LaunchSettings<'a> {
+        LaunchSettings {
+            mode,
+            terminal,
+            start_in_project,
+        }
+    }
+}
+
+/// Spawns `program` for the project at `project_path`, applying its launch override if any.
+///
+/// The working directory is the one of the launch override, else the project itself
+/// if the program or the `settings` start programs there.
+/// `values` hold the root and the answers to the program's prompts, see [`command::expand`].
+/// `env` is set for the program on top of its own variables, e.g. the project's environment
+/// profile and [`activation_env`].
+pub fn launch(
+    program: &Program,
+    project_path: &Path,
+    launch_override: Option<&LaunchOverride>,
+    settings: LaunchSettings,
+    values: &command::Values,
+    env: &[(String, String)],
+) -> io::Result<()> {
+    let target = launch_override
+        .and_then(|launch_override| launch_override.path())
+        .map(|path| project_path.join(path))
+        .unwrap_or_else(|| project_path.to_path_buf());
+
+    let args: Vec<String> = match program.desktop_entry() {
+        Some(id) => desktop_entry::find(id)
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::NotFound, format!("{id} is not installed"))
+            })?
+            .command(&target),
+        None => command::expand(program.command(), project_path, &target, values)?,
+    };
+
+    let mut args = args.into_iter();
+
+    let exec = args
+        .next()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "empty command"))?;
+
+    let mut process = if program.run_in_terminal() {
+        let mut process = terminal::wrap(settings.terminal)?;
+        process.arg(&exec);
+        process
+    } else {
+        Command::new(&exec)
+    };
+
+    process
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .envs(program.env().iter().map(|(key, value)| (key, value)))
+        .envs(env.iter().map(|(key, value)| (key, value)));
+
+    let working_directory =
+        match launch_override.and_then(|launch_override| launch_override.working_directory()) {
+            Some(working_directory) => Some(project_path.join(working_directory)),
+            None => program
+                .start_in_project()
+                .unwrap_or(settings.start_in_project)
+                .then(|| project_path.to_path_buf()),
+        };
+
+    if let Some(working_directory) = working_directory {
+        process.current_dir(working_directory);
+    }
+
+    let mode = settings.mode;
+
+    if mode == LaunchMode::Detached {
+        // SAFETY: setsid is async-signal-safe and nothing else runs between fork and exec.
+        unsafe {
+            process.pre_exec(|| {
+                rustix::process::setsid()?;
+                Ok(())
+            });
+        }
+    }
+
+    reap(process.spawn()?, exec, mode == LaunchMode::Tracked);
+
+    Ok(())
+}
+
+/// Waits for the child on a thread of its own, so it does not linger as a zombie once it exits,
+/// logging how it exited if `log_exit` is set.
+pub fn reap(mut child: Child, name: String, log_exit: bool) {
+    std::thread::spawn(move || match child.wait() {
+        Ok(status) if log_exit => info!("{name} exited - {status}"),
+        Ok(_) => {}
+        Err(err) => error!("failed to wait for {name}: {err}"),
+    });
+}
+
+/// Variables handing the activation token over, letting the compositor focus the window the program opens.
+pub fn activation_env(token: &str) -> [(String, String); 2] {
+    [
+        ("XDG_ACTIVATION_TOKEN".to_string(), token.to_string()),
+        ("DESKTOP_STARTUP_ID".to_string(), token.to_string()),
+    ]
+}
+
+/// Lets the user pick an application for the project in the desktop portal's chooser and opens it there.
+pub async fn open_with(project_path: PathBuf) -> Result<(), String> {
+    let directory = std::fs::File::open(&project_path).map_err(|err| err.to_string())?;
+
+    ashpd::desktop::open_uri::OpenFileRequest::default()
+        .ask(true)
+        .send_file(&directory)
+        .await
+        .and_then(|request| request.response())
+        .map_err(|err| err.to_string())
+}
+
+/// Returns whether `executable` is an existing file or can be found in `$PATH`.
+pub fn is_installed(executable: &str) -> bool {
+    if executable.contains('/') {
+        return Path::new(executable).is_file();
+    }
+
+    std::env::var_os("PATH").is_some_and(|paths| {
+        std::env::split_paths(&paths).any(|path| path.join(executable).is_file())
+    })
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Adjusts how a single program is launched for a single project.
+///
+/// Both paths are relative to the project directory.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LaunchOverride {
+    project: PathBuf,
+    program: String,
+    working_directory: Option<PathBuf>,
+    path: Option<PathBuf>,
+}
+
+impl LaunchOverride {
+    pub fn new(
+        project: PathBuf,
+        program: String,
+        working_directory: Option<PathBuf>,
+        path: Option<PathBuf>,
+    ) -> LaunchOverride {
+        LaunchOverride {
+            project,
+            program,
+            working_directory,
+            path,
+        }
+    }
+
+    pub fn project(&self) -> &Path {
+        &self.project
+    }
+
+    pub fn set_project(&mut self, project: PathBuf) {
+        self.project = project;
+    }
+
+    pub fn program(&self) -> &str {
+        &self.program
+    }
+
+    pub fn working_directory(&self) -> Option<&PathBuf> {
+        self.working_directory.as_ref()
+    }
+
+    pub fn path(&self) -> Option<&PathBuf> {
+        self.path.as_ref()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.working_directory.is_none() && self.path.is_none()
+    }
+}
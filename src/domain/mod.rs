@@ -1,3 +1,75 @@
+pub mod annotation;
+
+pub mod audit;
+
+pub mod automation;
+
+pub mod autostart;
+
+pub mod check;
+
+pub mod command;
+
+pub mod content_search;
+
+pub mod desktop_entry;
+
+pub mod digest;
+
+pub mod dock;
+
+pub mod env_profile;
+
+pub mod flatpak;
+
+pub mod forge;
+
+pub mod git;
+
+pub mod history;
+
+pub mod import;
+
+pub mod launch;
+
+pub mod launch_override;
+
+pub mod mount;
+
+pub mod ownership;
+
+pub mod power;
+
 pub mod program;
 
 pub mod project;
+
+pub mod project_icon;
+
+pub mod ranking;
+
+pub mod readme;
+
+pub mod release;
+
+pub mod repo_task;
+
+pub mod root_label;
+
+pub mod rule;
+
+pub mod scan_diff;
+
+pub mod session;
+
+pub mod snapshot;
+
+pub mod stats;
+
+pub mod template;
+
+pub mod terminal;
+
+pub mod trash;
+
+pub mod watch;
@@ -0,0 +1,260 @@
+use rustix::fs::{statvfs, StatVfsMountFlags};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Returns whether the filesystem containing `path` is mounted read-only.
+pub fn is_read_only(path: &Path) -> bool {
+    statvfs(path)
+        .map(|stat| stat.f_flag.contains(StatVfsMountFlags::RDONLY))
+        .unwrap_or(false)
+}
+
+const NETWORK_FILESYSTEMS: [&str; 9] = [
+    "nfs",
+    "nfs4",
+    "cifs",
+    "smb3",
+    "9p",
+    "davfs",
+    "fuse.sshfs",
+    "fuse.rclone",
+    "fuse.gvfsd-fuse",
+];
+
+/// Directories udisks mounts removable media in, one folder per user and volume label.
+const MEDIA_DIRS: [&str; 2] = ["/run/media", "/media"];
+
+/// A root living on removable or network media, which may come and go.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RemovableRoot {
+    mount_point: PathBuf,
+    mounted: bool,
+}
+
+impl RemovableRoot {
+    pub fn mount_point(&self) -> &Path {
+        &self.mount_point
+    }
+
+    pub fn is_mounted(&self) -> bool {
+        self.mounted
+    }
+}
+
+/// Returns the removable or network media the root lives on, if any.
+///
+/// Unmounted media is recognised by its filesystem table entry or by its udisks media directory.
+pub fn removable_root(root: &Path) -> Option<RemovableRoot> {
+    let mounted = mounts()
+        .into_iter()
+        .filter(|mount| root.starts_with(&mount.mount_point))
+        .max_by_key(|mount| mount.mount_point.as_os_str().len());
+
+    if let Some(mount) = mounted.filter(|mount| mount.mount_point != Path::new("/")) {
+        return mount.is_removable().then_some(RemovableRoot {
+            mount_point: mount.mount_point,
+            mounted: true,
+        });
+    }
+
+    let mount_point = fstab_mount_points()
+        .into_iter()
+        .filter(|mount_point| mount_point != Path::new("/") && root.starts_with(mount_point))
+        .max_by_key(|mount_point| mount_point.as_os_str().len())
+        .or_else(|| media_mount_point(root))?;
+
+    Some(RemovableRoot {
+        mount_point,
+        mounted: false,
+    })
+}
+
+/// Mounts the media of the root, through udisks for media directories and `mount` otherwise.
+pub fn mount(root: &RemovableRoot) -> Result<(), String> {
+    let mut command = if media_mount_point(&root.mount_point).is_some() {
+        let folder = root
+            .mount_point
+            .file_name()
+            .ok_or_else(|| format!("{:?} has no volume label", root.mount_point))?
+            .to_string_lossy();
+
+        let device = media_device(&folder)
+            .ok_or_else(|| format!("no connected device is labelled {folder:?}"))?;
+
+        let mut command = Command::new("udisksctl");
+        command.arg("mount").arg("--block-device").arg(device);
+        command
+    } else {
+        let mut command = Command::new("mount");
+        command.arg(&root.mount_point);
+        command
+    };
+
+    let output = command.output().map_err(|err| err.to_string())?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    Ok(())
+}
+
+struct Mount {
+    mount_point: PathBuf,
+    filesystem: String,
+    source: String,
+}
+
+impl Mount {
+    fn is_removable(&self) -> bool {
+        NETWORK_FILESYSTEMS.contains(&self.filesystem.as_str())
+            || media_mount_point(&self.mount_point).is_some()
+            || self
+                .source
+                .strip_prefix("/dev/")
+                .is_some_and(is_removable_device)
+    }
+}
+
+fn mounts() -> Vec<Mount> {
+    let Ok(mountinfo) = fs::read_to_string("/proc/self/mountinfo") else {
+        return vec![];
+    };
+
+    mountinfo
+        .lines()
+        .filter_map(|line| {
+            let (fields, filesystem_fields) = line.split_once(" - ")?;
+            let mount_point = fields.split(' ').nth(4)?;
+            let mut filesystem_fields = filesystem_fields.split(' ');
+
+            Some(Mount {
+                mount_point: PathBuf::from(unescape(mount_point)),
+                filesystem: filesystem_fields.next()?.to_string(),
+                source: filesystem_fields.next()?.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn fstab_mount_points() -> Vec<PathBuf> {
+    let Ok(fstab) = fs::read_to_string("/etc/fstab") else {
+        return vec![];
+    };
+
+    fstab
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_whitespace().nth(1))
+        .filter(|mount_point| mount_point.starts_with('/'))
+        .map(|mount_point| PathBuf::from(unescape(mount_point)))
+        .collect()
+}
+
+/// Returns the udisks mount point `path` is in, e.g. `/run/media/user/usb-stick`.
+fn media_mount_point(path: &Path) -> Option<PathBuf> {
+    MEDIA_DIRS.iter().find_map(|media_dir| {
+        let mut components = path.strip_prefix(media_dir).ok()?.components();
+        let user = components.next()?;
+        let label = components.next()?;
+
+        Some(Path::new(media_dir).join(user).join(label))
+    })
+}
+
+/// Returns the device udisks names the media folder after, by the label of its filesystem
+/// or, for filesystems without one, its UUID.
+fn media_device(folder: &str) -> Option<PathBuf> {
+    ["/dev/disk/by-label", "/dev/disk/by-uuid"]
+        .into_iter()
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flat_map(|entries| entries.flatten())
+        .find(|entry| unescape_udev(&entry.file_name().to_string_lossy()) == folder)
+        .map(|entry| entry.path())
+}
+
+fn is_removable_device(device: &str) -> bool {
+    let Ok(path) = fs::canonicalize(Path::new("/sys/class/block").join(device)) else {
+        return false;
+    };
+
+    // partitions carry the flag on their parent disk
+    [path.join("removable"), path.with_file_name("removable")]
+        .iter()
+        .any(|flag| fs::read_to_string(flag).is_ok_and(|flag| flag.trim() == "1"))
+}
+
+/// Resolves the hex escapes udev writes in device link names, e.g. `\x20` for a space.
+fn unescape_udev(name: &str) -> String {
+    let mut bytes = vec![];
+    let mut rest = name;
+
+    while let Some(index) = rest.find("\\x") {
+        bytes.extend_from_slice(rest[..index].as_bytes());
+
+        match rest
+            .get(index + 2..index + 4)
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+        {
+            Some(byte) => {
+                bytes.push(byte);
+                rest = &rest[index + 4..];
+            }
+            None => {
+                bytes.extend_from_slice(b"\\x");
+                rest = &rest[index + 2..];
+            }
+        }
+    }
+
+    bytes.extend_from_slice(rest.as_bytes());
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Resolves the octal escapes of mount tables, e.g. `\040` for a space.
+fn unescape(field: &str) -> String {
+    let mut unescaped = String::new();
+    let mut rest = field;
+
+    while let Some(index) = rest.find('\\') {
+        unescaped.push_str(&rest[..index]);
+        let escape = rest.get(index + 1..index + 4);
+
+        match escape.and_then(|escape| u8::from_str_radix(escape, 8).ok()) {
+            Some(byte) => {
+                unescaped.push(byte as char);
+                rest = &rest[index + 4..];
+            }
+            None => {
+                unescaped.push('\\');
+                rest = &rest[index + 1..];
+            }
+        }
+    }
+
+    unescaped.push_str(rest);
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_device_labels() {
+        assert_eq!(unescape_udev(r"My\x20Stick"), "My Stick");
+        assert_eq!(unescape_udev(r"K\xc3\xb6ln"), "Köln");
+        assert_eq!(unescape_udev(r"odd\xzz"), r"odd\xzz");
+    }
+
+    #[test]
+    fn finds_media_mount_points() {
+        assert_eq!(
+            media_mount_point(Path::new("/run/media/me/usb-stick/code/api")),
+            Some(PathBuf::from("/run/media/me/usb-stick"))
+        );
+        assert_eq!(media_mount_point(Path::new("/home/me/code")), None);
+    }
+}
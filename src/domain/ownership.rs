@@ -0,0 +1,54 @@
+use crate::domain::git;
+use nix::unistd::{Uid, User};
+use serde::{Deserialize, Serialize};
+use std::os::unix::fs::MetadataExt;
+use std::path::Path;
+
+/// Who is responsible for a project on a shared root.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Ownership {
+    owner: Option<String>,
+    last_committer: Option<String>,
+}
+
+impl Ownership {
+    pub fn detect(project: &Path) -> Ownership {
+        Ownership {
+            owner: owner(project),
+            last_committer: git::last_committer(project),
+        }
+    }
+
+    /// User owning the project folder.
+    pub fn owner(&self) -> Option<&str> {
+        self.owner.as_deref()
+    }
+
+    /// Author of the commit `HEAD` points to.
+    pub fn last_committer(&self) -> Option<&str> {
+        self.last_committer.as_deref()
+    }
+
+    /// Whether `person` owns the project or committed last, ignoring case.
+    pub fn involves(&self, person: &str) -> bool {
+        let person = person.to_lowercase();
+
+        [self.owner(), self.last_committer()]
+            .into_iter()
+            .flatten()
+            .any(|name| name.to_lowercase().contains(&person))
+    }
+}
+
+/// Resolves the owner's name through the system user database, so LDAP and sssd users are found
+/// too, falling back to the numeric uid.
+fn owner(path: &Path) -> Option<String> {
+    let uid = path.metadata().ok()?.uid();
+
+    let name = User::from_uid(Uid::from_raw(uid))
+        .ok()
+        .flatten()
+        .map(|user| user.name);
+
+    Some(name.unwrap_or_else(|| uid.to_string()))
+}
@@ -0,0 +1,69 @@
+use std::process::{Command, Stdio};
+
+/// Whether expensive background work should currently be avoided.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct PowerState {
+    on_battery: bool,
+    metered: bool,
+}
+
+impl PowerState {
+    /// Queries UPower and NetworkManager, treating missing services as plugged in and unmetered.
+    pub fn detect() -> PowerState {
+        PowerState {
+            on_battery: on_battery(),
+            metered: metered(),
+        }
+    }
+
+    pub fn on_battery(&self) -> bool {
+        self.on_battery
+    }
+
+    pub fn metered(&self) -> bool {
+        self.metered
+    }
+
+    pub fn is_constrained(&self) -> bool {
+        self.on_battery || self.metered
+    }
+}
+
+fn on_battery() -> bool {
+    run("upower", &["--dump"]).is_some_and(|output| {
+        output
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("on-battery:"))
+            .any(|value| value.trim() == "yes")
+    })
+}
+
+/// NetworkManager reports `1` (yes) or `3` (guessed yes) for metered connections.
+fn metered() -> bool {
+    run(
+        "busctl",
+        &[
+            "get-property",
+            "org.freedesktop.NetworkManager",
+            "/org/freedesktop/NetworkManager",
+            "org.freedesktop.NetworkManager",
+            "Metered",
+        ],
+    )
+    .is_some_and(|output| matches!(output.trim(), "u 1" | "u 3"))
+}
+
+fn run(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(String::from_utf8_lossy(&output.stdout).to_string())
+}
@@ -1,14 +1,125 @@
+use crate::domain::command;
+use crate::domain::desktop_entry::{self, DesktopEntry};
+use crate::domain::flatpak::{self, FlatpakApp};
+use crate::domain::launch;
 use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Program {
     name: String,
     command: String,
+    /// Id of the installed application launched instead of `command`, which then only keeps its `Exec`
+    /// converted by [`DesktopEntry::path_command`].
+    #[serde(default)]
+    desktop_entry: Option<String>,
+    /// Id of the Flatpak application the generated `command` runs.
+    #[serde(default)]
+    flatpak: Option<String>,
+    /// Opens `command` inside a terminal window, for terminal programs like `nvim %path%`.
+    #[serde(default)]
+    run_in_terminal: bool,
+    /// Key launching the selected project with the program.
+    #[serde(default)]
+    mnemonic: Option<char>,
+    /// Icon name or image path shown on the launch buttons instead of the name.
+    #[serde(default)]
+    icon: Option<String>,
+    /// Variables set for the program, e.g. `JAVA_HOME` for an IDE.
+    #[serde(default)]
+    env: Vec<(String, String)>,
+    /// Starts the program inside the project or, with `false`, in the app's working directory,
+    /// following the setting if unset.
+    #[serde(default)]
+    start_in_project: Option<bool>,
 }
 
 impl Program {
-    pub fn new(name: String, command: String) -> Program {
-        Program { name, command }
+    pub fn new(name: String, command: String, run_in_terminal: bool) -> Program {
+        Program {
+            name,
+            command,
+            desktop_entry: None,
+            flatpak: None,
+            run_in_terminal,
+            mnemonic: None,
+            icon: None,
+            env: vec![],
+            start_in_project: None,
+        }
+    }
+
+    pub fn from_desktop_entry(name: String, entry: &DesktopEntry) -> Program {
+        Program {
+            name,
+            command: entry.path_command(),
+            desktop_entry: Some(entry.id().to_string()),
+            flatpak: None,
+            run_in_terminal: false,
+            mnemonic: None,
+            icon: entry.icon().map(str::to_string),
+            env: vec![],
+            start_in_project: None,
+        }
+    }
+
+    pub fn from_flatpak(name: String, app: &FlatpakApp) -> Program {
+        Program {
+            name,
+            command: flatpak::command(app.id()),
+            desktop_entry: None,
+            flatpak: Some(app.id().to_string()),
+            run_in_terminal: false,
+            mnemonic: None,
+            // Flatpak apps export their icon under their id
+            icon: Some(app.id().to_string()),
+            env: vec![],
+            start_in_project: None,
+        }
+    }
+
+    pub fn desktop_entry(&self) -> Option<&str> {
+        self.desktop_entry.as_deref()
+    }
+
+    pub fn flatpak(&self) -> Option<&str> {
+        self.flatpak.as_deref()
+    }
+
+    pub fn run_in_terminal(&self) -> bool {
+        self.run_in_terminal
+    }
+
+    pub fn mnemonic(&self) -> Option<char> {
+        self.mnemonic
+    }
+
+    pub fn set_mnemonic(&mut self, mnemonic: Option<char>) {
+        self.mnemonic = mnemonic;
+    }
+
+    pub fn icon(&self) -> Option<&str> {
+        self.icon.as_deref()
+    }
+
+    pub fn set_icon(&mut self, icon: Option<String>) {
+        self.icon = icon;
+    }
+
+    pub fn env(&self) -> &[(String, String)] {
+        self.env.as_slice()
+    }
+
+    pub fn set_env(&mut self, env: Vec<(String, String)>) {
+        self.env = env;
+    }
+
+    pub fn start_in_project(&self) -> Option<bool> {
+        self.start_in_project
+    }
+
+    pub fn set_start_in_project(&mut self, start_in_project: Option<bool>) {
+        self.start_in_project = start_in_project;
     }
 
     pub fn command(&self) -> &str {
@@ -17,7 +128,230 @@ impl Program {
     pub fn name(&self) -> &str {
         &self.name
     }
+    /// Whether the command refers to the project through one of [`command::PROJECT_PLACEHOLDERS`].
+    ///
+    /// Unknown placeholders are passed on as written, see [`command::unknown_placeholders`].
     pub fn is_valid_command(command: &str) -> bool {
-        command.contains("%path%")
+        command::PROJECT_PLACEHOLDERS
+            .iter()
+            .any(|placeholder| command.contains(placeholder))
+            && command::split(command).is_ok()
+    }
+
+    /// Labels of the values asked for before launching, see [`command::prompts`].
+    pub fn prompts(&self) -> Vec<String> {
+        match self.desktop_entry() {
+            Some(_) => vec![],
+            None => command::prompts(self.command()),
+        }
+    }
+
+    /// Whether the application or executable the program runs can still be found.
+    pub fn is_installed(&self) -> bool {
+        if let Some(id) = self.desktop_entry() {
+            return desktop_entry::find(id).is_some();
+        }
+
+        if let Some(id) = self.flatpak() {
+            return flatpak::is_installed(id);
+        }
+
+        Program::is_valid_command(self.command())
+            && command::split(self.command())
+                .is_ok_and(|args| args.first().is_some_and(|exec| launch::is_installed(exec)))
+    }
+}
+
+/// Three-way merge of program lists by name, `mine` and `theirs` both having started out as `base`.
+///
+/// Keeps the order of `theirs`, applying the programs changed or deleted in `mine` and appending
+/// the ones added there. Where both sides changed the same program, `mine` wins.
+pub fn merge(base: &[Program], mine: &[Program], theirs: &[Program]) -> Vec<Program> {
+    let find = |programs: &'_ [Program], name: &str| -> Option<Program> {
+        programs
+            .iter()
+            .find(|program| program.name() == name)
+            .cloned()
+    };
+
+    let mut merged: Vec<Program> = theirs
+        .iter()
+        .filter_map(|program| {
+            match (find(base, program.name()), find(mine, program.name())) {
+                // deleted in mine
+                (Some(_), None) => None,
+                (Some(original), Some(my_program)) if my_program != original => Some(my_program),
+                (None, Some(my_program)) => Some(my_program),
+                _ => Some(program.clone()),
+            }
+        })
+        .collect();
+
+    for program in mine {
+        if find(base, program.name()).is_none() && find(theirs, program.name()).is_none() {
+            merged.push(program.clone());
+        }
+    }
+
+    merged
+}
+
+/// Programs shared as a TOML snippet, e.g. through the clipboard.
+#[derive(Serialize, Deserialize)]
+struct SharedPrograms {
+    programs: Vec<Program>,
+}
+
+/// TOML snippet sharing the programs, leaving out their personal mnemonics and their variables,
+/// which often hold tokens or other credentials.
+pub fn export(programs: &[Program]) -> Result<String, toml::ser::Error> {
+    let programs = programs
+        .iter()
+        .cloned()
+        .map(|mut program| {
+            program.set_mnemonic(None);
+            program.set_env(vec![]);
+            program
+        })
+        .collect();
+
+    toml::to_string(&SharedPrograms { programs })
+}
+
+/// Programs of a snippet created by [`export`].
+pub fn import(snippet: &str) -> Result<Vec<Program>, toml::de::Error> {
+    toml::from_str::<SharedPrograms>(snippet).map(|shared| shared.programs)
+}
+
+impl AsRef<str> for Program {
+    fn as_ref(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Last answers to the prompts of a program, by the prompts' labels.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PromptAnswers {
+    program: String,
+    answers: Vec<(String, String)>,
+}
+
+impl PromptAnswers {
+    pub fn new(program: String, answers: Vec<(String, String)>) -> PromptAnswers {
+        PromptAnswers { program, answers }
+    }
+
+    pub fn program(&self) -> &str {
+        &self.program
+    }
+
+    pub fn set_program(&mut self, program: String) {
+        self.program = program;
+    }
+
+    pub fn answers(&self) -> &[(String, String)] {
+        &self.answers
+    }
+}
+
+/// Program a single project is launched with by default instead of the global default.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct DefaultProgram {
+    project: PathBuf,
+    program: String,
+}
+
+impl DefaultProgram {
+    pub fn new(project: PathBuf, program: String) -> DefaultProgram {
+        DefaultProgram { project, program }
+    }
+
+    pub fn project(&self) -> &Path {
+        &self.project
+    }
+
+    pub fn set_project(&mut self, project: PathBuf) {
+        self.project = project;
+    }
+
+    pub fn program(&self) -> &str {
+        &self.program
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn program(name: &str, command: &str) -> Program {
+        Program::new(name.to_string(), command.to_string(), false)
+    }
+
+    #[test]
+    fn export_round_trips_without_personal_settings() {
+        let mut editor = program("Editor", "code %path%");
+        editor.set_mnemonic(Some('e'));
+        editor.set_env(vec![("GITHUB_TOKEN".to_string(), "secret".to_string())]);
+        editor.set_icon(Some("code".to_string()));
+        let shell = program("Shell", "sh -c \"cd '%path%' && exec $SHELL\"");
+
+        let snippet = export(&[editor.clone(), shell.clone()]).unwrap();
+        assert!(!snippet.contains("secret"));
+
+        editor.set_mnemonic(None);
+        editor.set_env(vec![]);
+        assert_eq!(import(&snippet).unwrap(), [editor, shell]);
+    }
+
+    fn names(programs: &[Program]) -> Vec<&str> {
+        programs.iter().map(Program::name).collect()
+    }
+
+    #[test]
+    fn merge_keeps_changes_of_both_sides() {
+        let base = [
+            program("Editor", "code %path%"),
+            program("Files", "nautilus %path%"),
+        ];
+        let mine = [
+            program("Editor", "code --new-window %path%"),
+            program("Files", "nautilus %path%"),
+            program("Shell", "kitty --directory %path%"),
+        ];
+        let theirs = [
+            program("Files", "nautilus %path%"),
+            program("Editor", "code %path%"),
+            program("Vim", "nvim %path%"),
+        ];
+
+        let merged = merge(&base, &mine, &theirs);
+
+        assert_eq!(names(&merged), ["Files", "Editor", "Vim", "Shell"]);
+        assert_eq!(merged[1].command(), "code --new-window %path%");
+    }
+
+    #[test]
+    fn merge_applies_deletions_and_prefers_mine_on_conflicts() {
+        let base = [
+            program("Editor", "code %path%"),
+            program("Files", "nautilus %path%"),
+        ];
+        let mine = [program("Editor", "zed %path%")];
+        let theirs = [
+            program("Editor", "code --reuse-window %path%"),
+            program("Files", "nemo %path%"),
+        ];
+
+        let merged = merge(&base, &mine, &theirs);
+
+        assert_eq!(merged, [program("Editor", "zed %path%")]);
+    }
+
+    #[test]
+    fn merge_takes_their_changes_to_untouched_programs() {
+        let base = [program("Editor", "code %path%")];
+        let theirs = [program("Editor", "zed %path%")];
+
+        assert_eq!(merge(&base, &base, &theirs), theirs);
     }
 }
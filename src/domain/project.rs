@@ -1,10 +1,172 @@
-use std::fs::DirEntry;
+use crate::domain::forge;
+use crate::domain::mount;
+use crate::domain::rule::{RuleAction, RuleSet};
+use globset::GlobSet;
+use log::error;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, DirEntry};
+use std::io;
+use std::iter;
+use std::os::unix::fs::MetadataExt;
+use std::path::{Component, Path, PathBuf};
 use std::time::SystemTime;
 
-#[derive(Debug, Clone)]
+/// Marker file freezing a project, optionally containing the reason.
+pub const LOCK_FILE: &str = ".project-overview-lock";
+
+/// Ignore patterns used until the list is edited, skipping the junk filesystems and other
+/// operating systems leave on shared and external drives.
+pub const DEFAULT_IGNORE_PATTERNS: [&str; 6] = [
+    "lost+found",
+    "$RECYCLE.BIN",
+    "System Volume Information",
+    ".Trash-*",
+    ".snapshots",
+    ".zfs",
+];
+
+/// Files or directories marking a directory as a project, which discovery does not descend into.
+const PROJECT_MARKERS: [&str; 9] = [
+    ".git",
+    "Cargo.toml",
+    "package.json",
+    "pyproject.toml",
+    "go.mod",
+    "pom.xml",
+    "build.gradle",
+    "CMakeLists.txt",
+    "Makefile",
+];
+
+/// Language or build system of a project, detected by its marker file.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum ProjectKind {
+    Rust,
+    Node,
+    Go,
+    Python,
+    Java,
+    CMake,
+}
+
+impl ProjectKind {
+    /// In order of precedence, e.g. a Rust project with a `package.json` for its web frontend.
+    pub const ALL: [ProjectKind; 6] = [
+        ProjectKind::Rust,
+        ProjectKind::Go,
+        ProjectKind::Java,
+        ProjectKind::Python,
+        ProjectKind::Node,
+        ProjectKind::CMake,
+    ];
+
+    fn marker(&self) -> &'static str {
+        match self {
+            ProjectKind::Rust => "Cargo.toml",
+            ProjectKind::Node => "package.json",
+            ProjectKind::Go => "go.mod",
+            ProjectKind::Python => "pyproject.toml",
+            ProjectKind::Java => "pom.xml",
+            ProjectKind::CMake => "CMakeLists.txt",
+        }
+    }
+
+    pub fn detect(path: &Path) -> Option<ProjectKind> {
+        ProjectKind::ALL
+            .into_iter()
+            .find(|kind| path.join(kind.marker()).is_file())
+    }
+
+    /// Folder the kind's tooling builds into or downloads dependencies to, never holding sources.
+    pub fn artifact_dir(&self) -> Option<&'static str> {
+        match self {
+            ProjectKind::Rust => Some("target"),
+            ProjectKind::Node => Some("node_modules"),
+            ProjectKind::Python => Some(".venv"),
+            ProjectKind::Java => Some(".gradle"),
+            ProjectKind::Go | ProjectKind::CMake => None,
+        }
+    }
+
+    /// Name used to filter by the kind in the search, e.g. `kind:rust`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ProjectKind::Rust => "rust",
+            ProjectKind::Node => "node",
+            ProjectKind::Go => "go",
+            ProjectKind::Python => "python",
+            ProjectKind::Java => "java",
+            ProjectKind::CMake => "cmake",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ProjectKind::Rust => "Rust",
+            ProjectKind::Node => "Node.js",
+            ProjectKind::Go => "Go",
+            ProjectKind::Python => "Python",
+            ProjectKind::Java => "Java",
+            ProjectKind::CMake => "CMake",
+        }
+    }
+
+    /// MIME type of the kind's source files, whose default application can open the project.
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            ProjectKind::Rust => "text/x-rust",
+            ProjectKind::Node => "text/javascript",
+            ProjectKind::Go => "text/x-go",
+            ProjectKind::Python => "text/x-python",
+            ProjectKind::Java => "text/x-java",
+            ProjectKind::CMake => "text/x-cmake",
+        }
+    }
+
+    pub fn icon_name(&self) -> &'static str {
+        match self {
+            ProjectKind::Rust => "text-x-rust",
+            ProjectKind::Node => "text-x-javascript",
+            ProjectKind::Go => "text-x-go",
+            ProjectKind::Python => "text-x-python",
+            ProjectKind::Java => "text-x-java",
+            ProjectKind::CMake => "text-x-cmake",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Project {
     name: String,
+    path: PathBuf,
+    alternate_paths: Vec<PathBuf>,
     modify: SystemTime,
+    /// `None` if the filesystem does not record when files are created.
+    #[serde(default)]
+    created: Option<SystemTime>,
+    /// Inode of the directory, kept when it is renamed or moved on the same filesystem.
+    #[serde(default)]
+    inode: Option<u64>,
+    /// Device of the filesystem holding the directory, which `inode` is only unique on.
+    #[serde(default)]
+    device: Option<u64>,
+    /// Url of the `origin` remote, `None` outside of git repositories.
+    #[serde(default)]
+    remote: Option<String>,
+    read_only: bool,
+    lock_reason: Option<String>,
+    #[serde(default)]
+    kind: Option<ProjectKind>,
+    /// Name given by a rule, shown instead of the directory name.
+    #[serde(default)]
+    display_name: Option<String>,
+    /// Tags assigned by rules.
+    #[serde(default)]
+    rule_tags: Vec<String>,
+    /// Label prefixed with parent directories, set while another project has the same label.
+    #[serde(skip)]
+    qualified_label: Option<String>,
 }
 
 impl Project {
@@ -12,27 +174,388 @@ impl Project {
         &self.name
     }
 
+    /// Name shown for the project, given by a rule or the directory name,
+    /// with as many parent directories as needed to tell apart projects of the same name.
+    pub fn label(&self) -> &str {
+        self.qualified_label
+            .as_deref()
+            .or(self.display_name.as_deref())
+            .unwrap_or(&self.name)
+    }
+
+    pub fn rule_tags(&self) -> &[String] {
+        self.rule_tags.as_slice()
+    }
+
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    pub fn alternate_paths(&self) -> &[PathBuf] {
+        self.alternate_paths.as_slice()
+    }
+
     pub fn modify(&self) -> &SystemTime {
         &self.modify
     }
+
+    pub fn created(&self) -> Option<SystemTime> {
+        self.created
+    }
+
+    /// Device and inode of the directory, the same for every path leading to it.
+    pub fn file_id(&self) -> Option<(u64, u64)> {
+        self.device.zip(self.inode)
+    }
+
+    pub fn remote(&self) -> Option<&str> {
+        self.remote.as_deref()
+    }
+
+    pub fn kind(&self) -> Option<ProjectKind> {
+        self.kind
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Contents of the lock file, empty if no reason was given, `None` if the project is not locked.
+    pub fn lock_reason(&self) -> Option<&str> {
+        self.lock_reason.as_deref()
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.lock_reason.is_some()
+    }
+
+    /// Whether destructive actions must be disabled for this project.
+    pub fn is_protected(&self) -> bool {
+        self.read_only || self.is_locked()
+    }
+
+    /// Shows the project as `name` instead of its folder name or the display name of a rule.
+    pub fn rename(&mut self, name: String) {
+        self.display_name = Some(name);
+    }
+
+    /// Drops the name given by [`Project::rename`], going back to the display name of a rule or
+    /// the folder name.
+    pub fn reset_name(&mut self, rules: &RuleSet) {
+        self.display_name = None;
+        self.apply_rules(rules);
+    }
+
+    /// Assigns the metadata of every matching rule, later rules overriding the kind and display name of earlier ones.
+    pub fn apply_rules(&mut self, rules: &RuleSet) {
+        let actions: Vec<RuleAction> = rules.actions(self).cloned().collect();
+
+        for action in actions {
+            match action {
+                RuleAction::Tag(tag) => {
+                    if !self.rule_tags.contains(&tag) {
+                        self.rule_tags.push(tag);
+                    }
+                }
+                RuleAction::Kind(kind) => self.kind = Some(kind),
+                RuleAction::DisplayName(name) => self.display_name = Some(name),
+            }
+        }
+    }
+
+    /// Prefixes the labels projects share with their parent directories, as few as it takes
+    /// to tell them apart, e.g. `client-a/api` and `client-b/api`.
+    pub fn disambiguate(projects: &mut [Project]) {
+        for project in projects.iter_mut() {
+            project.qualified_label = None;
+        }
+
+        let mut groups: HashMap<String, Vec<usize>> = HashMap::new();
+
+        for (index, project) in projects.iter().enumerate() {
+            groups
+                .entry(project.label().to_string())
+                .or_default()
+                .push(index);
+        }
+
+        for (label, indices) in groups.into_iter().filter(|(_, indices)| indices.len() > 1) {
+            // parent directories of each project, innermost first
+            let parents: Vec<Vec<String>> = indices
+                .iter()
+                .map(|&index| {
+                    projects[index]
+                        .path
+                        .parent()
+                        .into_iter()
+                        .flat_map(Path::components)
+                        .filter_map(|component| match component {
+                            Component::Normal(segment) => {
+                                Some(segment.to_string_lossy().to_string())
+                            }
+                            _ => None,
+                        })
+                        .rev()
+                        .collect()
+                })
+                .collect();
+
+            let most = parents.iter().map(Vec::len).max().unwrap_or_default();
+            let depth = (1..=most)
+                .find(|&depth| {
+                    let prefixes: HashSet<&[String]> = parents
+                        .iter()
+                        .map(|parents| &parents[..depth.min(parents.len())])
+                        .collect();
+
+                    prefixes.len() == parents.len()
+                })
+                .unwrap_or(most);
+
+            for (&index, parents) in indices.iter().zip(&parents) {
+                let mut segments: Vec<&str> = parents
+                    .iter()
+                    .take(depth)
+                    .rev()
+                    .map(String::as_str)
+                    .collect();
+                segments.push(&label);
+
+                projects[index].qualified_label = Some(segments.join("/"));
+            }
+        }
+    }
 }
 
 impl TryFrom<DirEntry> for Project {
     type Error = String;
 
     fn try_from(dir_entry: DirEntry) -> Result<Self, Self::Error> {
-        let name = dir_entry
+        let entry_path = dir_entry.path();
+        let path = entry_path
+            .canonicalize()
+            .map_err(|err| format!("Failed to canonicalize {:?}: {}", entry_path, err))?;
+
+        let name = path
             .file_name()
-            .to_str()
+            .and_then(|name| name.to_str())
             .ok_or_else(|| format!("Failed to convert {:?} to Project", dir_entry))?
             .to_string();
 
-        let modify = dir_entry
-            .metadata()
-            .map_err(|err| err.to_string())?
-            .modified()
-            .map_err(|err| err.to_string())?;
+        let metadata = path.metadata().map_err(|err| err.to_string())?;
+        let modify = metadata.modified().map_err(|err| err.to_string())?;
+        let created = metadata.created().ok();
+        let inode = Some(metadata.ino());
+        let device = Some(metadata.dev());
+        let remote = forge::origin_url(&path);
+
+        let read_only = mount::is_read_only(&path);
+
+        let lock_reason = std::fs::read_to_string(path.join(LOCK_FILE))
+            .ok()
+            .map(|reason| reason.trim().to_string());
+
+        let kind = ProjectKind::detect(&path);
+
+        let alternate_paths = if entry_path != path {
+            vec![entry_path]
+        } else {
+            vec![]
+        };
+
+        Ok(Project {
+            name,
+            path,
+            alternate_paths,
+            modify,
+            created,
+            inode,
+            device,
+            remote,
+            read_only,
+            lock_reason,
+            kind,
+            display_name: None,
+            rule_tags: vec![],
+            qualified_label: None,
+        })
+    }
+}
+
+/// Projects merged into a single entry per directory as they are added, remembering every other
+/// path a directory was reached by.
+///
+/// Directories are told apart by device and inode, as bind mounts of one directory have
+/// different canonical paths, falling back to the path where those are unknown.
+#[derive(Debug, Default)]
+pub struct DedupedProjects {
+    indices: HashMap<Result<(u64, u64), PathBuf>, usize>,
+    paths: HashSet<PathBuf>,
+    projects: Vec<Project>,
+}
+
+impl DedupedProjects {
+    pub fn extend(&mut self, projects: impl IntoIterator<Item = Project>) {
+        for project in projects {
+            let key = project.file_id().ok_or_else(|| project.path.clone());
+
+            match self.indices.get(&key) {
+                Some(&index) => {
+                    let merged = &mut self.projects[index];
+
+                    for path in iter::once(project.path).chain(project.alternate_paths) {
+                        if path != merged.path && !merged.alternate_paths.contains(&path) {
+                            merged.alternate_paths.push(path.clone());
+                            self.paths.insert(path);
+                        }
+                    }
+                }
+                None => {
+                    self.indices.insert(key, self.projects.len());
+                    self.paths.insert(project.path.clone());
+                    self.paths.extend(project.alternate_paths.iter().cloned());
+                    self.projects.push(project);
+                }
+            }
+        }
+    }
+
+    /// Whether one of the projects was reached by the path.
+    pub fn contains_path(&self, path: &Path) -> bool {
+        self.paths.contains(path)
+    }
+
+    pub fn projects(&self) -> &[Project] {
+        &self.projects
+    }
+
+    pub fn into_projects(self) -> Vec<Project> {
+        self.projects
+    }
+}
+
+/// Reads the projects below the root, handing them over in batches of `batch_size` as they are read.
+/// Directories whose name matches one of the `ignore` globs are skipped, together with everything in them.
+pub fn scan(
+    root: &Path,
+    depth: usize,
+    ignore: &GlobSet,
+    batch_size: usize,
+    mut on_batch: impl FnMut(Vec<Project>),
+) -> io::Result<()> {
+    let mut batch = Vec::with_capacity(batch_size);
+
+    discover(root, depth, ignore, &mut |entry| {
+        match Project::try_from(entry) {
+            Ok(project) => batch.push(project),
+            Err(err) => error!("{}", err),
+        }
+
+        if batch.len() >= batch_size {
+            on_batch(std::mem::take(&mut batch));
+        }
+    })?;
+
+    if !batch.is_empty() {
+        on_batch(batch);
+    }
+
+    Ok(())
+}
+
+/// Hands the entries of the root to `on_entry` while walking it, replacing directories that only
+/// group projects by the projects nested in them, down to `depth` levels below the root.
+fn discover(
+    root: &Path,
+    depth: usize,
+    ignore: &GlobSet,
+    on_entry: &mut impl FnMut(DirEntry),
+) -> io::Result<()> {
+    for entry in fs::read_dir(root)?.filter_map(|entry| entry.ok()) {
+        if is_ignored(&entry, ignore) {
+            continue;
+        }
+
+        if !nested_projects(&entry.path(), depth.saturating_sub(1), ignore, on_entry) {
+            on_entry(entry);
+        }
+    }
+
+    Ok(())
+}
+
+/// Directories whose entries are listed as projects, the root and the ones grouping projects below it.
+pub fn project_dirs(root: &Path, depth: usize, ignore: &GlobSet) -> Vec<PathBuf> {
+    let mut dirs = vec![root.to_path_buf()];
 
-        Ok(Project { name, modify })
+    if depth <= 1 {
+        return dirs;
     }
+
+    let Ok(read_dir) = fs::read_dir(root) else {
+        return dirs;
+    };
+
+    for entry in read_dir.filter_map(|entry| entry.ok()) {
+        let path = entry.path();
+
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+        if hidden || !path.is_dir() || is_ignored(&entry, ignore) || looks_like_project(&path) {
+            continue;
+        }
+
+        dirs.extend(project_dirs(&path, depth - 1, ignore));
+    }
+
+    dirs
+}
+
+/// Hands the project directories below the directory to `on_entry`, returning whether there were any.
+/// There are none if it looks like a project itself.
+fn nested_projects(
+    dir: &Path,
+    depth: usize,
+    ignore: &GlobSet,
+    on_entry: &mut impl FnMut(DirEntry),
+) -> bool {
+    if depth == 0 || !dir.is_dir() || looks_like_project(dir) {
+        return false;
+    }
+
+    let Ok(read_dir) = fs::read_dir(dir) else {
+        return false;
+    };
+
+    let mut found = false;
+
+    for entry in read_dir.filter_map(|entry| entry.ok()) {
+        let path = entry.path();
+
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+        if hidden || !path.is_dir() || is_ignored(&entry, ignore) {
+            continue;
+        }
+
+        if looks_like_project(&path) {
+            on_entry(entry);
+            found = true;
+        } else {
+            found |= nested_projects(&path, depth - 1, ignore, on_entry);
+        }
+    }
+
+    found
+}
+
+fn is_ignored(entry: &DirEntry, ignore: &GlobSet) -> bool {
+    let name = entry.file_name();
+    let name = name.to_string_lossy();
+
+    ignore.is_match(name.as_ref())
+}
+
+fn looks_like_project(dir: &Path) -> bool {
+    PROJECT_MARKERS
+        .iter()
+        .any(|marker| dir.join(marker).exists())
 }
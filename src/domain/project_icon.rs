@@ -0,0 +1,57 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Icons larger than this are ignored to keep the list cheap to render.
+const MAX_ICON_SIZE: u64 = 256 * 1024;
+
+/// Common places for a project's logo, relative to the project root.
+const CANDIDATES: [&str; 10] = [
+    "icon.svg",
+    "logo.svg",
+    "icon.png",
+    "logo.png",
+    "assets/icon.svg",
+    "assets/logo.svg",
+    "assets/icon.png",
+    "assets/logo.png",
+    ".github/logo.svg",
+    ".github/logo.png",
+];
+
+/// Looks for a logo in the usual asset locations, then for a logo image referenced by the README.
+pub fn find(project: &Path) -> Option<PathBuf> {
+    CANDIDATES
+        .iter()
+        .map(|candidate| project.join(candidate))
+        .chain(readme_images(project))
+        .find(|path| is_usable(path))
+}
+
+fn is_usable(path: &Path) -> bool {
+    let is_image = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| matches!(extension, "svg" | "png"));
+
+    is_image
+        && fs::metadata(path)
+            .is_ok_and(|metadata| metadata.is_file() && metadata.len() <= MAX_ICON_SIZE)
+}
+
+/// Relative image paths in the README whose name hints at a logo or icon.
+fn readme_images(project: &Path) -> Vec<PathBuf> {
+    let Ok(readme) = fs::read_to_string(project.join("README.md")) else {
+        return vec![];
+    };
+
+    readme
+        .split(['(', '"', '\''])
+        .filter_map(|part| part.split([')', '"', '\'']).next())
+        .filter(|link| !link.contains("://") && !link.contains(char::is_whitespace))
+        .filter(|link| {
+            let link = link.to_lowercase();
+            link.contains("logo") || link.contains("icon")
+        })
+        .map(|link| project.join(link.trim_start_matches("./")))
+        .collect()
+}
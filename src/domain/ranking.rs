@@ -0,0 +1,233 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+const DAY: u64 = 24 * 60 * 60;
+
+/// Projects whose usage is kept, the least used ones being forgotten first.
+pub const MAX_TRACKED_PROJECTS: usize = 500;
+
+/// How often and how recently a project was launched.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ProjectUsage {
+    project: PathBuf,
+    launches: u32,
+    /// Seconds since the unix epoch.
+    last_launch: u64,
+}
+
+impl ProjectUsage {
+    pub fn new(project: PathBuf) -> ProjectUsage {
+        ProjectUsage {
+            project,
+            launches: 0,
+            last_launch: 0,
+        }
+    }
+
+    pub fn project(&self) -> &Path {
+        &self.project
+    }
+
+    pub fn set_project(&mut self, project: PathBuf) {
+        self.project = project;
+    }
+
+    pub fn record_launch(&mut self, now: u64) {
+        self.launches += 1;
+        self.last_launch = now;
+    }
+
+    /// Launch count weighted by how long ago the last launch was, like browsers rank their history.
+    pub fn frecency(&self, now: u64) -> f64 {
+        let age = now.saturating_sub(self.last_launch);
+
+        let recency = match age {
+            age if age < 4 * DAY => 100.0,
+            age if age < 14 * DAY => 70.0,
+            age if age < 31 * DAY => 50.0,
+            age if age < 90 * DAY => 30.0,
+            _ => 10.0,
+        };
+
+        self.launches as f64 * recency
+    }
+}
+
+/// Frecency of every tracked project by its path, so ranking does not search the usage per project.
+pub fn frecencies(usage: &[ProjectUsage], now: u64) -> HashMap<&Path, f64> {
+    usage
+        .iter()
+        .map(|usage| (usage.project(), usage.frecency(now)))
+        .collect()
+}
+
+/// Forgets the least used projects beyond [`MAX_TRACKED_PROJECTS`].
+pub fn truncate(usage: &mut Vec<ProjectUsage>, now: u64) {
+    if usage.len() <= MAX_TRACKED_PROJECTS {
+        return;
+    }
+
+    usage.sort_by(|a, b| {
+        b.frecency(now)
+            .total_cmp(&a.frecency(now))
+            .then_with(|| b.last_launch.cmp(&a.last_launch))
+    });
+    usage.truncate(MAX_TRACKED_PROJECTS);
+}
+
+/// Scores how well `text` matches `query` typed with characters left out, from `0` to `1`.
+///
+/// Every query character has to appear in order, ignoring case and whitespace.
+/// Consecutive characters, word starts, short texts and matching case score higher,
+/// taking the best of all places the match can start at. `None` means no match.
+pub fn fuzzy_score(text: &str, query: &str) -> Option<f64> {
+    let exact_case = text.contains(query.trim());
+
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let query: Vec<char> = query
+        .to_lowercase()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+
+    if query.is_empty() {
+        return Some(1.0);
+    }
+
+    let matched = (0..text.len())
+        .filter(|&start| text[start] == query[0])
+        .filter_map(|start| alignment_score(&text, &query, start))
+        .max_by(f64::total_cmp)?;
+
+    let coverage = query.len() as f64 / text.len() as f64;
+    let case = if exact_case { 1.0 } else { 0.0 };
+
+    Some(0.75 * matched + 0.15 * coverage + 0.1 * case)
+}
+
+/// Scores matching the query greedily from `start` on, from `0` to `1`.
+fn alignment_score(text: &[char], query: &[char], start: usize) -> Option<f64> {
+    let mut score = 0.0;
+    let mut position = start;
+    let mut previous: Option<usize> = None;
+
+    for c in query {
+        let index = position + text[position..].iter().position(|t| t == c)?;
+
+        score += 1.0;
+
+        if previous.is_some_and(|previous| previous + 1 == index) {
+            score += 1.0;
+        }
+
+        if index == 0 || !text[index - 1].is_alphanumeric() {
+            score += 1.0;
+        }
+
+        previous = Some(index);
+        position = index + 1;
+    }
+
+    Some(score / (3.0 * query.len() as f64))
+}
+
+/// Blends the match score with the frecency relative to the most used match, `weight` being the share of the usage.
+pub fn blend(match_score: f64, frecency: f64, max_frecency: f64, weight: f64) -> f64 {
+    let usage = if max_frecency > 0.0 {
+        frecency / max_frecency
+    } else {
+        0.0
+    };
+
+    (1.0 - weight) * match_score + weight * usage
+}
+
+/// Orders pinned projects before all others whatever their modification time, then the better
+/// ranked ones, leaving ties to the sort order of the list.
+pub fn compare(
+    a_pinned: bool,
+    a_rank: Option<f64>,
+    b_pinned: bool,
+    b_rank: Option<f64>,
+) -> Ordering {
+    b_pinned.cmp(&a_pinned).then_with(|| {
+        b_rank
+            .unwrap_or_default()
+            .total_cmp(&a_rank.unwrap_or_default())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_matches_characters_in_order() {
+        assert!(fuzzy_score("project-overview", "prov").is_some());
+        assert!(fuzzy_score("project-overview", "pov").is_some());
+        assert!(fuzzy_score("project-overview", "vorp").is_none());
+        assert_eq!(fuzzy_score("project-overview", " "), Some(1.0));
+    }
+
+    #[test]
+    fn fuzzy_prefers_word_starts_and_consecutive_characters() {
+        let word_starts = fuzzy_score("project-overview", "po").unwrap();
+        let scattered = fuzzy_score("shipyard-logs", "po").unwrap();
+        assert!(word_starts > scattered);
+
+        let consecutive = fuzzy_score("overview", "over").unwrap();
+        let spread = fuzzy_score("oxvxexr", "over").unwrap();
+        assert!(consecutive > spread);
+
+        assert!(fuzzy_score("api", "api").unwrap() > fuzzy_score("api-gateway", "api").unwrap());
+        assert!(fuzzy_score("Api", "Api").unwrap() > fuzzy_score("Api", "api").unwrap());
+    }
+
+    #[test]
+    fn blend_weighs_usage_against_the_match() {
+        assert_eq!(blend(0.8, 50.0, 100.0, 0.0), 0.8);
+        assert_eq!(blend(0.8, 50.0, 100.0, 1.0), 0.5);
+        assert_eq!(blend(0.8, 50.0, 0.0, 0.5), 0.4);
+
+        // a frequently launched project overtakes a slightly better match
+        assert!(blend(0.7, 100.0, 100.0, 0.3) > blend(0.8, 0.0, 100.0, 0.3));
+    }
+
+    #[test]
+    fn pinned_projects_come_first_whatever_their_modification_time() {
+        // name, pinned, rank, modified
+        let mut projects = [
+            ("recent", false, None, 30),
+            ("old-pin", true, None, 10),
+            ("match", false, Some(0.9), 20),
+            ("new-pin", true, None, 40),
+        ];
+
+        projects.sort_by(|a, b| compare(a.1, a.2, b.1, b.2).then_with(|| b.3.cmp(&a.3)));
+
+        let names: Vec<&str> = projects.iter().map(|project| project.0).collect();
+        assert_eq!(names, ["new-pin", "old-pin", "match", "recent"]);
+    }
+
+    #[test]
+    fn truncate_forgets_the_least_used_projects() {
+        let now = 100 * DAY;
+        let mut usage: Vec<ProjectUsage> = (0..MAX_TRACKED_PROJECTS + 2)
+            .map(|index| {
+                let mut usage = ProjectUsage::new(PathBuf::from(format!("/code/{index}")));
+                usage.record_launch(now);
+                usage
+            })
+            .collect();
+        usage[0].last_launch = 0;
+
+        truncate(&mut usage, now);
+
+        assert_eq!(usage.len(), MAX_TRACKED_PROJECTS);
+        assert!(!usage
+            .iter()
+            .any(|usage| usage.project() == Path::new("/code/0")));
+    }
+}
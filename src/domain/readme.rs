@@ -0,0 +1,127 @@
+use std::path::Path;
+
+const FILE_NAMES: [&str; 4] = ["README.md", "readme.md", "Readme.md", "README"];
+
+/// Hosts of badge images, also matching any image url mentioning a badge.
+const BADGE_HOSTS: [&str; 7] = [
+    "shields.io",
+    "badge",
+    "travis-ci",
+    "codecov.io",
+    "coveralls.io",
+    "badgen.net",
+    "docs.rs",
+];
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ReadmeBadgeKind {
+    Build,
+    Coverage,
+    Version,
+    Other,
+}
+
+/// A shield-style badge image of the README, e.g. `[![build](https://…/badge.svg)](https://…)`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ReadmeBadge {
+    kind: ReadmeBadgeKind,
+    label: String,
+    link: Option<String>,
+}
+
+impl ReadmeBadge {
+    pub fn kind(&self) -> ReadmeBadgeKind {
+        self.kind
+    }
+
+    /// Alt text of the badge image.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Page the badge links to, if it is wrapped in a link.
+    pub fn link(&self) -> Option<&str> {
+        self.link.as_deref()
+    }
+}
+
+/// Reads the badges of the project's README, none if it has no README.
+pub fn badges(project: &Path) -> Vec<ReadmeBadge> {
+    FILE_NAMES
+        .iter()
+        .find_map(|file_name| std::fs::read_to_string(project.join(file_name)).ok())
+        .map(|readme| parse(&readme))
+        .unwrap_or_default()
+}
+
+/// Collects the markdown images pointing to badge hosts, with the links wrapping them.
+fn parse(readme: &str) -> Vec<ReadmeBadge> {
+    let mut badges = vec![];
+    let mut rest = readme;
+
+    while let Some(start) = rest.find("![") {
+        let linked = rest[..start].ends_with('[');
+        let after = &rest[start + 2..];
+
+        let Some((alt, image, mut after)) = split_link(after) else {
+            rest = after;
+            continue;
+        };
+
+        let mut link = None;
+
+        if linked {
+            if let Some((target, remaining)) = after.strip_prefix("](").and_then(split_target) {
+                link = Some(target.to_string());
+                after = remaining;
+            }
+        }
+
+        if BADGE_HOSTS.iter().any(|host| image.contains(host)) {
+            badges.push(ReadmeBadge {
+                kind: kind(&format!("{alt} {image}").to_lowercase()),
+                label: alt.to_string(),
+                link,
+            });
+        }
+
+        rest = after;
+    }
+
+    badges
+}
+
+/// Splits `alt](target) rest` into its parts.
+fn split_link(text: &str) -> Option<(&str, &str, &str)> {
+    let (alt, rest) = text.split_once("](")?;
+
+    if alt.contains('\n') {
+        return None;
+    }
+
+    let (target, rest) = split_target(rest)?;
+    Some((alt, target, rest))
+}
+
+/// Splits `target "title") rest` into the target and the rest after the closing parenthesis.
+fn split_target(text: &str) -> Option<(&str, &str)> {
+    let (target, rest) = text.split_once(')')?;
+    let target = target.split_whitespace().next()?;
+    Some((target, rest))
+}
+
+fn kind(text: &str) -> ReadmeBadgeKind {
+    let mentions = |words: &[&str]| words.iter().any(|word| text.contains(word));
+
+    if mentions(&["coverage", "codecov", "coveralls"]) {
+        ReadmeBadgeKind::Coverage
+    } else if mentions(&[
+        "build", "workflow", "actions", "pipeline", "travis", "circleci",
+    ]) {
+        ReadmeBadgeKind::Build
+    } else if mentions(&["version", "release", "crates.io", "npm", "pypi", "/v/"]) {
+        ReadmeBadgeKind::Version
+    } else {
+        ReadmeBadgeKind::Other
+    }
+}
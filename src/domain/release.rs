@@ -0,0 +1,79 @@
+use crate::domain::git;
+use chrono::NaiveDate;
+use std::path::Path;
+
+const CHANGELOG_FILES: [&str; 3] = ["CHANGELOG.md", "changelog.md", "CHANGELOG"];
+
+/// Latest released version of a project.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Release {
+    version: String,
+    /// Seconds since the unix epoch, `None` if the changelog does not date it.
+    released: Option<u64>,
+    /// Commits after the release tag, `None` if the release is only known from the changelog.
+    unreleased_commits: Option<usize>,
+}
+
+impl Release {
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    pub fn released(&self) -> Option<u64> {
+        self.released
+    }
+
+    pub fn unreleased_commits(&self) -> Option<usize> {
+        self.unreleased_commits
+    }
+
+    pub fn has_unreleased_changes(&self) -> bool {
+        self.unreleased_commits.is_some_and(|commits| commits > 0)
+    }
+}
+
+/// Finds the latest release from the git tags, falling back to the first version in the changelog.
+pub fn detect(project: &Path) -> Option<Release> {
+    if let Some((tag, released)) = git::latest_tag(project) {
+        return Some(Release {
+            unreleased_commits: git::commits_since(project, &tag),
+            version: tag,
+            released: Some(released),
+        });
+    }
+
+    let changelog = CHANGELOG_FILES
+        .iter()
+        .find_map(|file_name| std::fs::read_to_string(project.join(file_name)).ok())?;
+
+    changelog.lines().find_map(parse_heading)
+}
+
+/// Parses version headings like `## [1.2.0] - 2024-05-01` or `## v1.2.0`, skipping `Unreleased`.
+fn parse_heading(line: &str) -> Option<Release> {
+    let heading = line.strip_prefix('#')?.trim_start_matches('#').trim();
+    let mut words = heading.split_whitespace();
+
+    let version = words.next()?.trim_matches(|c| c == '[' || c == ']');
+
+    if !version
+        .trim_start_matches('v')
+        .starts_with(|c: char| c.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let released = words
+        .filter_map(|word| {
+            NaiveDate::parse_from_str(word.trim_matches(|c| c == '(' || c == ')'), "%Y-%m-%d").ok()
+        })
+        .next()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|date| date.and_utc().timestamp().max(0) as u64);
+
+    Some(Release {
+        version: version.to_string(),
+        released,
+        unreleased_commits: None,
+    })
+}
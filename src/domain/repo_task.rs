@@ -0,0 +1,99 @@
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// A command defined by the project itself, like a `package.json` script or a `just` recipe.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RepoTask {
+    command: String,
+}
+
+impl RepoTask {
+    fn new(command: String) -> RepoTask {
+        RepoTask { command }
+    }
+
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+
+    /// Collects the tasks of every build tool used by the project.
+    pub fn detect(project: &Path) -> Vec<RepoTask> {
+        let mut tasks = vec![];
+
+        tasks.extend(npm_scripts(project));
+        tasks.extend(just_recipes(project));
+        tasks.extend(make_targets(project));
+
+        if project.join("Cargo.toml").is_file() {
+            tasks.extend(
+                ["cargo build", "cargo test", "cargo run"]
+                    .map(|command| RepoTask::new(command.to_string())),
+            );
+        }
+
+        tasks
+    }
+}
+
+fn npm_scripts(project: &Path) -> Vec<RepoTask> {
+    let Ok(content) = fs::read_to_string(project.join("package.json")) else {
+        return vec![];
+    };
+
+    let Ok(package) = serde_json::from_str::<serde_json::Value>(&content) else {
+        return vec![];
+    };
+
+    package
+        .get("scripts")
+        .and_then(|scripts| scripts.as_object())
+        .map(|scripts| {
+            scripts
+                .keys()
+                .map(|name| RepoTask::new(format!("npm run {name}")))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn just_recipes(project: &Path) -> Vec<RepoTask> {
+    if !project.join("justfile").is_file() && !project.join("Justfile").is_file() {
+        return vec![];
+    }
+
+    let Ok(output) = Command::new("just")
+        .arg("--summary")
+        .current_dir(project)
+        .stderr(Stdio::null())
+        .output()
+    else {
+        return vec![];
+    };
+
+    String::from_utf8_lossy(&output.stdout)
+        .split_whitespace()
+        .map(|recipe| RepoTask::new(format!("just {recipe}")))
+        .collect()
+}
+
+fn make_targets(project: &Path) -> Vec<RepoTask> {
+    let Ok(content) = fs::read_to_string(project.join("Makefile")) else {
+        return vec![];
+    };
+
+    content
+        .lines()
+        .filter(|line| !line.starts_with(['\t', ' ', '.', '#']))
+        .filter_map(|line| line.split_once(':'))
+        .filter(|(_, rest)| !rest.starts_with('='))
+        .map(|(target, _)| target.trim())
+        .filter(|target| {
+            !target.is_empty()
+                && target
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_'))
+        })
+        .map(|target| RepoTask::new(format!("make {target}")))
+        .collect()
+}
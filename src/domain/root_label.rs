@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Friendly name of a project root, e.g. "Work" or "NAS", shown instead of its path.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RootLabel {
+    root: PathBuf,
+    label: String,
+}
+
+impl RootLabel {
+    pub fn new(root: PathBuf, label: String) -> RootLabel {
+        RootLabel { root, label }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+}
@@ -0,0 +1,298 @@
+use crate::domain::project::{Project, ProjectKind};
+use globset::{Glob, GlobMatcher, GlobSet, GlobSetBuilder};
+use log::warn;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::mem;
+
+/// What the pattern of a rule is matched against.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum RuleTarget {
+    /// Name of the project directory.
+    #[default]
+    Name,
+    /// Full path of the project.
+    Path,
+}
+
+impl RuleTarget {
+    pub const ALL: [RuleTarget; 2] = [RuleTarget::Name, RuleTarget::Path];
+}
+
+/// How the pattern of a rule is written.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub enum RuleSyntax {
+    /// `*` matches any characters including `/`, `?` a single one.
+    #[default]
+    Glob,
+    /// Regular expression, matching anywhere unless anchored with `^` and `$`.
+    Regex,
+}
+
+impl RuleSyntax {
+    pub const ALL: [RuleSyntax; 2] = [RuleSyntax::Glob, RuleSyntax::Regex];
+}
+
+/// Metadata a rule assigns to the projects it matches.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum RuleAction {
+    Tag(String),
+    Kind(ProjectKind),
+    DisplayName(String),
+}
+
+impl RuleAction {
+    /// One action of every kind with an empty value, in the order the rule editor lists them.
+    pub fn all() -> [RuleAction; 3] {
+        [
+            RuleAction::Tag(String::new()),
+            RuleAction::Kind(ProjectKind::ALL[0]),
+            RuleAction::DisplayName(String::new()),
+        ]
+    }
+
+    /// Whether both assign the same metadata, whatever its value.
+    pub fn same_kind(&self, other: &RuleAction) -> bool {
+        mem::discriminant(self) == mem::discriminant(other)
+    }
+
+    /// Whether there is something to assign, tags and display names not being blank.
+    pub fn is_complete(&self) -> bool {
+        match self {
+            RuleAction::Tag(value) | RuleAction::DisplayName(value) => !value.trim().is_empty(),
+            RuleAction::Kind(_) => true,
+        }
+    }
+}
+
+/// Assigns metadata to every project matching a pattern while scanning, e.g. tagging `*/clients/*` as client work.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Rule {
+    target: RuleTarget,
+    /// Rules saved before regular expressions were supported are globs.
+    #[serde(default)]
+    syntax: RuleSyntax,
+    pattern: String,
+    action: RuleAction,
+}
+
+impl Rule {
+    pub fn new(
+        target: RuleTarget,
+        syntax: RuleSyntax,
+        pattern: String,
+        action: RuleAction,
+    ) -> Rule {
+        Rule {
+            target,
+            syntax,
+            pattern,
+            action,
+        }
+    }
+
+    pub fn target(&self) -> RuleTarget {
+        self.target
+    }
+
+    pub fn syntax(&self) -> RuleSyntax {
+        self.syntax
+    }
+
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    pub fn action(&self) -> &RuleAction {
+        &self.action
+    }
+
+    /// Why the pattern cannot be used with the syntax, `None` if it can.
+    pub fn pattern_error(syntax: RuleSyntax, pattern: &str) -> Option<String> {
+        Matcher::new(syntax, pattern).err()
+    }
+}
+
+/// Rules with their patterns compiled, so matching every project of a scan stays cheap.
+pub struct RuleSet {
+    rules: Vec<(Matcher, Rule)>,
+}
+
+impl RuleSet {
+    /// Leaves out rules whose pattern does not compile, which the rule editor does not let through.
+    pub fn new(rules: &[Rule]) -> RuleSet {
+        let rules = rules
+            .iter()
+            .filter_map(|rule| match Matcher::new(rule.syntax, &rule.pattern) {
+                Ok(matcher) => Some((matcher, rule.clone())),
+                Err(err) => {
+                    warn!("skipping the rule for {:?}: {err}", rule.pattern);
+                    None
+                }
+            })
+            .collect();
+
+        RuleSet { rules }
+    }
+
+    /// Actions of the rules matching the project, in the order of the rules.
+    pub fn actions<'a>(&'a self, project: &'a Project) -> impl Iterator<Item = &'a RuleAction> {
+        let name = project.name().as_str();
+        let path = project.path().to_string_lossy();
+
+        self.rules
+            .iter()
+            .filter(move |(matcher, rule)| match rule.target {
+                RuleTarget::Name => matcher.is_match(name),
+                RuleTarget::Path => matcher.is_match(&path),
+            })
+            .map(|(_, rule)| &rule.action)
+    }
+}
+
+enum Matcher {
+    Glob(GlobMatcher),
+    Regex(Regex),
+}
+
+impl Matcher {
+    fn new(syntax: RuleSyntax, pattern: &str) -> Result<Matcher, String> {
+        match syntax {
+            RuleSyntax::Glob => Glob::new(pattern)
+                .map(|glob| Matcher::Glob(glob.compile_matcher()))
+                .map_err(|err| err.kind().to_string()),
+            RuleSyntax::Regex => Regex::new(pattern)
+                .map(Matcher::Regex)
+                .map_err(|err| err.to_string()),
+        }
+    }
+
+    fn is_match(&self, text: &str) -> bool {
+        match self {
+            Matcher::Glob(glob) => glob.is_match(text),
+            Matcher::Regex(regex) => regex.is_match(text),
+        }
+    }
+}
+
+/// Globs matching a whole name, `*` standing for any characters including `/` and `?` for a single one.
+///
+/// Invalid patterns are left out with a warning.
+pub fn glob_set(patterns: &[String]) -> GlobSet {
+    let mut builder = GlobSetBuilder::new();
+
+    for pattern in patterns {
+        match Glob::new(pattern) {
+            Ok(glob) => {
+                builder.add(glob);
+            }
+            Err(err) => warn!("skipping the glob {pattern:?}: {}", err.kind()),
+        }
+    }
+
+    builder.build().unwrap_or_else(|err| {
+        warn!("failed to compile the globs {patterns:?}: {err}");
+        GlobSet::empty()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn project(path: &str) -> Project {
+        serde_json::from_value(json!({
+            "name": path.rsplit('/').next().unwrap(),
+            "path": path,
+            "alternate_paths": [],
+            "modify": { "secs_since_epoch": 0, "nanos_since_epoch": 0 },
+            "read_only": false,
+        }))
+        .unwrap()
+    }
+
+    fn tag(target: RuleTarget, syntax: RuleSyntax, pattern: &str) -> Rule {
+        Rule::new(
+            target,
+            syntax,
+            pattern.to_string(),
+            RuleAction::Tag("client".to_string()),
+        )
+    }
+
+    fn matches(rule: Rule, path: &str) -> bool {
+        RuleSet::new(&[rule])
+            .actions(&project(path))
+            .next()
+            .is_some()
+    }
+
+    #[test]
+    fn matches_globs_across_separators() {
+        let rule = tag(RuleTarget::Path, RuleSyntax::Glob, "*/clients/*");
+
+        assert!(matches(rule.clone(), "/home/me/clients/acme"));
+        assert!(!matches(rule, "/home/me/oss/acme"));
+        assert!(matches(
+            tag(RuleTarget::Name, RuleSyntax::Glob, "app-?"),
+            "/code/app-1"
+        ));
+        assert!(!matches(
+            tag(RuleTarget::Name, RuleSyntax::Glob, "app-?"),
+            "/code/app-10"
+        ));
+    }
+
+    #[test]
+    fn matches_long_names_quickly() {
+        let rule = tag(RuleTarget::Name, RuleSyntax::Glob, &"*a".repeat(20));
+
+        assert!(!matches(rule, &format!("/code/{}b", "a".repeat(60))));
+    }
+
+    #[test]
+    fn matches_regular_expressions() {
+        let rule = tag(RuleTarget::Name, RuleSyntax::Regex, r"^(api|web)-\d+$");
+
+        assert!(matches(rule.clone(), "/code/api-2"));
+        assert!(!matches(rule, "/code/api-next"));
+        assert!(matches(
+            tag(RuleTarget::Path, RuleSyntax::Regex, "clients"),
+            "/home/me/clients/acme"
+        ));
+    }
+
+    #[test]
+    fn reports_invalid_patterns() {
+        assert!(Rule::pattern_error(RuleSyntax::Regex, "(api").is_some());
+        assert!(Rule::pattern_error(RuleSyntax::Glob, "[a").is_some());
+        assert!(Rule::pattern_error(RuleSyntax::Glob, "*/clients/*").is_none());
+        assert!(
+            RuleSet::new(&[tag(RuleTarget::Name, RuleSyntax::Regex, "(api")])
+                .rules
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn reads_rules_without_syntax_as_globs() {
+        let rule: Rule = serde_json::from_value(json!({
+            "target": "Name",
+            "pattern": "app-*",
+            "action": { "Tag": "client" },
+        }))
+        .unwrap();
+
+        assert_eq!(rule.syntax(), RuleSyntax::Glob);
+    }
+
+    #[test]
+    fn globs_match_whole_names() {
+        let ignore = glob_set(&["target".to_string(), ".*".to_string(), "[".to_string()]);
+
+        assert!(ignore.is_match("target"));
+        assert!(ignore.is_match(".git"));
+        assert!(!ignore.is_match("targets"));
+    }
+}
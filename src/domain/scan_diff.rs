@@ -0,0 +1,230 @@
+use crate::domain::project::Project;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+/// Projects that appeared, disappeared or were renamed between two scans.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ScanDiff {
+    added: Vec<PathBuf>,
+    removed: Vec<PathBuf>,
+    /// Old and new path of projects whose directory kept its device and inode under another path.
+    renamed: Vec<(PathBuf, PathBuf)>,
+    /// Old and new path of projects sharing their `origin` remote, which a fresh clone of another
+    /// project does too, so they are only pointed out.
+    possibly_renamed: Vec<(PathBuf, PathBuf)>,
+}
+
+impl ScanDiff {
+    pub fn between(previous: &[Project], current: &[Project]) -> ScanDiff {
+        let previous_paths: HashSet<&PathBuf> = previous.iter().map(Project::path).collect();
+        let current_paths: HashSet<&PathBuf> = current.iter().map(Project::path).collect();
+
+        // taken out once they turn out to be renamed
+        let mut added: Vec<Option<&Project>> = current
+            .iter()
+            .filter(|project| !previous_paths.contains(project.path()))
+            .map(Some)
+            .collect();
+        let mut removed: Vec<&Project> = previous
+            .iter()
+            .filter(|old| !current_paths.contains(old.path()))
+            .collect();
+
+        let mut by_file_id: HashMap<(u64, u64), usize> = HashMap::new();
+        let mut by_remote: HashMap<&str, Vec<usize>> = HashMap::new();
+
+        for (index, project) in added.iter().enumerate() {
+            let Some(project) = project else {
+                continue;
+            };
+
+            if let Some(file_id) = project.file_id() {
+                by_file_id.entry(file_id).or_insert(index);
+            }
+            if let Some(remote) = project.remote() {
+                by_remote.entry(remote).or_default().push(index);
+            }
+        }
+
+        let mut renamed = vec![];
+        let mut possibly_renamed = vec![];
+
+        // the inode survives a rename on the same filesystem
+        removed.retain(|old| {
+            let Some(new) = old
+                .file_id()
+                .and_then(|file_id| by_file_id.get(&file_id))
+                .and_then(|index| added[*index].take())
+            else {
+                return true;
+            };
+
+            renamed.push((old.path().clone(), new.path().clone()));
+            false
+        });
+
+        // the remote a copy to another filesystem, but just as well an unrelated clone
+        removed.retain(|old| {
+            let Some(new) = old
+                .remote()
+                .and_then(|remote| by_remote.get(remote))
+                .and_then(|indices| indices.iter().find(|index| added[**index].is_some()))
+                .and_then(|index| added[*index].take())
+            else {
+                return true;
+            };
+
+            possibly_renamed.push((old.path().clone(), new.path().clone()));
+            false
+        });
+
+        ScanDiff {
+            added: added
+                .into_iter()
+                .flatten()
+                .map(|project| project.path().clone())
+                .collect(),
+            removed: removed
+                .iter()
+                .map(|project| project.path().clone())
+                .collect(),
+            renamed,
+            possibly_renamed,
+        }
+    }
+
+    pub fn added(&self) -> &[PathBuf] {
+        self.added.as_slice()
+    }
+
+    pub fn removed(&self) -> &[PathBuf] {
+        self.removed.as_slice()
+    }
+
+    pub fn renamed(&self) -> &[(PathBuf, PathBuf)] {
+        self.renamed.as_slice()
+    }
+
+    pub fn possibly_renamed(&self) -> &[(PathBuf, PathBuf)] {
+        self.possibly_renamed.as_slice()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.renamed.is_empty()
+            && self.possibly_renamed.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn project(path: &str, inode: Option<u64>, remote: Option<&str>) -> Project {
+        serde_json::from_value(serde_json::json!({
+            "name": Path::new(path).file_name().unwrap().to_str().unwrap(),
+            "path": path,
+            "alternate_paths": [],
+            "modify": { "secs_since_epoch": 0, "nanos_since_epoch": 0 },
+            "inode": inode,
+            "device": inode.map(|_| 1),
+            "remote": remote,
+            "read_only": false,
+        }))
+        .unwrap()
+    }
+
+    fn paths(paths: &[&str]) -> Vec<PathBuf> {
+        paths.iter().map(PathBuf::from).collect()
+    }
+
+    #[test]
+    fn unchanged_scans_have_no_diff() {
+        let projects = [
+            project("/code/a", Some(1), None),
+            project("/code/b", None, None),
+        ];
+
+        assert!(ScanDiff::between(&projects, &projects).is_empty());
+    }
+
+    #[test]
+    fn lists_added_and_removed_projects() {
+        let previous = [
+            project("/code/a", Some(1), None),
+            project("/code/b", Some(2), None),
+        ];
+        let current = [
+            project("/code/b", Some(2), None),
+            project("/code/c", Some(3), None),
+        ];
+
+        let diff = ScanDiff::between(&previous, &current);
+
+        assert_eq!(diff.added(), paths(&["/code/c"]));
+        assert_eq!(diff.removed(), paths(&["/code/a"]));
+        assert!(diff.renamed().is_empty());
+    }
+
+    #[test]
+    fn detects_renames_by_inode() {
+        let previous = [project("/code/old", Some(1), None)];
+        let current = [
+            project("/code/other", Some(2), None),
+            project("/code/new", Some(1), None),
+        ];
+
+        let diff = ScanDiff::between(&previous, &current);
+
+        assert_eq!(
+            diff.renamed(),
+            [(PathBuf::from("/code/old"), PathBuf::from("/code/new"))]
+        );
+        assert_eq!(diff.added(), paths(&["/code/other"]));
+        assert!(diff.removed().is_empty());
+    }
+
+    #[test]
+    fn matches_each_added_project_once() {
+        let previous = [
+            project("/code/a", None, Some("git@host:me/app.git")),
+            project("/code/b", None, Some("git@host:me/app.git")),
+            project("/code/c", None, Some("git@host:me/app.git")),
+        ];
+        let current = [
+            project("/code/d", None, Some("git@host:me/app.git")),
+            project("/code/e", None, Some("git@host:me/app.git")),
+        ];
+
+        let diff = ScanDiff::between(&previous, &current);
+
+        assert_eq!(diff.possibly_renamed().len(), 2);
+        assert_eq!(diff.removed(), paths(&["/code/c"]));
+        assert!(diff.added().is_empty());
+    }
+
+    #[test]
+    fn only_trusts_the_inode_for_renames() {
+        let previous = [
+            project("/code/old", Some(1), Some("git@host:me/app.git")),
+            project("/code/gone", Some(2), Some("git@host:me/lib.git")),
+        ];
+        let current = [
+            project("/code/new", Some(1), Some("git@host:me/app.git")),
+            project("/code/clone", Some(3), Some("git@host:me/lib.git")),
+        ];
+
+        let diff = ScanDiff::between(&previous, &current);
+
+        assert_eq!(
+            diff.renamed(),
+            [(PathBuf::from("/code/old"), PathBuf::from("/code/new"))]
+        );
+        assert_eq!(
+            diff.possibly_renamed(),
+            [(PathBuf::from("/code/gone"), PathBuf::from("/code/clone"))]
+        );
+    }
+}
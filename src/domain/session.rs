@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// A named set of projects, each opened with its own program, that are launched together.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    name: String,
+    entries: Vec<SessionEntry>,
+    /// Whether an autostart entry launches this session on login.
+    #[serde(default)]
+    autostart: bool,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SessionEntry {
+    project: PathBuf,
+    program: String,
+}
+
+impl Session {
+    pub fn new(name: String) -> Session {
+        Session {
+            name,
+            entries: vec![],
+            autostart: false,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn autostart(&self) -> bool {
+        self.autostart
+    }
+
+    pub fn set_autostart(&mut self, autostart: bool) {
+        self.autostart = autostart;
+    }
+
+    pub fn entries(&self) -> &[SessionEntry] {
+        self.entries.as_slice()
+    }
+
+    pub fn contains(&self, project: &Path, program: &str) -> bool {
+        self.entries
+            .iter()
+            .any(|entry| entry.project == project && entry.program == program)
+    }
+
+    pub fn add(&mut self, project: PathBuf, program: String) {
+        if !self.contains(&project, &program) {
+            self.entries.push(SessionEntry { project, program });
+        }
+    }
+
+    pub fn remove(&mut self, project: &Path, program: &str) {
+        self.entries
+            .retain(|entry| entry.project != project || entry.program != program);
+    }
+
+    pub fn remove_program(&mut self, program: &str) {
+        self.entries.retain(|entry| entry.program != program);
+    }
+
+    pub fn move_project(&mut self, project: &Path, path: &Path) {
+        for entry in &mut self.entries {
+            if entry.project == project {
+                entry.project = path.to_path_buf();
+            }
+        }
+    }
+
+    pub fn rename_program(&mut self, program: &str, name: &str) {
+        for entry in &mut self.entries {
+            if entry.program == program {
+                entry.program = name.to_string();
+            }
+        }
+    }
+}
+
+impl SessionEntry {
+    pub fn project(&self) -> &Path {
+        &self.project
+    }
+
+    pub fn program(&self) -> &str {
+        &self.program
+    }
+}
@@ -0,0 +1,96 @@
+use crate::domain::annotation::Annotation;
+use crate::domain::ownership::Ownership;
+use crate::domain::project::Project;
+use crate::domain::stats::ProjectStats;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Metadata of every project of a root, for browsing it on machines without access to it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    root: PathBuf,
+    created: SystemTime,
+    projects: Vec<SnapshotProject>,
+}
+
+impl Snapshot {
+    pub fn new(root: PathBuf, projects: Vec<SnapshotProject>) -> Snapshot {
+        Snapshot {
+            root,
+            created: SystemTime::now(),
+            projects,
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn created(&self) -> SystemTime {
+        self.created
+    }
+
+    pub fn projects(&self) -> &[SnapshotProject] {
+        self.projects.as_slice()
+    }
+
+    pub fn project(&self, path: &Path) -> Option<&SnapshotProject> {
+        self.projects
+            .iter()
+            .find(|project| project.project.path() == path)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotProject {
+    project: Project,
+    #[serde(default)]
+    annotation: Annotation,
+    #[serde(default)]
+    stats: Option<ProjectStats>,
+    #[serde(default)]
+    ownership: Option<Ownership>,
+}
+
+impl SnapshotProject {
+    pub fn new(
+        project: Project,
+        annotation: Annotation,
+        stats: Option<ProjectStats>,
+        ownership: Option<Ownership>,
+    ) -> SnapshotProject {
+        SnapshotProject {
+            project,
+            annotation,
+            stats,
+            ownership,
+        }
+    }
+
+    pub fn project(&self) -> &Project {
+        &self.project
+    }
+
+    pub fn annotation(&self) -> &Annotation {
+        &self.annotation
+    }
+
+    pub fn stats(&self) -> Option<&ProjectStats> {
+        self.stats.as_ref()
+    }
+
+    pub fn ownership(&self) -> Option<&Ownership> {
+        self.ownership.as_ref()
+    }
+}
+
+pub fn save(path: &Path, snapshot: &Snapshot) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(snapshot).map_err(|err| err.to_string())?;
+    std::fs::write(path, content).map_err(|err| err.to_string())
+}
+
+pub fn load(path: &Path) -> Result<Snapshot, String> {
+    let content = std::fs::read_to_string(path).map_err(|err| err.to_string())?;
+    serde_json::from_str(&content).map_err(|err| format!("Failed to parse {:?}: {}", path, err))
+}
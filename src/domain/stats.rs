@@ -0,0 +1,162 @@
+use crate::domain::git;
+use crate::domain::project::ProjectKind;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Folders holding build output or downloaded dependencies that can be regenerated.
+pub const ARTIFACT_DIRS: [&str; 7] = [
+    "target",
+    "node_modules",
+    "build",
+    "dist",
+    ".venv",
+    "__pycache__",
+    ".gradle",
+];
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ProjectStats {
+    dirty: Option<bool>,
+    artifact_size: u64,
+    #[serde(default)]
+    size: u64,
+}
+
+impl ProjectStats {
+    pub fn collect(project: &Path, kind: Option<ProjectKind>) -> ProjectStats {
+        ProjectStats {
+            dirty: git::is_dirty(project),
+            artifact_size: artifacts_size(project, &artifact_dirs(project, kind)),
+            size: dir_size(project),
+        }
+    }
+
+    /// Whether the repository has uncommitted changes, `None` outside of repositories.
+    pub fn dirty(&self) -> Option<bool> {
+        self.dirty
+    }
+
+    /// Bytes used by the project's artifact folders.
+    pub fn artifact_size(&self) -> u64 {
+        self.artifact_size
+    }
+
+    /// Bytes used by the whole project, artifacts included.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+/// How many projects of a kind there are and how much disk they use together.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct KindStats {
+    /// `None` for the projects no kind was detected for.
+    kind: Option<ProjectKind>,
+    count: usize,
+    size: u64,
+}
+
+impl KindStats {
+    /// Sums up the projects by kind, the most common kind first.
+    ///
+    /// Projects whose statistics are not collected yet are counted without their size.
+    pub fn by_kind<'a>(
+        projects: impl IntoIterator<Item = (Option<ProjectKind>, Option<&'a ProjectStats>)>,
+    ) -> Vec<KindStats> {
+        let mut kinds: Vec<KindStats> = vec![];
+
+        for (kind, stats) in projects {
+            let size = stats.map(ProjectStats::size).unwrap_or_default();
+
+            match kinds.iter_mut().find(|existing| existing.kind == kind) {
+                Some(existing) => {
+                    existing.count += 1;
+                    existing.size += size;
+                }
+                None => kinds.push(KindStats {
+                    kind,
+                    count: 1,
+                    size,
+                }),
+            }
+        }
+
+        kinds.sort_by(|a, b| b.count.cmp(&a.count).then(b.size.cmp(&a.size)));
+        kinds
+    }
+
+    pub fn kind(&self) -> Option<ProjectKind> {
+        self.kind
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+}
+
+/// Artifact folders of the project that are safe to delete, leaving out symlinked ones.
+///
+/// Folders like `build` or `dist` are often committed sources, so only the kind's own artifact
+/// folder is taken as is, any other only if git does not track anything in it.
+pub fn artifact_dirs(project: &Path, kind: Option<ProjectKind>) -> Vec<&'static str> {
+    ARTIFACT_DIRS
+        .into_iter()
+        .filter(|dir| {
+            project
+                .join(dir)
+                .symlink_metadata()
+                .is_ok_and(|metadata| metadata.is_dir())
+        })
+        .filter(|dir| {
+            kind.and_then(|kind| kind.artifact_dir()) == Some(*dir)
+                || git::is_tracked(project, dir) == Some(false)
+        })
+        .collect()
+}
+
+/// Bytes used by the given artifact folders of the project.
+pub fn artifacts_size(project: &Path, dirs: &[&str]) -> u64 {
+    dirs.iter().map(|dir| dir_size(&project.join(dir))).sum()
+}
+
+/// Deletes the given artifact folders of the project, as picked by [`artifact_dirs`],
+/// returning the bytes freed.
+pub fn clean_artifacts(project: &Path, dirs: &[&str]) -> io::Result<u64> {
+    let mut freed = 0;
+
+    for dir in dirs {
+        let dir = project.join(dir);
+        let size = dir_size(&dir);
+
+        fs::remove_dir_all(dir)?;
+        freed += size;
+    }
+
+    Ok(freed)
+}
+
+/// Sums up the file sizes below `path` without following symlinks.
+fn dir_size(path: &Path) -> u64 {
+    let Ok(metadata) = path.symlink_metadata() else {
+        return 0;
+    };
+
+    if !metadata.is_dir() {
+        return metadata.len();
+    }
+
+    fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| dir_size(&entry.path()))
+                .sum()
+        })
+        .unwrap_or(0)
+}
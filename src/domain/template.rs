@@ -0,0 +1,204 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Token replaced with the new project's name in file names and contents.
+const NAME_TOKEN: &str = "%name%";
+/// Token replaced with the new project's path in hook commands.
+const PATH_TOKEN: &str = "%path%";
+/// File inside a template listing the commands to run after creating a project, one per line.
+const HOOK_FILE: &str = ".project-overview-hook";
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Template {
+    name: String,
+    path: PathBuf,
+    hook: Vec<String>,
+}
+
+impl Template {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    pub fn hook(&self) -> &[String] {
+        self.hook.as_slice()
+    }
+
+    /// Lists every folder inside `templates_path` as a template.
+    pub fn list(templates_path: &Path) -> Vec<Template> {
+        let Ok(entries) = fs::read_dir(templates_path) else {
+            return vec![];
+        };
+
+        let mut templates: Vec<Template> = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| {
+                Some(Template {
+                    name: entry.file_name().to_str()?.to_string(),
+                    hook: read_hook(&entry.path()),
+                    path: entry.path(),
+                })
+            })
+            .collect();
+
+        templates.sort_by(|a, b| a.name.cmp(&b.name));
+        templates
+    }
+}
+
+/// Creates a new project at `target` by copying `template` (or an empty folder if `None`),
+/// replacing the name token in file names and text file contents with `name`.
+pub fn scaffold(template: Option<&Path>, target: &Path, name: &str) -> io::Result<()> {
+    if target.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", target.display()),
+        ));
+    }
+
+    fs::create_dir_all(target)?;
+
+    match template {
+        Some(template) => copy_dir(template, target, name),
+        None => Ok(()),
+    }
+}
+
+fn read_hook(template: &Path) -> Vec<String> {
+    fs::read_to_string(template.join(HOOK_FILE))
+        .unwrap_or_default()
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Copies the contents of `source` into `target`, keeping permissions like executable scripts
+/// and links as links.
+fn copy_dir(source: &Path, target: &Path, name: &str) -> io::Result<()> {
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+
+        if entry.file_name() == HOOK_FILE {
+            continue;
+        }
+        let file_name = entry
+            .file_name()
+            .to_string_lossy()
+            .replace(NAME_TOKEN, name);
+        let target = target.join(file_name);
+        let file_type = entry.file_type()?;
+
+        if file_type.is_dir() {
+            fs::create_dir_all(&target)?;
+            copy_dir(&entry.path(), &target, name)?;
+            // set last, as a read-only folder would not let its contents be copied
+            fs::set_permissions(&target, entry.metadata()?.permissions())?;
+            continue;
+        }
+
+        if file_type.is_symlink() {
+            std::os::unix::fs::symlink(fs::read_link(entry.path())?, &target)?;
+            continue;
+        }
+
+        match fs::read_to_string(entry.path()) {
+            Ok(content) => {
+                fs::write(&target, content.replace(NAME_TOKEN, name))?;
+                fs::set_permissions(&target, entry.metadata()?.permissions())?;
+            }
+            Err(_) => {
+                fs::copy(entry.path(), &target)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs a single hook command through `sh` inside the new project.
+pub fn run_hook_step(step: &str, project: &Path, name: &str) -> Result<(), String> {
+    let step = step
+        .replace(NAME_TOKEN, name)
+        .replace(PATH_TOKEN, &project.to_string_lossy());
+
+    let output = Command::new("sh")
+        .arg("-c")
+        .arg(&step)
+        .current_dir(project)
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|err| err.to_string())?;
+
+    if output.status.success() {
+        return Ok(());
+    }
+
+    let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+
+    Err(if stderr.is_empty() {
+        output.status.to_string()
+    } else {
+        stderr
+    })
+}
+
+/// Progress of the hook commands of a freshly created project, which are run one after another.
+#[derive(Debug, Clone)]
+pub struct HookRun {
+    project: PathBuf,
+    steps: Vec<String>,
+    finished: usize,
+    error: Option<String>,
+}
+
+impl HookRun {
+    pub fn new(project: PathBuf, steps: Vec<String>) -> HookRun {
+        HookRun {
+            project,
+            steps,
+            finished: 0,
+            error: None,
+        }
+    }
+
+    pub fn project(&self) -> &PathBuf {
+        &self.project
+    }
+
+    pub fn finished(&self) -> usize {
+        self.finished
+    }
+
+    pub fn total(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// The step to run next, `None` once all steps ran or one of them failed.
+    pub fn current_step(&self) -> Option<&str> {
+        if self.error.is_some() {
+            return None;
+        }
+
+        self.steps.get(self.finished).map(String::as_str)
+    }
+
+    pub fn finish_step(&mut self, result: Result<(), String>) {
+        match result {
+            Ok(()) => self.finished += 1,
+            Err(err) => self.error = Some(err),
+        }
+    }
+}
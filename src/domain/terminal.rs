@@ -0,0 +1,63 @@
+use crate::domain::launch;
+use std::io;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Terminal emulators tried in order if `$TERMINAL` is not set.
+const TERMINALS: [&str; 5] = [
+    "cosmic-term",
+    "gnome-terminal",
+    "konsole",
+    "alacritty",
+    "xterm",
+];
+
+/// Runs a shell command inside a new terminal window, keeping the window open afterwards.
+pub fn spawn(command: &str, working_directory: &Path, preferred: Option<&str>) -> io::Result<()> {
+    let child = wrap(preferred)?
+        .arg("sh")
+        .arg("-c")
+        .arg(format!("{command}; exec \"${{SHELL:-sh}}\""))
+        .current_dir(working_directory)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+
+    launch::reap(child, "terminal".to_string(), false);
+    Ok(())
+}
+
+/// Command opening a new terminal window that runs the arguments added to it,
+/// in `preferred` if set or else the detected terminal emulator.
+pub fn wrap(preferred: Option<&str>) -> io::Result<Command> {
+    let terminal = find(preferred)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no terminal emulator found"))?;
+
+    let separator = if terminal.ends_with("gnome-terminal") {
+        "--"
+    } else {
+        "-e"
+    };
+
+    let mut command = Command::new(&terminal);
+    command.arg(separator);
+
+    Ok(command)
+}
+
+fn find(preferred: Option<&str>) -> Option<String> {
+    if let Some(terminal) = preferred.filter(|terminal| !terminal.is_empty()) {
+        return Some(terminal.to_string());
+    }
+
+    if let Ok(terminal) = std::env::var("TERMINAL") {
+        if !terminal.is_empty() {
+            return Some(terminal);
+        }
+    }
+
+    TERMINALS
+        .iter()
+        .find(|terminal| launch::is_installed(terminal))
+        .map(|terminal| terminal.to_string())
+}
@@ -0,0 +1,160 @@
+use serde::{Deserialize, Serialize};
+use std::fs::{self, DirBuilder, OpenOptions};
+use std::io::{self, Write};
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::fs::{DirBuilderExt, MetadataExt, PermissionsExt};
+use std::path::{Path, PathBuf};
+
+/// A project moved to the trash by the app, remembered so it can be restored.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TrashedProject {
+    original: PathBuf,
+    trashed: PathBuf,
+    info: PathBuf,
+}
+
+impl TrashedProject {
+    pub fn original(&self) -> &Path {
+        &self.original
+    }
+
+    pub fn trashed(&self) -> &Path {
+        &self.trashed
+    }
+
+    /// Whether the project is still in the trash, e.g. the trash was not emptied since.
+    pub fn exists(&self) -> bool {
+        self.trashed.exists()
+    }
+}
+
+/// Moves the project into the trash following the freedesktop.org trash specification.
+///
+/// Projects on the home filesystem go to the user's trash, others to the trash at the top of
+/// their own filesystem, so the project is only ever renamed and never copied.
+pub fn trash(path: &Path) -> io::Result<TrashedProject> {
+    let trash = trash_dir(path)?;
+    let files = trash.join("files");
+    let infos = trash.join("info");
+
+    let mut builder = DirBuilder::new();
+    builder.recursive(true).mode(0o700);
+    builder.create(&files)?;
+    builder.create(&infos)?;
+
+    let name = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "path has no name"))?;
+
+    let mut index = 1;
+    let (trash_name, info, mut info_file) = loop {
+        let mut trash_name = name.to_os_string();
+        if index > 1 {
+            trash_name.push(format!(".{index}"));
+        }
+        index += 1;
+
+        if files.join(&trash_name).exists() {
+            continue;
+        }
+
+        let mut info_name = trash_name.clone();
+        info_name.push(".trashinfo");
+        let info = infos.join(info_name);
+
+        match OpenOptions::new().write(true).create_new(true).open(&info) {
+            Ok(file) => break (trash_name, info, file),
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(err) => return Err(err),
+        }
+    };
+
+    let trashed = files.join(&trash_name);
+
+    let written = write!(
+        info_file,
+        "[Trash Info]\nPath={}\nDeletionDate={}\n",
+        encode_path(path),
+        chrono::Local::now().format("%Y-%m-%dT%H:%M:%S")
+    )
+    .and_then(|_| fs::rename(path, &trashed));
+
+    if let Err(err) = written {
+        let _ = fs::remove_file(&info);
+        return Err(err);
+    }
+
+    Ok(TrashedProject {
+        original: path.to_path_buf(),
+        trashed,
+        info,
+    })
+}
+
+/// Moves the project back to where it was trashed from.
+pub fn restore(project: &TrashedProject) -> io::Result<()> {
+    if project.original.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{:?} already exists", project.original),
+        ));
+    }
+
+    fs::rename(&project.trashed, &project.original)?;
+    let _ = fs::remove_file(&project.info);
+
+    Ok(())
+}
+
+/// Picks the trash for the path: the user's trash when the path shares its filesystem, otherwise
+/// `$topdir/.Trash/$uid` if the administrator set it up, or `$topdir/.Trash-$uid`.
+fn trash_dir(path: &Path) -> io::Result<PathBuf> {
+    let home_trash = dirs::data_dir()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no data directory"))?
+        .join("Trash");
+
+    let device = fs::metadata(path)?.dev();
+    let home_device = home_trash
+        .ancestors()
+        .find_map(|dir| fs::metadata(dir).ok())
+        .map(|metadata| metadata.dev());
+
+    if home_device == Some(device) {
+        return Ok(home_trash);
+    }
+
+    let top_dir = path
+        .ancestors()
+        .take_while(|dir| fs::metadata(dir).is_ok_and(|metadata| metadata.dev() == device))
+        .last()
+        .unwrap_or(path);
+    let uid = rustix::process::getuid().as_raw();
+
+    let shared = top_dir.join(".Trash");
+    let shared_is_valid = fs::symlink_metadata(&shared)
+        .is_ok_and(|metadata| metadata.is_dir() && metadata.permissions().mode() & 0o1000 != 0);
+
+    if shared_is_valid {
+        let user_trash = shared.join(uid.to_string());
+        if DirBuilder::new().mode(0o700).create(&user_trash).is_ok() || user_trash.is_dir() {
+            return Ok(user_trash);
+        }
+    }
+
+    Ok(top_dir.join(format!(".Trash-{uid}")))
+}
+
+/// Percent-encodes the path as required for the `Path` key of trash info files and `file://` URLs.
+pub fn encode_path(path: &Path) -> String {
+    path.as_os_str()
+        .as_bytes()
+        .iter()
+        .copied()
+        .map(|byte| match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'/' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            byte => format!("%{byte:02X}"),
+        })
+        .collect()
+}
@@ -0,0 +1,44 @@
+use log::{error, warn};
+use notify::event::ModifyKind;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::path::{Path, PathBuf};
+
+/// Watches the directories listing projects, see [`project::project_dirs`](crate::domain::project::project_dirs),
+/// calling `on_change` whenever a project directory is created, renamed or removed.
+/// Watching stops when the watcher is dropped.
+///
+/// Directories that cannot be watched, e.g. unreadable ones like `lost+found`, are left out.
+pub fn watch(
+    dirs: &[PathBuf],
+    on_change: impl Fn() + Send + 'static,
+) -> notify::Result<RecommendedWatcher> {
+    let mut watcher =
+        notify::recommended_watcher(move |event: notify::Result<Event>| match event {
+            Ok(event) if is_structural(&event) => on_change(),
+            Ok(_) => {}
+            Err(err) => error!("{}", err),
+        })?;
+
+    for dir in dirs {
+        if let Err(err) = watcher.watch(dir, RecursiveMode::NonRecursive) {
+            warn!("not watching {dir:?}: {err}");
+        }
+    }
+
+    Ok(watcher)
+}
+
+/// Whether the event adds or removes an entry, ignoring hidden files like the shared annotations.
+fn is_structural(event: &Event) -> bool {
+    let adds_or_removes = matches!(
+        event.kind,
+        EventKind::Create(_) | EventKind::Remove(_) | EventKind::Modify(ModifyKind::Name(_))
+    );
+
+    adds_or_removes && event.paths.iter().any(|path| !is_hidden(path))
+}
+
+fn is_hidden(path: &Path) -> bool {
+    path.file_name()
+        .is_some_and(|name| name.to_string_lossy().starts_with('.'))
+}
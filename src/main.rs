@@ -1,16 +1,29 @@
 mod app;
+mod cli;
 mod config;
 pub mod domain;
 mod i18n;
 fn main() -> cosmic::iced::Result {
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("warn")).init();
 
+    let args = cli::Args::parse();
+
     // Get the system's preferred languages.
     let requested_languages = i18n_embed::DesktopLanguageRequester::requested_languages();
 
-    // Enable localizations to be applied.
+    // Enable localizations to be applied, also to the notifications of launches without a window.
     i18n::init(&requested_languages);
 
+    if let Some(session) = args.launch_session() {
+        cli::launch_session(session);
+        return Ok(());
+    }
+
+    if let Some((program, project)) = args.launch_project() {
+        cli::launch_project(program, project);
+        return Ok(());
+    }
+
     // Settings for configuring the application window and iced runtime.
     let settings = cosmic::app::Settings::default().size_limits(
         cosmic::iced::Limits::NONE
@@ -18,6 +31,12 @@ fn main() -> cosmic::iced::Result {
             .min_height(180.0),
     );
 
-    // Starts the application's event loop with `()` as the application's flags.
-    cosmic::app::run::<app::AppModel>(settings, ())
+    // Kiosk windows run on their own, so neither mode ever takes over a window of the other.
+    if args.kiosk() {
+        return cosmic::app::run::<app::AppModel>(settings, args);
+    }
+
+    // Starts the application's event loop with the command line arguments as the application's flags,
+    // forwarding them to the running instance if there is one.
+    cosmic::app::run_single_instance::<app::AppModel>(settings, args)
 }